@@ -1,7 +1,8 @@
 use std::sync::{Arc, Mutex};
 
-use actix_web::{HttpResponse, web};
-use protocol::PlayerAction;
+use actix_web::{HttpResponse, http::StatusCode, web};
+use engine::TransportError;
+use protocol::{ActionError, PlayerAction};
 use uuid::Uuid;
 
 use crate::state::AppState;
@@ -21,7 +22,11 @@ use crate::state::AppState;
 /// # Returns
 ///
 /// Returns a JSON response containing the step result, including events that occurred
-/// and any state changes.
+/// and any state changes. Returns `409 Conflict` if the game is already over,
+/// `400 Bad Request` if the action asks for zero items or a missing hotbar
+/// slot, `500 Internal Server Error` if the next level could not be generated,
+/// and `422 Unprocessable Entity` if the game refused the action. Refused
+/// actions carry the reason as a structured [`ActionError`].
 pub async fn apply_move(
     path: web::Path<Uuid>,
     data: web::Data<Arc<Mutex<AppState>>>,
@@ -41,10 +46,27 @@ pub async fn apply_move(
     };
 
     let mut engine_guard = engine.lock().await;
-    let Ok(result) = engine_guard.apply_step(json.into_inner()).await else {
-        return HttpResponse::InternalServerError()
-            .json("Failed to apply move");
-    };
+    match engine_guard.apply_step(json.into_inner()).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(TransportError::Rejected(error)) => {
+            HttpResponse::build(refusal_status(&error)).json(error)
+        },
+        Err(TransportError::LockError | TransportError::InvalidWorld) => {
+            HttpResponse::InternalServerError().json("Failed to apply move")
+        },
+    }
+}
 
-    HttpResponse::Ok().json(result)
+/// Picks the status code for an action the game refused.
+fn refusal_status(error: &ActionError) -> StatusCode {
+    match error {
+        ActionError::GameOver { .. } | ActionError::Dead => {
+            StatusCode::CONFLICT
+        },
+        ActionError::LevelGeneration => StatusCode::INTERNAL_SERVER_ERROR,
+        ActionError::InvalidCount | ActionError::InvalidSlot { .. } => {
+            StatusCode::BAD_REQUEST
+        },
+        _ => StatusCode::UNPROCESSABLE_ENTITY,
+    }
 }
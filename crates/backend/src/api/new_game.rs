@@ -28,7 +28,10 @@ pub struct NewGameResponse {
 pub async fn new_game(data: web::Data<Arc<Mutex<AppState>>>) -> HttpResponse {
     let game_id = Uuid::new_v4();
 
-    let state = Engine::new_state();
+    let Ok(state) = Engine::new_state() else {
+        return HttpResponse::InternalServerError()
+            .json("Failed to create game");
+    };
     let engine = Engine::new_local_game(Arc::new(Mutex::new(state)));
     let state = engine.state();
 
@@ -8,6 +8,7 @@ use backend::{
     api::{apply_move, game_state, new_game},
     state::AppState,
 };
+use engine::LocalEngine;
 use protocol::{Direction, PlayerAction};
 
 #[actix_web::test]
@@ -86,6 +87,8 @@ async fn test_game_state_success() {
     assert!(json_body["player"].is_object());
     assert!(json_body["player"]["x"].is_number());
     assert!(json_body["player"]["y"].is_number());
+    assert_eq!(json_body["status"], "Running");
+    assert_eq!(json_body["depth"], 0);
 }
 
 #[actix_web::test]
@@ -131,8 +134,8 @@ async fn test_apply_move_success() {
     let create_json: Value = serde_json::from_slice(&create_body).unwrap();
     let game_id = create_json["game_id"].as_str().unwrap();
 
-    // Apply a move action
-    let move_action = PlayerAction::Move(Direction::North);
+    // Pick up the starting items lying at the spawn
+    let move_action = PlayerAction::PickUp;
     let req = test::TestRequest::post()
         .uri(&format!("/game/{}", game_id))
         .set_json(&move_action)
@@ -153,6 +156,148 @@ async fn test_apply_move_success() {
     assert!(json_body["diff"].is_object());
 }
 
+#[actix_web::test]
+async fn test_apply_move_rejected() {
+    let app_state = Arc::new(Mutex::new(AppState { games: HashMap::new() }));
+
+    let app = test::init_service(
+        App::new()
+            .route("/game", web::post().to(new_game))
+            .route("/game/{id}", web::get().to(game_state))
+            .route("/game/{id}", web::post().to(apply_move))
+            .app_data(web::Data::new(app_state)),
+    )
+    .await;
+
+    // First, create a new game
+    let create_req = test::TestRequest::post().uri("/game").to_request();
+
+    let create_resp = test::call_service(&app, create_req).await;
+    let create_body = test::read_body(create_resp).await;
+    let create_json: Value = serde_json::from_slice(&create_body).unwrap();
+    let game_id = create_json["game_id"].as_str().unwrap();
+
+    // The player never spawns on the stairs down
+    let req = test::TestRequest::post()
+        .uri(&format!("/game/{}", game_id))
+        .set_json(&PlayerAction::Descend)
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    // Should return 422 with the reason
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = test::read_body(resp).await;
+    let json_body: Value =
+        serde_json::from_slice(&body).expect("Failed to parse JSON");
+    assert_eq!(
+        json_body["NoStairs"]["position"],
+        create_json["state"]["player"]
+    );
+
+    // The refused action did not take a turn
+    let state_req = test::TestRequest::get()
+        .uri(&format!("/game/{}", game_id))
+        .to_request();
+
+    let state_resp = test::call_service(&app, state_req).await;
+    let state_body = test::read_body(state_resp).await;
+    let state_json: Value = serde_json::from_slice(&state_body).unwrap();
+    assert_eq!(state_json["player"], create_json["state"]["player"]);
+}
+
+#[actix_web::test]
+async fn test_apply_move_invalid_slot() {
+    let app_state = Arc::new(Mutex::new(AppState { games: HashMap::new() }));
+
+    let app = test::init_service(
+        App::new()
+            .route("/game", web::post().to(new_game))
+            .route("/game/{id}", web::post().to(apply_move))
+            .app_data(web::Data::new(app_state)),
+    )
+    .await;
+
+    // First, create a new game
+    let create_req = test::TestRequest::post().uri("/game").to_request();
+
+    let create_resp = test::call_service(&app, create_req).await;
+    let create_body = test::read_body(create_resp).await;
+    let create_json: Value = serde_json::from_slice(&create_body).unwrap();
+    let game_id = create_json["game_id"].as_str().unwrap();
+
+    // The hotbar has no slot this far out
+    let req = test::TestRequest::post()
+        .uri(&format!("/game/{}", game_id))
+        .set_json(&PlayerAction::ClearHotbar { slot: 99 })
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    // Should return 400 with the reason
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let json_body: Value =
+        serde_json::from_slice(&body).expect("Failed to parse JSON");
+    assert_eq!(json_body["InvalidSlot"]["slot"], 99);
+}
+
+#[actix_web::test]
+async fn test_apply_move_game_over() {
+    let app_state = Arc::new(Mutex::new(AppState { games: HashMap::new() }));
+
+    // A cramped cave full of enemies that soon overwhelm the player
+    let state = corelib::new_game(&corelib::WorldSettings {
+        map_width: 11,
+        map_height: 11,
+        floor_tiles: 40,
+        enemies: 20,
+        generator: corelib::GeneratorKind::RandomWalk,
+        ..corelib::WorldSettings::default()
+    })
+    .expect("Failed to generate world");
+    let game_id = Uuid::new_v4();
+    app_state.lock().unwrap().add_game(
+        game_id,
+        LocalEngine::new_local_game(Arc::new(Mutex::new(state))),
+    );
+
+    let app = test::init_service(
+        App::new()
+            .route("/game/{id}", web::post().to(apply_move))
+            .app_data(web::Data::new(app_state)),
+    )
+    .await;
+
+    // Pick up the starting gear and drop it again until the player dies
+    let mut resp = None;
+    for turn in 0..1000 {
+        let action = if turn % 2 == 0 {
+            PlayerAction::PickUp
+        } else {
+            PlayerAction::Drop { item_id: 0, count: None }
+        };
+        let req = test::TestRequest::post()
+            .uri(&format!("/game/{}", game_id))
+            .set_json(&action)
+            .to_request();
+
+        let turn_resp = test::call_service(&app, req).await;
+        if turn_resp.status() != StatusCode::OK {
+            resp = Some(turn_resp);
+            break;
+        }
+    }
+    let resp = resp.expect("The player never died");
+
+    // Should return 409 with the reason
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body = test::read_body(resp).await;
+    let json_body: Value =
+        serde_json::from_slice(&body).expect("Failed to parse JSON");
+    assert_eq!(json_body, "Dead");
+}
+
 #[actix_web::test]
 async fn test_apply_move_not_found() {
     let app_state = Arc::new(Mutex::new(AppState { games: HashMap::new() }));
@@ -238,8 +383,8 @@ async fn test_full_game_flow() {
     let initial_x = create_json["state"]["player"]["x"].as_i64().unwrap();
     let initial_y = create_json["state"]["player"]["y"].as_i64().unwrap();
 
-    // 2. Apply a move
-    let move_action = PlayerAction::Move(Direction::East);
+    // 2. Pick up the starting items lying at the spawn
+    let move_action = PlayerAction::PickUp;
     let move_req = test::TestRequest::post()
         .uri(&format!("/game/{}", game_id))
         .set_json(&move_action)
@@ -351,6 +496,10 @@ async fn test_multiple_moves_same_game() {
             .to_request();
 
         let move_resp = test::call_service(&app, move_req).await;
+        // Walls around the spawn may refuse some of the moves
+        if move_resp.status() == StatusCode::UNPROCESSABLE_ENTITY {
+            continue;
+        }
         assert_eq!(move_resp.status(), StatusCode::OK);
 
         // Verify response structure
@@ -398,7 +547,7 @@ async fn test_concurrent_games_isolation() {
     let game_id2 = create_json2["game_id"].as_str().unwrap();
 
     // Apply different moves to each game
-    let move1 = PlayerAction::Move(Direction::North);
+    let move1 = PlayerAction::PickUp;
     let move_req1 = test::TestRequest::post()
         .uri(&format!("/game/{}", game_id1))
         .set_json(&move1)
@@ -407,7 +556,7 @@ async fn test_concurrent_games_isolation() {
     let move_resp1 = test::call_service(&app, move_req1).await;
     assert_eq!(move_resp1.status(), StatusCode::OK);
 
-    let move2 = PlayerAction::Move(Direction::South);
+    let move2 = PlayerAction::PickUp;
     let move_req2 = test::TestRequest::post()
         .uri(&format!("/game/{}", game_id2))
         .set_json(&move2)
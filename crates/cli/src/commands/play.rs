@@ -1,4 +1,7 @@
-use corelib::{Direction, PlayerAction, WorldSettings, new_game};
+use corelib::{
+    Content, Direction, EquipmentSlot, GameState, GeneratorKind, PlayerAction,
+    Tile, WorldError, WorldSettings, new_game_with_content,
+};
 use ratatui::crossterm::event::{self, Event, KeyCode};
 use sha2::Digest;
 
@@ -10,21 +13,52 @@ fn seed_from_u64(seed: u64) -> [u8; 32] {
     hasher.finalize().into()
 }
 
+/// Returns the direction of the first tile next to the player that holds the
+/// given tile.
+fn adjacent(game: &GameState, tile: &Tile) -> Option<Direction> {
+    let player = game.player().position();
+    Direction::ALL.into_iter().find(|direction| {
+        game.dungeon().get_tile(player + direction.to_offset_position()) == tile
+    })
+}
+
+/// Returns the action that moves an inventory item to where it belongs:
+/// worn items to their equipment slot, consumables to a free hotbar slot.
+fn equip_action(game: &GameState, item_id: usize) -> Option<PlayerAction> {
+    let item = game.items_catalog().get(item_id)?;
+    if let Some(slot) = item.equipment_slot() {
+        return Some(PlayerAction::EquipItem { item_id, slot });
+    }
+    let slot = game.hotbar().empty_slot()?;
+    Some(PlayerAction::AssignHotbar { item_id, slot })
+}
+
 /// Run TUI with game
+///
+/// # Errors
+///
+/// Returns an error if no game can be created with the given settings.
 pub(crate) fn play(
     seed: u64,
     map_width: usize,
     map_height: usize,
     floor_tiles: usize,
     enemies: usize,
-) {
-    let mut game = new_game(&WorldSettings {
-        seed: seed_from_u64(seed),
-        map_width,
-        map_height,
-        floor_tiles,
-        enemies,
-    });
+    generator: GeneratorKind,
+    content: Content,
+) -> Result<(), WorldError> {
+    let mut game = new_game_with_content(
+        &WorldSettings {
+            seed: seed_from_u64(seed),
+            map_width,
+            map_height,
+            floor_tiles,
+            enemies,
+            generator,
+            ..WorldSettings::default()
+        },
+        content,
+    )?;
     let mut tui = TuiApplication::default();
 
     loop {
@@ -41,20 +75,33 @@ pub(crate) fn play(
                     if tui.mode() == &Mode::Game {
                         let result =
                             game.apply_player_action(&PlayerAction::Skip);
-                        tui.set_event_log(result.events);
+                        tui.set_step_result(result);
                     } else {
                         match tui.inventory_focus() {
+                            InventoryFocus::Equipment => {
+                                let state = tui.equipment_state();
+                                let Some(slot) = state
+                                    .selected()
+                                    .and_then(|i| EquipmentSlot::ALL.get(i))
+                                else {
+                                    continue;
+                                };
+                                let result = game.apply_player_action(
+                                    &PlayerAction::UnequipItem { slot: *slot },
+                                );
+                                tui.set_step_result(result);
+                            },
                             InventoryFocus::Hotbar => {
                                 let state = tui.hotbar_state();
                                 let Some(selected) = state.selected() else {
                                     continue;
                                 };
                                 let result = game.apply_player_action(
-                                    &PlayerAction::UnequipItem {
+                                    &PlayerAction::ClearHotbar {
                                         slot: selected,
                                     },
                                 );
-                                tui.set_event_log(result.events);
+                                tui.set_step_result(result);
                             },
                             InventoryFocus::Inventory => {
                                 let state = tui.inventory_state();
@@ -67,14 +114,13 @@ pub(crate) fn play(
                                     .flatten()
                                     .collect::<Vec<_>>();
                                 let item = inventory_items[selected];
-                                let slot = game.hotbar().empty_slot().unwrap();
-                                let result = game.apply_player_action(
-                                    &PlayerAction::EquipItem {
-                                        slot,
-                                        item_id: item.id(),
-                                    },
-                                );
-                                tui.set_event_log(result.events);
+                                let Some(action) =
+                                    equip_action(&game, item.id())
+                                else {
+                                    continue;
+                                };
+                                let result = game.apply_player_action(&action);
+                                tui.set_step_result(result);
                             },
                         }
                     }
@@ -82,12 +128,69 @@ pub(crate) fn play(
                 KeyCode::Char('i') => {
                     tui.toggle_inventory();
                 },
+                KeyCode::Char('g') => {
+                    let result =
+                        game.apply_player_action(&PlayerAction::PickUp);
+                    tui.set_step_result(result);
+                },
+                KeyCode::Char('x') => {
+                    if tui.mode() != &Mode::Inventory
+                        || tui.inventory_focus() != &InventoryFocus::Inventory
+                    {
+                        continue;
+                    }
+                    let Some(selected) = tui.inventory_state().selected()
+                    else {
+                        continue;
+                    };
+                    let Some(item) =
+                        game.inventory().iter().flatten().nth(selected)
+                    else {
+                        continue;
+                    };
+                    let result =
+                        game.apply_player_action(&PlayerAction::Drop {
+                            item_id: item.id(),
+                            count: None,
+                        });
+                    tui.set_step_result(result);
+                },
+                KeyCode::Char('u') => {
+                    if tui.mode() != &Mode::Inventory {
+                        continue;
+                    }
+                    let action = match tui.inventory_focus() {
+                        InventoryFocus::Equipment => continue,
+                        InventoryFocus::Hotbar => {
+                            let Some(slot) = tui.hotbar_state().selected()
+                            else {
+                                continue;
+                            };
+                            PlayerAction::UseHotbarItem { slot }
+                        },
+                        InventoryFocus::Inventory => {
+                            let Some(selected) =
+                                tui.inventory_state().selected()
+                            else {
+                                continue;
+                            };
+                            let Some(item) =
+                                game.inventory().iter().flatten().nth(selected)
+                            else {
+                                continue;
+                            };
+                            PlayerAction::UseItem { item_id: item.id() }
+                        },
+                    };
+                    let result = game.apply_player_action(&action);
+                    tui.set_step_result(result);
+                },
                 KeyCode::Char('w') => {
                     if tui.mode() == &Mode::Game {
                         let result = game.apply_player_action(
                             &PlayerAction::Move(Direction::North),
                         );
-                        tui.set_event_log(result.events);
+                        tui.set_step_result(result);
                     } else {
                         tui.select_previous();
                     }
@@ -97,7 +200,7 @@ pub(crate) fn play(
                         let result = game.apply_player_action(
                             &PlayerAction::Move(Direction::South),
                         );
-                        tui.set_event_log(result.events);
+                        tui.set_step_result(result);
                     } else {
                         tui.select_next();
                     }
@@ -107,7 +210,7 @@ pub(crate) fn play(
                         let result = game.apply_player_action(
                             &PlayerAction::Move(Direction::West),
                         );
-                        tui.set_event_log(result.events);
+                        tui.set_step_result(result);
                     } else {
                         tui.toggle_inventory_focus();
                     }
@@ -117,37 +220,67 @@ pub(crate) fn play(
                         let result = game.apply_player_action(
                             &PlayerAction::Move(Direction::East),
                         );
-                        tui.set_event_log(result.events);
+                        tui.set_step_result(result);
                     } else {
                         tui.toggle_inventory_focus();
                     }
                 },
+                KeyCode::Char('>') => {
+                    let result =
+                        game.apply_player_action(&PlayerAction::Descend);
+                    tui.set_step_result(result);
+                },
+                KeyCode::Char('<') => {
+                    let result =
+                        game.apply_player_action(&PlayerAction::Ascend);
+                    tui.set_step_result(result);
+                },
+                KeyCode::Char('o') => {
+                    let Some(direction) = adjacent(&game, &Tile::DoorClosed)
+                    else {
+                        continue;
+                    };
+                    let result = game
+                        .apply_player_action(&PlayerAction::Open(direction));
+                    tui.set_step_result(result);
+                },
+                KeyCode::Char('c') => {
+                    let Some(direction) = adjacent(&game, &Tile::DoorOpen)
+                    else {
+                        continue;
+                    };
+                    let result = game
+                        .apply_player_action(&PlayerAction::Close(direction));
+                    tui.set_step_result(result);
+                },
                 KeyCode::Char('W') => {
                     let result = game.apply_player_action(
                         &PlayerAction::Attack(Direction::North),
                     );
-                    tui.set_event_log(result.events);
+                    tui.set_step_result(result);
                 },
                 KeyCode::Char('S') => {
                     let result = game.apply_player_action(
                         &PlayerAction::Attack(Direction::South),
                     );
-                    tui.set_event_log(result.events);
+                    tui.set_step_result(result);
                 },
                 KeyCode::Char('A') => {
                     let result = game.apply_player_action(
                         &PlayerAction::Attack(Direction::West),
                     );
-                    tui.set_event_log(result.events);
+                    tui.set_step_result(result);
                 },
                 KeyCode::Char('D') => {
                     let result = game.apply_player_action(
                         &PlayerAction::Attack(Direction::East),
                     );
-                    tui.set_event_log(result.events);
+                    tui.set_step_result(result);
                 },
                 _ => {},
             }
         }
     }
+
+    Ok(())
 }
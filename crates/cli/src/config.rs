@@ -1,4 +1,7 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use corelib::GeneratorKind;
 
 /// Configures the dungeon game CLI
 #[derive(Parser)]
@@ -28,5 +31,35 @@ pub(crate) enum Commands {
         /// Maximum number of enemies.
         #[arg(long)]
         enemies: Option<usize>,
+        /// Algorithm used to generate the map.
+        #[arg(long, value_enum)]
+        generator: Option<Generator>,
+        /// Directory with the item, effect and actor definitions to play with.
+        #[arg(long, value_name = "DIR")]
+        content: Option<PathBuf>,
     },
 }
+
+/// Map generators selectable from the command line
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum Generator {
+    /// Random walk cave
+    Cave,
+    /// Rectangular rooms joined by corridors
+    Rooms,
+    /// Rooms in binary space partitions
+    Bsp,
+    /// Caves grown by a cellular automaton
+    Cellular,
+}
+
+impl From<Generator> for GeneratorKind {
+    fn from(generator: Generator) -> Self {
+        match generator {
+            Generator::Cave => GeneratorKind::RandomWalk,
+            Generator::Rooms => GeneratorKind::RoomsAndCorridors,
+            Generator::Bsp => GeneratorKind::Bsp,
+            Generator::Cellular => GeneratorKind::CellularCaves,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use clap::{Error, Parser, error::ErrorKind};
+use corelib::Content;
 
 mod commands;
 mod config;
@@ -18,6 +19,8 @@ fn main() {
             map_height,
             enemies,
             floor_tiles,
+            generator,
+            content,
         } => {
             let seed = seed.unwrap_or(DEFAULT_SEED);
             let map_width = map_width.unwrap_or(DEFAULT_MAP_WIDTH);
@@ -25,30 +28,27 @@ fn main() {
             let floor_tiles = floor_tiles
                 .unwrap_or(DEFAULT_MAP_WIDTH * DEFAULT_MAP_HEIGHT / 3);
             let enemies = enemies.unwrap_or(floor_tiles / 500);
+            let generator = generator.map(Into::into).unwrap_or_default();
+            let content = match content {
+                Some(dir) => Content::load(&dir).unwrap_or_else(|error| {
+                    Error::raw(ErrorKind::ValueValidation, format!("{error}\n"))
+                        .exit()
+                }),
+                None => Content::default(),
+            };
 
-            if floor_tiles > map_width * map_height {
-                Error::raw(
-                    ErrorKind::ValueValidation,
-                    "floor_tiles must not exceed map area (width × height)\n",
-                )
-                .exit();
-            }
-
-            if enemies > floor_tiles - 1 {
-                Error::raw(
-                    ErrorKind::ValueValidation,
-                    "Too many enemies: must leave at least one floor tile free\n",
-                )
-                .exit();
-            }
-
-            commands::play::play(
+            if let Err(error) = commands::play::play(
                 seed,
                 map_width,
                 map_height,
                 floor_tiles,
                 enemies,
-            );
+                generator,
+                content,
+            ) {
+                Error::raw(ErrorKind::ValueValidation, format!("{error}\n"))
+                    .exit();
+            }
         },
     }
 }
@@ -1,6 +1,12 @@
-use std::{collections::VecDeque, io::Stdout};
+use std::{
+    collections::{HashMap, VecDeque},
+    io::Stdout,
+};
 
-use corelib::{GameEvent, GameState};
+use corelib::{
+    ActionError, ActorKind, EntityId, GameEvent, GameState, GameStatus,
+    StepResult,
+};
 use ratatui::{
     Terminal,
     layout::{Constraint, Layout},
@@ -8,8 +14,8 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, TableState},
 };
 use widgets::{
-    event_log::EventLogWidget, hotbar::HotbarWidget,
-    inventory::InventoryWidget, viewport::ViewportWidget,
+    equipment::EquipmentWidget, event_log::EventLogWidget,
+    hotbar::HotbarWidget, inventory::InventoryWidget, viewport::ViewportWidget,
 };
 
 pub mod widgets;
@@ -23,14 +29,20 @@ pub enum Mode {
 #[derive(PartialEq)]
 pub enum InventoryFocus {
     Inventory,
+    Equipment,
     Hotbar,
 }
 
 pub struct TuiApplication {
     terminal: Terminal<CrosstermBackend<Stdout>>,
     event_log: VecDeque<GameEvent>,
+    refusal: Option<ActionError>,
+    /// Kinds of every entity seen so far, so the log can still name the ones
+    /// that died.
+    kinds: HashMap<EntityId, ActorKind>,
     mode: Mode,
     inventory_focus: InventoryFocus,
+    equipment_state: TableState,
     hotbar_state: TableState,
     inventory_state: TableState,
 }
@@ -48,6 +60,10 @@ impl TuiApplication {
         &self.inventory_state
     }
 
+    pub fn equipment_state(&self) -> &TableState {
+        &self.equipment_state
+    }
+
     pub fn hotbar_state(&self) -> &TableState {
         &self.hotbar_state
     }
@@ -62,6 +78,7 @@ impl TuiApplication {
     pub fn select_next(&mut self) {
         match self.inventory_focus {
             InventoryFocus::Inventory => self.inventory_state.select_next(),
+            InventoryFocus::Equipment => self.equipment_state.select_next(),
             InventoryFocus::Hotbar => self.hotbar_state.select_next(),
         }
     }
@@ -69,6 +86,7 @@ impl TuiApplication {
     pub fn select_previous(&mut self) {
         match self.inventory_focus {
             InventoryFocus::Inventory => self.inventory_state.select_previous(),
+            InventoryFocus::Equipment => self.equipment_state.select_previous(),
             InventoryFocus::Hotbar => self.hotbar_state.select_previous(),
         }
     }
@@ -77,6 +95,11 @@ impl TuiApplication {
         match self.inventory_focus {
             InventoryFocus::Inventory => {
                 self.inventory_state.select(None);
+                self.equipment_state.select(Some(0));
+                self.inventory_focus = InventoryFocus::Equipment
+            },
+            InventoryFocus::Equipment => {
+                self.equipment_state.select(None);
                 self.hotbar_state.select(Some(0));
                 self.inventory_focus = InventoryFocus::Hotbar
             },
@@ -91,6 +114,18 @@ impl TuiApplication {
     pub fn set_event_log(&mut self, events: VecDeque<GameEvent>) {
         self.event_log = events;
     }
+
+    /// Shows the events of an accepted action. Refused actions keep the
+    /// previous log and add the reason they were refused.
+    pub fn set_step_result(&mut self, result: Result<StepResult, ActionError>) {
+        match result {
+            Ok(result) => {
+                self.set_event_log(result.events);
+                self.refusal = None;
+            },
+            Err(error) => self.refusal = Some(error),
+        }
+    }
 }
 
 impl Default for TuiApplication {
@@ -99,9 +134,12 @@ impl Default for TuiApplication {
             terminal: ratatui::init(),
             mode: Mode::Game,
             inventory_focus: InventoryFocus::Inventory,
+            equipment_state: TableState::default(),
             hotbar_state: TableState::default(),
             inventory_state: TableState::default(),
             event_log: VecDeque::with_capacity(100),
+            refusal: None,
+            kinds: HashMap::new(),
         }
     }
 }
@@ -111,8 +149,18 @@ impl TuiApplication {
         let viewport = ViewportWidget::new(state);
         // let stats = StatsWidget::from(state);
         let inventory = InventoryWidget::new(state);
+        let equipment = EquipmentWidget::new(state);
         let hotbar = HotbarWidget::new(state);
-        let event_log = EventLogWidget::new(&self.event_log);
+        self.kinds.extend(
+            std::iter::once(state.player())
+                .chain(state.entities())
+                .map(|actor| (actor.id(), actor.kind())),
+        );
+        let event_log = EventLogWidget::new(
+            &self.event_log,
+            self.refusal.as_ref(),
+            &self.kinds,
+        );
 
         self.terminal
             .draw(|frame| {
@@ -127,8 +175,19 @@ impl TuiApplication {
                 ]);
                 let [left_area, right_area] = horizontal.areas(main_area);
 
-                let title = Paragraph::new("dungeon-rs")
-                    .block(Block::default().borders(Borders::ALL));
+                let depth = state.depth();
+                let title = Paragraph::new(match state.status() {
+                    GameStatus::Running => {
+                        format!("dungeon-rs - Depth {depth}")
+                    },
+                    GameStatus::PlayerDead => {
+                        format!("dungeon-rs - Depth {depth} - You died")
+                    },
+                    GameStatus::Victory => {
+                        format!("dungeon-rs - Depth {depth} - Victory")
+                    },
+                })
+                .block(Block::default().borders(Borders::ALL));
 
                 frame.render_widget(title, title_area);
                 if self.mode == Mode::Inventory {
@@ -141,9 +200,19 @@ impl TuiApplication {
                     frame.render_widget(viewport, left_area);
                 }
                 if self.mode == Mode::Inventory {
+                    let [equipment_area, hotbar_area] = Layout::vertical([
+                        Constraint::Length(9),
+                        Constraint::Min(0),
+                    ])
+                    .areas(right_area);
+                    frame.render_stateful_widget(
+                        equipment,
+                        equipment_area,
+                        &mut self.equipment_state,
+                    );
                     frame.render_stateful_widget(
                         hotbar,
-                        right_area,
+                        hotbar_area,
                         &mut self.hotbar_state,
                     );
                 } else {
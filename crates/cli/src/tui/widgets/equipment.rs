@@ -0,0 +1,53 @@
+use corelib::GameState;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, Borders, Row, StatefulWidget, Table, TableState},
+};
+
+pub struct EquipmentWidget<'a> {
+    state: &'a GameState,
+}
+
+impl<'a> EquipmentWidget<'a> {
+    pub fn new(state: &'a GameState) -> Self {
+        Self { state }
+    }
+}
+
+impl StatefulWidget for EquipmentWidget<'_> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut rows = vec![];
+
+        for (slot, item) in self.state.equipment().iter() {
+            let item_name = match item {
+                Some(item) => self
+                    .state
+                    .items_catalog()
+                    .get(item.id())
+                    .map(|item| item.title())
+                    .unwrap_or("Unknown item")
+                    .to_string(),
+                None => "Empty".to_string(),
+            };
+            rows.push(Row::new(vec![slot.to_string(), item_name]));
+        }
+
+        let widths = [Constraint::Percentage(30), Constraint::Percentage(70)];
+        let table = Table::new(rows, widths)
+            .column_spacing(1)
+            .header(
+                Row::new(vec!["Slot", "Item"])
+                    .style(Style::new().bold())
+                    .bottom_margin(1),
+            )
+            .block(Block::default().title("Equipment").borders(Borders::ALL))
+            .row_highlight_style(Style::new().reversed())
+            .highlight_symbol(">>");
+
+        StatefulWidget::render(table, area, buf, state);
+    }
+}
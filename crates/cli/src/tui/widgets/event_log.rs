@@ -1,6 +1,6 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
-use corelib::GameEvent;
+use corelib::{ActionError, ActorKind, AttackOutcome, EntityId, GameEvent};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -9,11 +9,35 @@ use ratatui::{
 
 pub struct EventLogWidget<'a> {
     log: &'a VecDeque<GameEvent>,
+    refusal: Option<&'a ActionError>,
+    kinds: &'a HashMap<EntityId, ActorKind>,
 }
 
 impl<'a> EventLogWidget<'a> {
-    pub fn new(log: &'a VecDeque<GameEvent>) -> Self {
-        Self { log }
+    pub fn new(
+        log: &'a VecDeque<GameEvent>,
+        refusal: Option<&'a ActionError>,
+        kinds: &'a HashMap<EntityId, ActorKind>,
+    ) -> Self {
+        Self { log, refusal, kinds }
+    }
+
+    /// Names an entity by its kind, e.g. "the goblin".
+    fn name(&self, id: &EntityId) -> String {
+        match self.kinds.get(id) {
+            Some(kind) => format!("the {kind}"),
+            None => "something".to_string(),
+        }
+    }
+
+    /// Names an entity at the start of a sentence, e.g. "The goblin".
+    fn subject(&self, id: &EntityId) -> String {
+        let name = self.name(id);
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => name,
+        }
     }
 }
 
@@ -27,18 +51,27 @@ impl Widget for EventLogWidget<'_> {
                     log.push_str("Player skipped their turn.\n")
                 },
                 GameEvent::PlayerDied => log.push_str("Player died.\n"),
+                GameEvent::PlayerStunned => {
+                    log.push_str("Player is stunned and loses the turn.\n")
+                },
                 GameEvent::PlayerMoved { from: _, to: _ } => {
                     log.push_str("Player moved.\n")
                 },
-                GameEvent::PlayerBumped { position: _, direction: _ } => {
-                    log.push_str("Player bumped into something.\n")
-                },
-                GameEvent::PlayerAttacked { damage, target: _ } => log
-                    .push_str(&format!(
-                        "Player attacked with {damage} damage.\n"
-                    )),
-                GameEvent::PlayerAttackMissed => {
-                    log.push_str("Player attack missed.\n")
+                GameEvent::PlayerAttacked { target, outcome } => {
+                    log.push_str(&match outcome {
+                        AttackOutcome::Miss => {
+                            format!(
+                                "{} dodges the player.\n",
+                                self.subject(target)
+                            )
+                        },
+                        AttackOutcome::Hit { damage } => {
+                            format!("Player attacked with {damage} damage.\n")
+                        },
+                        AttackOutcome::Critical { damage } => format!(
+                            "Player critically hit for {damage} damage!\n"
+                        ),
+                    });
                 },
                 GameEvent::PlayerEquippedItem { item_id: _, slot } => log
                     .push_str(&format!(
@@ -47,24 +80,108 @@ impl Widget for EventLogWidget<'_> {
                 GameEvent::PlayerUnequippedItem { slot } => log.push_str(
                     &format!("Player unequipped an item from {slot} slot.\n"),
                 ),
-                GameEvent::EntityMoved { from: _, id, to: _ } => {
-                    log.push_str(&format!("Entity({id:?}) moved.\n",))
+                GameEvent::PlayerAssignedHotbar { item_id, slot } => log
+                    .push_str(&format!(
+                        "Player put item {item_id} into hotbar slot {slot}.\n"
+                    )),
+                GameEvent::PlayerClearedHotbar { slot } => log
+                    .push_str(&format!("Player cleared hotbar slot {slot}.\n")),
+                GameEvent::PlayerChangedDepth { from, to } if to > from => {
+                    log.push_str(&format!("Player descended to depth {to}.\n"))
+                },
+                GameEvent::PlayerChangedDepth { from: _, to } => {
+                    log.push_str(&format!("Player ascended to depth {to}.\n"))
+                },
+                GameEvent::PlayerPickedUpItem { item_id, count } => log
+                    .push_str(&format!(
+                        "Player picked up {count} x item {item_id}.\n",
+                    )),
+                GameEvent::PlayerDroppedItem { item_id, count } => log
+                    .push_str(&format!(
+                        "Player dropped {count} x item {item_id}.\n",
+                    )),
+                GameEvent::PlayerInventoryFull { item_id } => log.push_str(
+                    &format!("No room in the inventory for item {item_id}.\n"),
+                ),
+                GameEvent::PlayerOpenedDoor { position: _ } => {
+                    log.push_str("Player opened the door.\n")
+                },
+                GameEvent::PlayerClosedDoor { position: _ } => {
+                    log.push_str("Player closed the door.\n")
                 },
-                GameEvent::EntityAttacked { damage, id, target: _ } => log
+                GameEvent::EntityOpenedDoor { id, position: _ } => log
                     .push_str(&format!(
-                        "Entity({id:?}) attacked with {damage} damage.\n",
+                        "{} opened a door.\n",
+                        self.subject(id)
                     )),
+                GameEvent::EntityHurtByTerrain { id, position: _, damage } => {
+                    log.push_str(&format!(
+                        "{} was hurt by the terrain for {damage} damage.\n",
+                        self.subject(id),
+                    ))
+                },
+                GameEvent::EntityCreated { id, position: _ } => {
+                    log.push_str(&format!("{} appeared.\n", self.subject(id)))
+                },
+                GameEvent::EntityDied { id } => {
+                    log.push_str(&format!("{} died.\n", self.subject(id)))
+                },
+                GameEvent::EntityDroppedLoot {
+                    id,
+                    position: _,
+                    item_id,
+                    count,
+                } => log.push_str(&format!(
+                    "{} dropped {count} x item {item_id}.\n",
+                    self.subject(id),
+                )),
+                GameEvent::EntityMoved { from: _, id, to: _ } => {
+                    log.push_str(&format!("{} moved.\n", self.subject(id)))
+                },
+                GameEvent::EntityAttacked { id, target: _, outcome } => {
+                    log.push_str(&match outcome {
+                        AttackOutcome::Miss => {
+                            format!("Player dodges {}.\n", self.name(id))
+                        },
+                        AttackOutcome::Hit { damage } => format!(
+                            "{} attacked with {damage} damage.\n",
+                            self.subject(id),
+                        ),
+                        AttackOutcome::Critical { damage } => format!(
+                            "{} critically hit for {damage} damage!\n",
+                            self.subject(id),
+                        ),
+                    });
+                },
                 GameEvent::EffectTick { entity_id, effect_id: _ } => log
                     .push_str(&format!(
-                        "Entity({entity_id:?}) effect ticked.\n",
+                        "{} feels an effect.\n",
+                        self.subject(entity_id),
                     )),
                 GameEvent::EffectExpired { entity_id, effect_id: _ } => log
                     .push_str(&format!(
-                        "Entity({entity_id:?}) effect expired.\n",
+                        "An effect on {} wore off.\n",
+                        self.name(entity_id),
                     )),
+                GameEvent::ItemUsed { item_id } => {
+                    log.push_str(&format!("Player used item {item_id}.\n",))
+                },
+                GameEvent::EffectApplied {
+                    entity_id,
+                    effect_id: _,
+                    name,
+                    duration,
+                } => log.push_str(&format!(
+                    "{} is affected by {name} for {duration} turns.\n",
+                    self.subject(entity_id),
+                )),
             }
         }
 
+        if let Some(error) = self.refusal {
+            log.push_str(&format!("Cannot do that: {error}.\n"));
+        }
+
         let paragraph = Paragraph::new(log)
             .block(Block::default().title("Logs").borders(Borders::ALL));
         paragraph.render(area, buf);
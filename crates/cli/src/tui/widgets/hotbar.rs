@@ -34,20 +34,26 @@ impl StatefulWidget for HotbarWidget<'_> {
                 rows.push(Row::new(vec![
                     format!("Slot {}", index + 1),
                     item_name,
+                    format!("{}", item.count()),
                 ]));
             } else {
                 rows.push(Row::new(vec![
                     format!("Slot {}", index),
                     "Empty".to_string(),
+                    String::new(),
                 ]));
             }
         }
 
-        let widths = [Constraint::Percentage(20), Constraint::Percentage(90)];
+        let widths = [
+            Constraint::Percentage(20),
+            Constraint::Percentage(65),
+            Constraint::Percentage(15),
+        ];
         let table = Table::new(rows, widths)
             .column_spacing(1)
             .header(
-                Row::new(vec!["Slot", "Item"])
+                Row::new(vec!["Slot", "Item", "Qty"])
                     .style(Style::new().bold())
                     .bottom_margin(1),
             )
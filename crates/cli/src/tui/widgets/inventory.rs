@@ -42,6 +42,7 @@ impl StatefulWidget for InventoryWidget<'_> {
             rows.push(Row::new(vec![
                 format!("{}", item.id()),
                 item_name,
+                format!("{}", item.count()),
                 item_description,
             ]));
         }
@@ -55,13 +56,14 @@ impl StatefulWidget for InventoryWidget<'_> {
 
         let widths = [
             Constraint::Percentage(10),
-            Constraint::Percentage(45),
+            Constraint::Percentage(35),
+            Constraint::Percentage(10),
             Constraint::Percentage(45),
         ];
         let table = Table::new(rows, widths)
             .column_spacing(1)
             .header(
-                Row::new(vec!["ID", "Item", "Description"])
+                Row::new(vec!["ID", "Item", "Qty", "Description"])
                     .style(Style::new().bold())
                     .bottom_margin(1),
             )
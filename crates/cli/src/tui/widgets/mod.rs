@@ -1,3 +1,4 @@
+pub mod equipment;
 pub mod event_log;
 pub mod hotbar;
 pub mod inventory;
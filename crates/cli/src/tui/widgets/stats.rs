@@ -8,18 +8,26 @@ use ratatui::{
 #[allow(dead_code)]
 pub struct StatsWidget {
     hp: u32,
+    max_hp: u32,
     min_damage: u32,
     max_damage: u32,
     defense: u32,
+    accuracy: u32,
+    evasion: u32,
+    crit_chance: u32,
 }
 
 impl From<&GameState> for StatsWidget {
     fn from(game_state: &GameState) -> Self {
         Self {
             hp: game_state.player().stats().hp(),
+            max_hp: game_state.player().stats().max_hp(),
             min_damage: game_state.player().stats().min_damage(),
             max_damage: game_state.player().stats().max_damage(),
             defense: game_state.player().stats().defense(),
+            accuracy: game_state.player().stats().accuracy(),
+            evasion: game_state.player().stats().evasion(),
+            crit_chance: game_state.player().stats().crit_chance(),
         }
     }
 }
@@ -27,12 +35,16 @@ impl From<&GameState> for StatsWidget {
 impl Widget for StatsWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let hp = self.hp;
+        let max_hp = self.max_hp;
         let min_damage = self.min_damage;
         let max_damage = self.max_damage;
         let defense = self.defense;
+        let accuracy = self.accuracy;
+        let evasion = self.evasion;
+        let crit_chance = self.crit_chance;
 
         let paragraph = Paragraph::new(format!(
-            "HP: {hp}\nAttack: {min_damage}-{max_damage}\nDefense: {defense}"
+            "HP: {hp}/{max_hp}\nAttack: {min_damage}-{max_damage}\nDefense: {defense}\nAccuracy: {accuracy}%\nEvasion: {evasion}%\nCritical: {crit_chance}%"
         ))
         .block(Block::default().title("Stats").borders(Borders::ALL));
         paragraph.render(area, buf);
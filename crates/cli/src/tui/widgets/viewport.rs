@@ -1,4 +1,4 @@
-use corelib::{GameState, Position, Tile};
+use corelib::{GameState, Position, Tile, TileVisibility};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -25,6 +25,7 @@ impl Widget for ViewportWidget<'_> {
         let dungeon = self.state.dungeon();
         let player = self.state.player();
         let player_pos = player.position();
+        let fog = self.state.fog_of_war();
 
         let width = inner.width;
         let height = inner.height;
@@ -38,15 +39,72 @@ impl Widget for ViewportWidget<'_> {
                 let dy = y as i32 - (height / 2) as i32;
 
                 let pos = Position::new(player_x + dx, player_y + dy);
-                let tile = dungeon.get_tile(pos);
+                let visibility = fog.visibility(pos);
 
-                let (mut symbol, mut color) = match tile {
-                    Tile::Floor => ("_", Color::Green),
-                    Tile::Empty => (".", Color::Black),
-                };
+                let (mut symbol, mut color) =
+                    match (visibility, dungeon.get_tile(pos)) {
+                        (TileVisibility::Unknown, _) => (" ", Color::Black),
+                        (TileVisibility::Remembered, Tile::Floor) => {
+                            ("_", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::Floor) => {
+                            ("_", Color::Green)
+                        },
+                        (TileVisibility::Remembered, Tile::StairsDown) => {
+                            (">", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::StairsDown) => {
+                            (">", Color::White)
+                        },
+                        (TileVisibility::Remembered, Tile::StairsUp) => {
+                            ("<", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::StairsUp) => {
+                            ("<", Color::White)
+                        },
+                        (TileVisibility::Remembered, Tile::Wall) => {
+                            ("#", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::Wall) => {
+                            ("#", Color::Gray)
+                        },
+                        (TileVisibility::Remembered, Tile::DoorClosed) => {
+                            ("+", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::DoorClosed) => {
+                            ("+", Color::Yellow)
+                        },
+                        (TileVisibility::Remembered, Tile::DoorOpen) => {
+                            ("'", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::DoorOpen) => {
+                            ("'", Color::Yellow)
+                        },
+                        (TileVisibility::Remembered, Tile::Water) => {
+                            ("~", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::Water) => {
+                            ("~", Color::Blue)
+                        },
+                        (TileVisibility::Remembered, Tile::Lava) => {
+                            ("~", Color::DarkGray)
+                        },
+                        (TileVisibility::Visible, Tile::Lava) => {
+                            ("~", Color::Red)
+                        },
+                        (_, Tile::Empty) => (".", Color::Black),
+                    };
 
+                if visibility == TileVisibility::Visible
+                    && !self.state.floor_items().at(pos).is_empty()
+                {
+                    symbol = "*";
+                    color = Color::Cyan;
+                }
                 // TODO: Fix it
-                if self.state.entities().iter().any(|e| e.position() == pos) {
+                if visibility == TileVisibility::Visible
+                    && self.state.entities().iter().any(|e| e.position() == pos)
+                {
                     symbol = "🐺";
                     color = Color::Red;
                 };
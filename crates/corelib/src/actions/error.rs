@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::{
+    GameStatus, Position, WorldError,
+    items::{EquipmentSlot, ItemId, SlotId},
+};
+
+/// Reasons why a player action was refused.
+///
+/// A refused action does not change the game and does not take a turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionError {
+    /// The game has already ended.
+    GameOver {
+        /// Status the game ended with.
+        status: GameStatus,
+    },
+    /// The player is dead.
+    Dead,
+    /// The level behind the stairs could not be generated.
+    LevelGeneration(WorldError),
+    /// Something blocks the way.
+    Blocked {
+        /// The blocked position.
+        position: Position,
+    },
+    /// There is nothing to attack.
+    NoTarget {
+        /// The attacked position.
+        position: Position,
+    },
+    /// There is no door to open or close.
+    NoDoor {
+        /// The position without a door.
+        position: Position,
+    },
+    /// There are no stairs leading the requested way.
+    NoStairs {
+        /// The player's position.
+        position: Position,
+    },
+    /// There is nothing to pick up.
+    NoItems {
+        /// The player's position.
+        position: Position,
+    },
+    /// The inventory does not hold the item.
+    ItemNotFound {
+        /// The missing item.
+        item_id: ItemId,
+    },
+    /// The item cannot be used.
+    NotUsable {
+        /// The item that was tried.
+        item_id: ItemId,
+    },
+    /// Zero items were asked for.
+    InvalidCount,
+    /// The inventory has no room for the item.
+    InventoryFull {
+        /// The item that does not fit.
+        item_id: ItemId,
+    },
+    /// The hotbar has no such slot.
+    InvalidSlot {
+        /// The requested slot.
+        slot: SlotId,
+    },
+    /// The hotbar slot already holds an item.
+    SlotOccupied {
+        /// The requested slot.
+        slot: SlotId,
+    },
+    /// The hotbar slot is empty.
+    SlotEmpty {
+        /// The requested slot.
+        slot: SlotId,
+    },
+    /// The item cannot be worn in the equipment slot.
+    NotEquippable {
+        /// The item that was tried.
+        item_id: ItemId,
+        /// The requested slot.
+        slot: EquipmentSlot,
+    },
+    /// Something is already worn in the equipment slot.
+    EquipmentSlotTaken {
+        /// The requested slot.
+        slot: EquipmentSlot,
+    },
+    /// Nothing is worn in the equipment slot.
+    NothingEquipped {
+        /// The requested slot.
+        slot: EquipmentSlot,
+    },
+    /// A two-handed weapon and the off hand cannot be used together.
+    TwoHanded {
+        /// The two-handed weapon.
+        item_id: ItemId,
+    },
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionError::GameOver { status } => {
+                write!(f, "the game is over ({status:?})")
+            },
+            ActionError::Dead => write!(f, "the player is dead"),
+            ActionError::LevelGeneration(error) => {
+                write!(f, "the next level could not be generated: {error}")
+            },
+            ActionError::Blocked { position } => {
+                write!(f, "the way to {position:?} is blocked")
+            },
+            ActionError::NoTarget { position } => {
+                write!(f, "there is nothing to attack at {position:?}")
+            },
+            ActionError::NoDoor { position } => {
+                write!(f, "there is no door at {position:?}")
+            },
+            ActionError::NoStairs { position } => {
+                write!(f, "there are no such stairs at {position:?}")
+            },
+            ActionError::NoItems { position } => {
+                write!(f, "there is nothing to pick up at {position:?}")
+            },
+            ActionError::ItemNotFound { item_id } => {
+                write!(f, "item {item_id} is not in the inventory")
+            },
+            ActionError::NotUsable { item_id } => {
+                write!(f, "item {item_id} cannot be used")
+            },
+            ActionError::InvalidCount => {
+                write!(f, "at least one item must be chosen")
+            },
+            ActionError::InventoryFull { item_id } => {
+                write!(f, "no room in the inventory for item {item_id}")
+            },
+            ActionError::InvalidSlot { slot } => {
+                write!(f, "hotbar slot {slot} does not exist")
+            },
+            ActionError::SlotOccupied { slot } => {
+                write!(f, "hotbar slot {slot} is already taken")
+            },
+            ActionError::SlotEmpty { slot } => {
+                write!(f, "hotbar slot {slot} is empty")
+            },
+            ActionError::NotEquippable { item_id, slot } => {
+                write!(f, "item {item_id} cannot be worn in the {slot}")
+            },
+            ActionError::EquipmentSlotTaken { slot } => {
+                write!(f, "something is already worn in the {slot}")
+            },
+            ActionError::NothingEquipped { slot } => {
+                write!(f, "nothing is worn in the {slot}")
+            },
+            ActionError::TwoHanded { item_id } => {
+                write!(f, "item {item_id} needs both hands")
+            },
+        }
+    }
+}
+
+impl std::error::Error for ActionError {}
@@ -1,20 +1,45 @@
+mod error;
+mod player_assign_hotbar;
 mod player_attack;
+mod player_clear_hotbar;
+mod player_close_door;
+mod player_drop_item;
 mod player_equip_item;
 mod player_move;
+mod player_open_door;
+mod player_pick_up;
 mod player_unequip_item;
+mod player_use_item;
+mod player_use_stairs;
+mod validate;
 
+pub use error::ActionError;
+pub(crate) use player_assign_hotbar::player_assign_hotbar;
 pub(crate) use player_attack::player_attack;
+pub(crate) use player_clear_hotbar::player_clear_hotbar;
+pub(crate) use player_close_door::player_close_door;
+pub(crate) use player_drop_item::player_drop_item;
 pub(crate) use player_equip_item::player_equip_item;
 pub(crate) use player_move::player_move;
+pub(crate) use player_open_door::player_open_door;
+pub(crate) use player_pick_up::player_pick_up;
 pub(crate) use player_unequip_item::player_unequip_item;
+pub(crate) use player_use_item::{
+    consumable_effect, player_use_hotbar_item, player_use_item,
+};
+pub(crate) use player_use_stairs::{
+    StairsDestination, player_use_stairs, stairs_destination,
+};
+pub(crate) use validate::validate_action;
 
 use crate::{
     direction::Direction,
-    items::{ItemId, SlotId},
+    items::{EquipmentSlot, ItemId, SlotId},
 };
 
 /// Represents an action that a player can take.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlayerAction {
     /// Skip the current turn.
     Skip,
@@ -22,16 +47,55 @@ pub enum PlayerAction {
     Move(Direction),
     /// Attack in the specified direction.
     Attack(Direction),
-    /// Equip an item in the specified slot.
+    /// Wear an item from the inventory in the specified equipment slot.
     EquipItem {
         /// The ID of the item to equip.
         item_id: ItemId,
         /// The slot to equip the item in.
-        slot: SlotId,
+        slot: EquipmentSlot,
     },
-    /// Unequip an item from the specified slot.
+    /// Take off the item worn in the specified equipment slot.
     UnequipItem {
         /// The slot to unequip the item from.
+        slot: EquipmentSlot,
+    },
+    /// Move a stack of consumables from the inventory to a hotbar slot.
+    AssignHotbar {
+        /// The ID of the item to assign.
+        item_id: ItemId,
+        /// The hotbar slot to put the items in.
+        slot: SlotId,
+    },
+    /// Move the items in a hotbar slot back to the inventory.
+    ClearHotbar {
+        /// The hotbar slot to clear.
+        slot: SlotId,
+    },
+    /// Take the stairs down to the next level.
+    Descend,
+    /// Take the stairs up to the previous level.
+    Ascend,
+    /// Open the door in the specified direction.
+    Open(Direction),
+    /// Close the door in the specified direction.
+    Close(Direction),
+    /// Pick up the items lying under the player.
+    PickUp,
+    /// Drop an item from the inventory under the player.
+    Drop {
+        /// The ID of the item to drop.
+        item_id: ItemId,
+        /// How many items to drop. Drops the whole stack if not set.
+        count: Option<u32>,
+    },
+    /// Use up one consumable item from the inventory.
+    UseItem {
+        /// The ID of the item to use.
+        item_id: ItemId,
+    },
+    /// Use up one consumable item from the specified hotbar slot.
+    UseHotbarItem {
+        /// The slot holding the item to use.
         slot: SlotId,
     },
 }
@@ -0,0 +1,90 @@
+use crate::{
+    GameState,
+    events::GameEvent,
+    items::{ItemId, SlotId},
+    step_result::StepContext,
+};
+
+/// Moves a stack of consumables from the inventory to a hotbar slot.
+pub(crate) fn player_assign_hotbar(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    item_id: ItemId,
+    slot: SlotId,
+) {
+    if state.hotbar.contains(slot) {
+        return;
+    }
+
+    let old_inventory = state.inventory.clone();
+    let old_hotbar = state.hotbar.clone();
+
+    let Some(stack) = state.inventory.take(item_id) else {
+        return;
+    };
+
+    state.hotbar.equip(stack, slot);
+
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+    step_context.diff().hotbar(old_hotbar.iter(), state.hotbar.iter());
+
+    step_context.add_event(GameEvent::PlayerAssignedHotbar { item_id, slot });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::{ActionError, PlayerAction},
+        actors::Actor,
+        dungeon::DungeonMap,
+        items::ItemStack,
+        position::Position,
+        rng::MyRng,
+    };
+
+    const POTION: ItemId = 3;
+
+    fn setup_state() -> GameState {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        for item_id in [0, POTION] {
+            assert!(
+                gs.inventory.add(ItemStack { item_id, count: 2 }, 10).is_ok()
+            );
+        }
+        gs
+    }
+
+    #[test]
+    fn assigning_moves_stack_from_inventory() {
+        let mut gs = setup_state();
+        let mut step_context = StepContext::default();
+        player_assign_hotbar(&mut gs, &mut step_context, POTION, 0);
+        let result = step_context.build();
+        assert!(matches!(
+            result.events.as_slices().0,
+            [GameEvent::PlayerAssignedHotbar { item_id: POTION, slot: 0 }]
+        ));
+        assert_eq!(
+            gs.hotbar.get(0),
+            Some(&ItemStack { item_id: POTION, count: 2 })
+        );
+        assert!(!gs.inventory.contains(POTION));
+    }
+
+    #[test]
+    fn only_consumables_go_to_the_hotbar() {
+        let mut gs = setup_state();
+        let refused = gs.apply_player_action(&PlayerAction::AssignHotbar {
+            item_id: 0,
+            slot: 0,
+        });
+        assert_eq!(refused.err(), Some(ActionError::NotUsable { item_id: 0 }));
+        assert!(!gs.hotbar.contains(0));
+    }
+}
@@ -1,37 +1,92 @@
 use crate::{
-    GameState, actors::ActorKind, direction::Direction, events::GameEvent,
-    mechanics::try_attack, step_result::StepContext,
+    GameState,
+    actors::ActorKind,
+    direction::Direction,
+    events::GameEvent,
+    items::{EquipmentSlot, ItemDef},
+    mechanics::{drop_loot, inflict_on_hit, refresh_entity_stats, try_attack},
+    step_result::StepContext,
+    walk_map::WalkMap,
 };
 
 /// Attacks the enemy in the specified direction.
+///
+/// The attack may miss. A hit inflicts the effects of the player's kind and
+/// of the wielded weapon.
+///
+/// A killed enemy is removed from the game, its tile is freed and its loot is
+/// dropped there.
+/// Killing the last enemy of the deepest level wins the game.
 pub(crate) fn player_attack(
     state: &mut GameState,
     step_context: &mut StepContext,
     direction: Direction,
+    walk_map: &mut WalkMap,
 ) {
     let player_position = state.player.position();
     let target_position = player_position + direction.to_offset_position();
 
-    let Some(target) = state
-        .entities
-        .iter_mut()
-        .find(|a| a.position == target_position && a.kind != ActorKind::Player)
-    else {
-        step_context.add_event(GameEvent::PlayerAttackMissed);
+    let Some(index) = state.entities.iter().position(|a| {
+        a.position == target_position && a.kind != ActorKind::Player
+    }) else {
         return;
     };
+    let target = &mut state.entities[index];
 
-    let damage = try_attack(&mut state.player, target, &mut state.rng);
+    let outcome =
+        try_attack(step_context, &mut state.player, target, &mut state.rng);
 
     step_context
-        .add_event(GameEvent::PlayerAttacked { target: target.id(), damage });
+        .add_event(GameEvent::PlayerAttacked { target: target.id(), outcome });
+
+    let weapon = state
+        .equipment
+        .get(EquipmentSlot::MainHand)
+        .and_then(|stack| state.content.items.get(stack.item_id));
+    let on_hit = state
+        .content
+        .actors
+        .on_hit(state.player.kind)
+        .iter()
+        .chain(weapon.map_or(&[][..], ItemDef::on_hit));
+    if outcome.is_hit()
+        && inflict_on_hit(
+            step_context,
+            &state.content.effects,
+            on_hit,
+            target,
+            &mut state.rng,
+        )
+    {
+        refresh_entity_stats(step_context, &state.content, target);
+    }
+
+    if !target.is_alive() {
+        let corpse = state.entities.remove(index);
+        walk_map.release(corpse.position);
+        step_context.diff().despawn(corpse.id);
+        step_context.add_event(GameEvent::EntityDied { id: corpse.id });
+        drop_loot(
+            step_context,
+            &state.content.loot,
+            &mut state.items,
+            &corpse,
+            &mut state.rng,
+        );
+
+        state.check_victory();
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use crate::{
-        actors::Actor, dungeon::DungeonMap, position::Position, rng::MyRng,
+        actors::Actor,
+        dungeon::DungeonMap,
+        items::{ItemKind, ItemStack},
+        position::Position,
+        rng::MyRng,
     };
 
     use super::*;
@@ -49,7 +104,13 @@ mod tests {
             DungeonMap::simple(10, 10),
             MyRng::from_seed([0; 32]),
         );
-        player_attack(&mut gs, &mut StepContext::default(), Direction::North);
+        let mut walk_map = gs.recalculate_walk_map();
+        player_attack(
+            &mut gs,
+            &mut StepContext::default(),
+            Direction::North,
+            &mut walk_map,
+        );
         assert_eq!(gs.entities[0].stats.hp, 20);
         assert_eq!(gs.entities[0].position, Position::new(1, 0));
         assert_eq!(gs.entities[1].stats.hp, 20);
@@ -73,19 +134,82 @@ mod tests {
             DungeonMap::simple(10, 10),
             MyRng::from_seed([0; 32]),
         );
-        player_attack(&mut gs, &mut StepContext::default(), Direction::North);
-        player_attack(&mut gs, &mut StepContext::default(), Direction::North);
-        player_attack(&mut gs, &mut StepContext::default(), Direction::North);
-        player_attack(&mut gs, &mut StepContext::default(), Direction::North);
-        player_attack(&mut gs, &mut StepContext::default(), Direction::North);
+        let dead_id = gs.entities[3].id();
+        let mut walk_map = gs.recalculate_walk_map();
+        let mut step_context = StepContext::default();
+        for _ in 0..5 {
+            player_attack(
+                &mut gs,
+                &mut step_context,
+                Direction::North,
+                &mut walk_map,
+            );
+        }
+        let events = step_context.build().events;
+
+        assert_eq!(gs.entities.len(), 3);
         assert_eq!(gs.entities[0].stats.hp, 20);
         assert_eq!(gs.entities[0].position, Position::new(1, 0));
         assert_eq!(gs.entities[1].stats.hp, 20);
         assert_eq!(gs.entities[1].position, Position::new(-1, 0));
         assert_eq!(gs.entities[2].stats.hp, 20);
         assert_eq!(gs.entities[2].position, Position::new(0, 1));
-        assert_eq!(gs.entities[3].stats.hp, 0);
-        assert_eq!(gs.entities[3].position, Position::new(0, -1));
-        assert!(!gs.entities[3].is_alive());
+        assert!(gs.entities.iter().all(|e| e.id() != dead_id));
+        assert!(walk_map.is_walkable(Position::new(0, -1)));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, GameEvent::EntityDied { id } if *id == dead_id))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn weapon_hits_inflict_effects() {
+        const FLAMING_SWORD: usize = 10;
+        const BURNING: usize = 10;
+
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position { x: 1, y: 0 }, ActorKind::Enemy)],
+            DungeonMap::simple(10, 10),
+            MyRng::from_seed([0; 32]),
+        );
+        if let Some(ItemKind::Weapon { on_hit, .. }) = gs
+            .content
+            .items
+            .items
+            .get_mut(FLAMING_SWORD)
+            .map(|item| &mut item.kind)
+        {
+            on_hit[0].chance = 100;
+        }
+        gs.equipment.equip(
+            ItemStack { item_id: FLAMING_SWORD, count: 1 },
+            EquipmentSlot::MainHand,
+        );
+        gs.entities[0].stats.hp = 100;
+
+        let mut walk_map = gs.recalculate_walk_map();
+        let mut step_context = StepContext::default();
+        player_attack(
+            &mut gs,
+            &mut step_context,
+            Direction::East,
+            &mut walk_map,
+        );
+
+        let enemy = &gs.entities[0];
+        assert_eq!(enemy.effects.len(), 1);
+        assert_eq!(enemy.effects[0].effect_id, BURNING);
+        assert!(step_context.build().events.contains(
+            &GameEvent::EffectApplied {
+                entity_id: enemy.id(),
+                effect_id: BURNING,
+                name: "burning".to_string(),
+                duration: 3,
+            }
+        ));
     }
 }
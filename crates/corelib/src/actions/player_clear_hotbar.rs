@@ -0,0 +1,106 @@
+use crate::{
+    GameState, events::GameEvent, items::SlotId, step_result::StepContext,
+};
+
+/// Moves the stack in a hotbar slot back to the inventory.
+pub(crate) fn player_clear_hotbar(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    slot: SlotId,
+) {
+    if !state.hotbar.contains(slot) {
+        return;
+    }
+
+    let old_inventory = state.inventory.clone();
+    let old_hotbar = state.hotbar.clone();
+
+    let Some(stack) = state.hotbar.take(slot) else {
+        return;
+    };
+
+    let item_id = stack.item_id;
+    let max_stack = state.content.items.max_stack(item_id);
+    match state.inventory.add(stack, max_stack) {
+        Ok(()) => {
+            step_context.add_event(GameEvent::PlayerClearedHotbar { slot });
+        },
+        Err(leftover) => {
+            // Validation refuses the action when the stack does not fit, so
+            // this only guards against calls that skip it. Whatever did not
+            // fit stays in the hotbar rather than being lost.
+            state.hotbar.equip(leftover, slot);
+            step_context.add_event(GameEvent::PlayerInventoryFull { item_id });
+        },
+    }
+
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+    step_context.diff().hotbar(old_hotbar.iter(), state.hotbar.iter());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::player_assign_hotbar, actors::Actor, dungeon::DungeonMap,
+        items::ItemStack, position::Position, rng::MyRng,
+    };
+
+    const POTION: usize = 3;
+
+    fn setup_state() -> GameState {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        assert!(
+            gs.inventory
+                .add(ItemStack { item_id: POTION, count: 1 }, 10)
+                .is_ok()
+        );
+        gs
+    }
+
+    #[test]
+    fn clearing_moves_stack_back_to_inventory() {
+        let mut gs = setup_state();
+        player_assign_hotbar(&mut gs, &mut StepContext::default(), POTION, 0);
+        let mut step_context = StepContext::default();
+        player_clear_hotbar(&mut gs, &mut step_context, 0);
+        let result = step_context.build();
+        assert!(matches!(
+            result.events.as_slices().0,
+            [GameEvent::PlayerClearedHotbar { slot: 0 }]
+        ));
+        assert!(!gs.hotbar.contains(0));
+        assert!(gs.inventory.contains(POTION));
+    }
+
+    #[test]
+    fn clearing_keeps_stack_when_inventory_is_full() {
+        let mut gs = setup_state();
+        player_assign_hotbar(&mut gs, &mut StepContext::default(), POTION, 0);
+        while gs.inventory.add(ItemStack { item_id: 1, count: 1 }, 1).is_ok() {}
+
+        let mut step_context = StepContext::default();
+        player_clear_hotbar(&mut gs, &mut step_context, 0);
+        let result = step_context.build();
+        assert!(matches!(
+            result.events.as_slices().0,
+            [GameEvent::PlayerInventoryFull { item_id: POTION }]
+        ));
+        assert!(gs.hotbar.contains(0));
+        assert!(result.diff.hotbar.is_empty());
+    }
+
+    #[test]
+    fn cannot_clear_empty_slot() {
+        let mut gs = setup_state();
+        let mut step_context = StepContext::default();
+        player_clear_hotbar(&mut gs, &mut step_context, 0);
+        let result = step_context.build();
+        assert!(result.events.is_empty());
+    }
+}
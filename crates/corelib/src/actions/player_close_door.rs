@@ -0,0 +1,95 @@
+use crate::{
+    GameState, direction::Direction, events::GameEvent,
+    mechanics::try_close_door, step_result::StepContext, walk_map::WalkMap,
+};
+
+/// Closes the door in the specified direction.
+pub(crate) fn player_close_door(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    direction: Direction,
+    walk_map: &mut WalkMap,
+) {
+    let position = state.player.position + direction.to_offset_position();
+    if try_close_door(step_context, &mut state.dungeon, walk_map, position) {
+        step_context.add_event(GameEvent::PlayerClosedDoor { position });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        GameState, Tile,
+        actions::{ActionError, PlayerAction},
+        actors::{Actor, ActorKind},
+        direction::Direction,
+        dungeon::DungeonMap,
+        events::GameEvent,
+        position::Position,
+        rng::MyRng,
+    };
+
+    fn setup_state(enemies: Vec<Actor>) -> GameState {
+        let mut dungeon = DungeonMap::simple(10, 10);
+        dungeon.set_tile(Position::new(1, 0), Tile::DoorOpen);
+        GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            enemies,
+            dungeon,
+            MyRng::new(),
+        )
+    }
+
+    #[test]
+    fn closed_door_blocks_movement() -> Result<(), ActionError> {
+        let mut gs = setup_state(vec![]);
+
+        let result =
+            gs.apply_player_action(&PlayerAction::Close(Direction::East))?;
+        assert_eq!(
+            result.events.front(),
+            Some(&GameEvent::PlayerClosedDoor {
+                position: Position::new(1, 0)
+            })
+        );
+        assert_eq!(result.diff.tiles[0].old, Tile::DoorOpen);
+        assert_eq!(result.diff.tiles[0].new, Tile::DoorClosed);
+
+        let refused =
+            gs.apply_player_action(&PlayerAction::Move(Direction::East));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::Blocked { position: Position::new(1, 0) })
+        );
+        assert_eq!(gs.player.position, Position::new(0, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn occupied_doorway_cannot_be_closed() {
+        // Skeletons never move, so the doorway stays occupied
+        let mut gs = setup_state(vec![Actor::create(
+            Position::new(1, 0),
+            ActorKind::Skeleton,
+        )]);
+
+        let refused =
+            gs.apply_player_action(&PlayerAction::Close(Direction::East));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::Blocked { position: Position::new(1, 0) })
+        );
+        assert_eq!(gs.dungeon.get_tile(Position::new(1, 0)), &Tile::DoorOpen);
+    }
+
+    #[test]
+    fn close_without_door() {
+        let mut gs = setup_state(vec![]);
+        let refused =
+            gs.apply_player_action(&PlayerAction::Close(Direction::North));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::NoDoor { position: Position::new(0, -1) })
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    GameState, events::GameEvent, items::ItemId, step_result::StepContext,
+};
+
+/// Drops an item from the inventory onto the floor under the player.
+///
+/// Without a count the whole stack is dropped, otherwise it is split.
+pub(crate) fn player_drop_item(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    item_id: ItemId,
+    count: Option<u32>,
+) {
+    let old_inventory = state.inventory.clone();
+
+    let stack = match count {
+        Some(count) => state.inventory.take_count(item_id, count),
+        None => state.inventory.take(item_id),
+    };
+    let Some(stack) = stack.filter(|stack| !stack.is_empty()) else {
+        return;
+    };
+    let count = stack.count;
+    state.items.add(state.player.position, stack);
+
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+    step_context.add_event(GameEvent::PlayerDroppedItem { item_id, count });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        GameState,
+        actions::{ActionError, PlayerAction},
+        actors::Actor,
+        direction::Direction,
+        dungeon::DungeonMap,
+        events::GameEvent,
+        items::ItemStack,
+        position::Position,
+        rng::MyRng,
+    };
+
+    fn setup_state() -> GameState {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 1, count: 1 }, 1).is_ok()
+        );
+        gs
+    }
+
+    #[test]
+    fn dropped_item_can_be_picked_up_again() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+
+        let result = gs.apply_player_action(&PlayerAction::Drop {
+            item_id: 1,
+            count: None,
+        })?;
+        assert_eq!(
+            result.events.front(),
+            Some(&GameEvent::PlayerDroppedItem { item_id: 1, count: 1 })
+        );
+        assert!(gs.inventory.iter().all(Option::is_none));
+        assert_eq!(gs.items.at(Position::new(0, 0)).len(), 1);
+
+        gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
+        gs.apply_player_action(&PlayerAction::Move(Direction::West))?;
+        gs.apply_player_action(&PlayerAction::PickUp)?;
+        assert_eq!(gs.inventory.iter().flatten().count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn drops_part_of_a_stack() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 3, count: 5 }, 10).is_ok()
+        );
+
+        let result = gs.apply_player_action(&PlayerAction::Drop {
+            item_id: 3,
+            count: Some(2),
+        })?;
+
+        assert_eq!(
+            result.events.front(),
+            Some(&GameEvent::PlayerDroppedItem { item_id: 3, count: 2 })
+        );
+        assert_eq!(
+            gs.items.at(Position::new(0, 0)),
+            [ItemStack { item_id: 3, count: 2 }]
+        );
+        assert_eq!(
+            gs.inventory.iter().flatten().find(|stack| stack.id() == 3),
+            Some(&ItemStack { item_id: 3, count: 3 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_drop_missing_item() {
+        let mut gs = setup_state();
+        let refused = gs.apply_player_action(&PlayerAction::Drop {
+            item_id: 0,
+            count: None,
+        });
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::ItemNotFound { item_id: 0 })
+        );
+        assert_eq!(gs.items.iter().count(), 0);
+    }
+}
@@ -1,26 +1,31 @@
 use crate::{
     GameState,
     events::GameEvent,
-    items::{ItemId, SlotId},
+    items::{EquipmentSlot, ItemId},
     step_result::StepContext,
 };
 
-/// Moves the player in the specified direction.
+/// Takes one item out of the inventory and wears it in the specified slot.
 pub(crate) fn player_equip_item(
     state: &mut GameState,
     step_context: &mut StepContext,
     item_id: ItemId,
-    slot: SlotId,
+    slot: EquipmentSlot,
 ) {
-    if state.hotbar.contains(slot) {
+    if state.equipment.get(slot).is_some() {
         return;
     }
 
-    let Some(stack) = state.inventory.take(item_id) else {
+    let old_inventory = state.inventory.clone();
+    let Some(stack) = state.inventory.take_count(item_id, 1) else {
         return;
     };
 
-    state.hotbar.equip(stack, slot);
+    state.equipment.equip(stack.clone(), slot);
+
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+    step_context.diff().equipment(slot, None, Some(stack));
+    state.refresh_player_stats(step_context);
 
     step_context.add_event(GameEvent::PlayerEquippedItem { item_id, slot });
 }
@@ -29,53 +34,122 @@ pub(crate) fn player_equip_item(
 mod tests {
     use super::*;
     use crate::{
-        actors::Actor, dungeon::DungeonMap, position::Position, rng::MyRng,
+        actions::{ActionError, PlayerAction},
+        actors::Actor,
+        dungeon::DungeonMap,
+        items::ItemStack,
+        position::Position,
+        rng::MyRng,
     };
 
+    const SWORD: ItemId = 0;
+    const GREATSWORD: ItemId = 1;
+    const ARMOR: ItemId = 2;
+    const SHIELD: ItemId = 4;
+
     fn setup_state() -> GameState {
-        GameState::new(
+        let mut gs = GameState::new(
             Actor::create_player(Position::new(0, 0)),
             vec![],
             DungeonMap::simple(5, 5),
             MyRng::new(),
-        )
+        );
+        for item_id in [SWORD, GREATSWORD, ARMOR, SHIELD] {
+            assert!(
+                gs.inventory.add(ItemStack { item_id, count: 1 }, 1).is_ok()
+            );
+        }
+        gs
+    }
+
+    fn equip(item_id: ItemId, slot: EquipmentSlot) -> PlayerAction {
+        PlayerAction::EquipItem { item_id, slot }
     }
 
     #[test]
     fn equipping_item_moves_it_from_inventory() {
         let mut gs = setup_state();
         let mut step_context = StepContext::default();
-        player_equip_item(&mut gs, &mut step_context, 0, 0);
+        player_equip_item(
+            &mut gs,
+            &mut step_context,
+            SWORD,
+            EquipmentSlot::MainHand,
+        );
         let result = step_context.build();
         assert!(matches!(
             result.events.as_slices().0,
-            [GameEvent::PlayerEquippedItem { item_id: 0, slot: 0 }]
+            [GameEvent::PlayerEquippedItem {
+                item_id: SWORD,
+                slot: EquipmentSlot::MainHand
+            }]
         ));
-        assert!(gs.hotbar.contains(0));
+        assert_eq!(result.diff.equipment.len(), 1);
+        assert_eq!(result.diff.equipment[0].slot, EquipmentSlot::MainHand);
+        assert!(!gs.inventory.contains(SWORD));
+    }
+
+    #[test]
+    fn items_only_fit_their_slot() {
+        let mut gs = setup_state();
+        assert_eq!(
+            gs.apply_player_action(&equip(SWORD, EquipmentSlot::Head)).err(),
+            Some(ActionError::NotEquippable {
+                item_id: SWORD,
+                slot: EquipmentSlot::Head
+            })
+        );
+        assert_eq!(
+            gs.apply_player_action(&equip(ARMOR, EquipmentSlot::OffHand)).err(),
+            Some(ActionError::NotEquippable {
+                item_id: ARMOR,
+                slot: EquipmentSlot::OffHand
+            })
+        );
         assert!(
-            gs.inventory
-                .iter()
-                .all(|slot| slot.as_ref().is_none_or(|s| s.id() != 0))
+            gs.apply_player_action(&equip(ARMOR, EquipmentSlot::Body)).is_ok()
         );
     }
 
     #[test]
-    fn cannot_equip_if_slot_taken_or_item_missing() {
+    fn one_weapon_at_a_time() -> Result<(), ActionError> {
         let mut gs = setup_state();
-        // First equip item 0 into slot 0
-        let mut step_context = StepContext::default();
-        player_equip_item(&mut gs, &mut step_context, 0, 0);
+        gs.apply_player_action(&equip(SWORD, EquipmentSlot::MainHand))?;
+        let max_damage = gs.player.stats.max_damage;
 
-        // Try to equip another item into same slot
-        let mut step_context2 = StepContext::default();
-        player_equip_item(&mut gs, &mut step_context2, 1, 0);
-        let result2 = step_context2.build();
-        assert!(result2.events.is_empty());
+        assert!(
+            gs.inventory.add(ItemStack { item_id: SWORD, count: 1 }, 1).is_ok()
+        );
+        assert_eq!(
+            gs.apply_player_action(&equip(SWORD, EquipmentSlot::MainHand))
+                .err(),
+            Some(ActionError::EquipmentSlotTaken {
+                slot: EquipmentSlot::MainHand
+            })
+        );
+        assert_eq!(gs.player.stats.max_damage, max_damage);
+        Ok(())
+    }
 
-        // Try to equip non-existent item
-        let mut step_context3 = StepContext::default();
-        player_equip_item(&mut gs, &mut step_context3, 999, 1);
-        let result3 = step_context3.build();
-        assert!(result3.events.is_empty());
+    #[test]
+    fn two_handed_weapons_need_both_hands() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        gs.apply_player_action(&equip(SHIELD, EquipmentSlot::OffHand))?;
+        assert_eq!(
+            gs.apply_player_action(&equip(GREATSWORD, EquipmentSlot::MainHand))
+                .err(),
+            Some(ActionError::TwoHanded { item_id: GREATSWORD })
+        );
+
+        gs.apply_player_action(&PlayerAction::UnequipItem {
+            slot: EquipmentSlot::OffHand,
+        })?;
+        gs.apply_player_action(&equip(GREATSWORD, EquipmentSlot::MainHand))?;
+        assert_eq!(
+            gs.apply_player_action(&equip(SHIELD, EquipmentSlot::OffHand))
+                .err(),
+            Some(ActionError::TwoHanded { item_id: GREATSWORD })
+        );
+        Ok(())
     }
 }
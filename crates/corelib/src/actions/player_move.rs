@@ -1,9 +1,15 @@
 use crate::{
-    GameState, direction::Direction, events::GameEvent, mechanics::try_move,
-    step_result::StepContext, walk_map::WalkMap,
+    GameState,
+    direction::Direction,
+    events::GameEvent,
+    mechanics::{enter_tile, try_move},
+    step_result::StepContext,
+    walk_map::WalkMap,
 };
 
 /// Moves the player in the specified direction.
+///
+/// Stepping on a harmful tile hurts the player.
 pub(crate) fn player_move(
     state: &mut GameState,
     step_context: &mut StepContext,
@@ -11,29 +17,31 @@ pub(crate) fn player_move(
     walk_map: &mut WalkMap,
 ) {
     if let Some((old_position, new_position)) =
-        try_move(&mut state.player, direction, walk_map)
+        try_move(step_context, &mut state.player, direction, walk_map)
     {
         step_context.add_event(GameEvent::PlayerMoved {
             from: old_position,
             to: new_position,
         });
-    } else {
-        step_context.add_event(GameEvent::PlayerBumped {
-            position: state.player.position,
-            direction,
-        });
+        enter_tile(step_context, &mut state.player, &state.dungeon);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        GameState, actions::PlayerAction, actors::Actor, direction::Direction,
-        dungeon::DungeonMap, position::Position, rng::MyRng,
+        GameState,
+        actions::{ActionError, PlayerAction},
+        actors::Actor,
+        direction::Direction,
+        dungeon::{DungeonMap, Tile},
+        events::GameEvent,
+        position::Position,
+        rng::MyRng,
     };
 
     #[test]
-    fn test_player_movement() {
+    fn test_player_movement() -> Result<(), ActionError> {
         // North
         {
             let mut gs = GameState::new(
@@ -42,7 +50,7 @@ mod tests {
                 DungeonMap::simple(10, 10),
                 MyRng::new(),
             );
-            gs.apply_player_action(&PlayerAction::Move(Direction::North));
+            gs.apply_player_action(&PlayerAction::Move(Direction::North))?;
             assert_eq!(gs.player.position, Position::new(1, 0));
         }
 
@@ -54,7 +62,7 @@ mod tests {
                 DungeonMap::simple(10, 10),
                 MyRng::new(),
             );
-            gs.apply_player_action(&PlayerAction::Move(Direction::South));
+            gs.apply_player_action(&PlayerAction::Move(Direction::South))?;
             assert_eq!(gs.player.position, Position::new(1, 2));
         }
 
@@ -66,7 +74,7 @@ mod tests {
                 DungeonMap::simple(10, 10),
                 MyRng::new(),
             );
-            gs.apply_player_action(&PlayerAction::Move(Direction::East));
+            gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
             assert_eq!(gs.player.position, Position::new(2, 1));
         }
 
@@ -78,13 +86,14 @@ mod tests {
                 DungeonMap::simple(10, 10),
                 MyRng::new(),
             );
-            gs.apply_player_action(&PlayerAction::Move(Direction::West));
+            gs.apply_player_action(&PlayerAction::Move(Direction::West))?;
             assert_eq!(gs.player.position, Position::new(0, 1));
         }
+        Ok(())
     }
 
     #[test]
-    fn test_move_only_to_walkable() {
+    fn test_move_only_to_walkable() -> Result<(), ActionError> {
         let mut gs = GameState::new(
             Actor::create_player(Position::new(0, -5)),
             vec![],
@@ -93,11 +102,50 @@ mod tests {
         );
 
         // Not allowed to move
-        gs.apply_player_action(&PlayerAction::Move(Direction::North));
+        let refused =
+            gs.apply_player_action(&PlayerAction::Move(Direction::North));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::Blocked { position: Position::new(0, -6) })
+        );
         assert_eq!(gs.player.position, Position::new(0, -5));
 
         // Allowed to move to walkable
-        gs.apply_player_action(&PlayerAction::Move(Direction::East));
+        gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
         assert_eq!(gs.player.position, Position::new(1, -5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_respects_terrain() -> Result<(), ActionError> {
+        let mut dungeon = DungeonMap::simple(10, 10);
+        dungeon.set_tile(Position::new(2, 1), Tile::Wall);
+        dungeon.set_tile(Position::new(1, 2), Tile::Lava);
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(1, 1)),
+            vec![],
+            dungeon,
+            MyRng::new(),
+        );
+        let hp = gs.player.stats.hp;
+
+        let refused =
+            gs.apply_player_action(&PlayerAction::Move(Direction::East));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::Blocked { position: Position::new(2, 1) })
+        );
+        assert_eq!(gs.player.position, Position::new(1, 1));
+
+        let result =
+            gs.apply_player_action(&PlayerAction::Move(Direction::South))?;
+        assert_eq!(gs.player.position, Position::new(1, 2));
+        assert_eq!(gs.player.stats.hp, hp - 5);
+        assert!(result.events.contains(&GameEvent::EntityHurtByTerrain {
+            id: gs.player.id,
+            position: Position::new(1, 2),
+            damage: 5,
+        }));
+        Ok(())
     }
 }
@@ -0,0 +1,114 @@
+use crate::{
+    GameState, direction::Direction, events::GameEvent,
+    mechanics::try_open_door, step_result::StepContext, walk_map::WalkMap,
+};
+
+/// Opens the door in the specified direction.
+pub(crate) fn player_open_door(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    direction: Direction,
+    walk_map: &mut WalkMap,
+) {
+    let position = state.player.position + direction.to_offset_position();
+    if try_open_door(step_context, &mut state.dungeon, walk_map, position) {
+        step_context.add_event(GameEvent::PlayerOpenedDoor { position });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        GameState, Tile,
+        actions::{ActionError, PlayerAction},
+        actors::{Actor, ActorKind},
+        direction::Direction,
+        dungeon::DungeonMap,
+        events::GameEvent,
+        position::Position,
+        rng::MyRng,
+    };
+
+    fn setup_state(enemies: Vec<Actor>) -> GameState {
+        let mut dungeon = DungeonMap::simple(10, 10);
+        dungeon.set_tile(Position::new(1, 0), Tile::DoorClosed);
+        GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            enemies,
+            dungeon,
+            MyRng::new(),
+        )
+    }
+
+    #[test]
+    fn door_can_be_walked_through_once_open() -> Result<(), ActionError> {
+        let mut gs = setup_state(vec![]);
+
+        let refused =
+            gs.apply_player_action(&PlayerAction::Move(Direction::East));
+        assert!(refused.is_err());
+        assert_eq!(gs.player.position, Position::new(0, 0));
+
+        let result =
+            gs.apply_player_action(&PlayerAction::Open(Direction::East))?;
+        assert_eq!(
+            result.events.front(),
+            Some(&GameEvent::PlayerOpenedDoor {
+                position: Position::new(1, 0)
+            })
+        );
+        assert_eq!(result.diff.tiles.len(), 1);
+        assert_eq!(result.diff.tiles[0].new, Tile::DoorOpen);
+
+        gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
+        assert_eq!(gs.player.position, Position::new(1, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn open_without_door() {
+        let mut gs = setup_state(vec![]);
+        let refused =
+            gs.apply_player_action(&PlayerAction::Open(Direction::West));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::NoDoor { position: Position::new(-1, 0) })
+        );
+    }
+
+    #[test]
+    fn closed_door_blocks_sight() -> Result<(), ActionError> {
+        let mut gs = setup_state(vec![]);
+        assert!(!gs.can_see(&gs.player, Position::new(2, 0)));
+
+        gs.apply_player_action(&PlayerAction::Open(Direction::East))?;
+        assert!(gs.can_see(&gs.player, Position::new(2, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn enemy_opens_door_on_its_path() -> Result<(), ActionError> {
+        let mut gs = setup_state(vec![Actor::create(
+            Position::new(2, 0),
+            ActorKind::Enemy,
+        )]);
+        gs.player.position = Position::new(0, 1);
+        // Lava lets the enemy see the player, but makes the door cheaper
+        for y in -10..10 {
+            if y != 0 {
+                gs.dungeon.set_tile(Position::new(1, y), Tile::Lava);
+            }
+        }
+        let id = gs.entities[0].id();
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+
+        assert!(result.events.contains(&GameEvent::EntityOpenedDoor {
+            id,
+            position: Position::new(1, 0)
+        }));
+        assert_eq!(gs.dungeon.get_tile(Position::new(1, 0)), &Tile::DoorOpen);
+        assert_eq!(gs.entities[0].position, Position::new(2, 0));
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{GameState, events::GameEvent, step_result::StepContext};
+
+/// Picks up the items lying under the player.
+///
+/// Stackable items are merged into the stacks already in the inventory. Items
+/// that do not fit into the inventory stay on the floor.
+pub(crate) fn player_pick_up(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+) {
+    let position = state.player.position;
+    let stacks = state.items.take_all(position);
+    let old_inventory = state.inventory.clone();
+    for stack in stacks {
+        let (item_id, count) = (stack.item_id, stack.count);
+        let max_stack = state.content.items.max_stack(item_id);
+        let leftover = state.inventory.add(stack, max_stack).err();
+
+        let left = leftover.as_ref().map_or(0, |stack| stack.count);
+        if left < count {
+            step_context.add_event(GameEvent::PlayerPickedUpItem {
+                item_id,
+                count: count - left,
+            });
+        }
+        if let Some(leftover) = leftover {
+            state.items.add(position, leftover);
+            step_context.add_event(GameEvent::PlayerInventoryFull { item_id });
+        }
+    }
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        GameState,
+        actions::{ActionError, PlayerAction},
+        actors::Actor,
+        dungeon::DungeonMap,
+        events::GameEvent,
+        items::ItemStack,
+        position::Position,
+        rng::MyRng,
+    };
+
+    fn setup_state() -> GameState {
+        GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        )
+    }
+
+    #[test]
+    fn picks_up_every_stack_on_the_tile() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        gs.items.add(Position::new(0, 0), ItemStack { item_id: 0, count: 1 });
+        gs.items.add(Position::new(0, 0), ItemStack { item_id: 2, count: 1 });
+
+        let result = gs.apply_player_action(&PlayerAction::PickUp)?;
+
+        assert!(gs.items.at(Position::new(0, 0)).is_empty());
+        assert_eq!(gs.inventory.iter().flatten().count(), 2);
+        assert_eq!(result.diff.inventory.len(), 2);
+        assert_eq!(
+            result.events.front(),
+            Some(&GameEvent::PlayerPickedUpItem { item_id: 0, count: 1 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn potions_merge_into_existing_stack() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        while gs.inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok() {}
+        let Some(_) = gs.inventory.take(0) else {
+            panic!("inventory must hold the swords");
+        };
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 3, count: 8 }, 10).is_ok()
+        );
+        gs.items.add(Position::new(0, 0), ItemStack { item_id: 3, count: 5 });
+
+        let result = gs.apply_player_action(&PlayerAction::PickUp)?;
+
+        assert_eq!(
+            gs.items.at(Position::new(0, 0)),
+            [ItemStack { item_id: 3, count: 3 }]
+        );
+        assert_eq!(
+            gs.inventory.iter().flatten().find(|stack| stack.id() == 3),
+            Some(&ItemStack { item_id: 3, count: 10 })
+        );
+        assert_eq!(
+            Vec::from(result.events),
+            [
+                GameEvent::PlayerPickedUpItem { item_id: 3, count: 2 },
+                GameEvent::PlayerInventoryFull { item_id: 3 },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_to_pick_up() {
+        let mut gs = setup_state();
+        let refused = gs.apply_player_action(&PlayerAction::PickUp);
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::NoItems { position: Position::new(0, 0) })
+        );
+    }
+
+    #[test]
+    fn items_stay_on_floor_when_inventory_is_full() {
+        let mut gs = setup_state();
+        while gs.inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok() {}
+        gs.items.add(Position::new(0, 0), ItemStack { item_id: 1, count: 1 });
+
+        let refused = gs.apply_player_action(&PlayerAction::PickUp);
+
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::InventoryFull { item_id: 1 })
+        );
+        assert_eq!(gs.items.at(Position::new(0, 0)).len(), 1);
+        assert_eq!(gs.tick_id, 0);
+    }
+}
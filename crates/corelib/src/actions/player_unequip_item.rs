@@ -1,24 +1,38 @@
 use crate::{
-    GameState, events::GameEvent, items::SlotId, step_result::StepContext,
+    GameState, events::GameEvent, items::EquipmentSlot,
+    step_result::StepContext,
 };
 
-/// Moves the player in the specified direction.
+/// Takes off the item worn in the specified slot and puts it back into the
+/// inventory.
 pub(crate) fn player_unequip_item(
     state: &mut GameState,
     step_context: &mut StepContext,
-    slot: SlotId,
+    slot: EquipmentSlot,
 ) {
-    if !state.hotbar.contains(slot) {
-        return;
-    }
-
-    let Some(stack) = state.hotbar.take(slot) else {
+    let old_inventory = state.inventory.clone();
+    let Some(stack) = state.equipment.take(slot) else {
         return;
     };
 
-    state.inventory.add(stack);
+    let item_id = stack.item_id;
+    let max_stack = state.content.items.max_stack(item_id);
+    match state.inventory.add(stack.clone(), max_stack) {
+        Ok(()) => {
+            step_context.diff().equipment(slot, Some(stack), None);
+            step_context.add_event(GameEvent::PlayerUnequippedItem { slot });
+        },
+        Err(leftover) => {
+            // Validation refuses the action when the item does not fit, so
+            // this only guards against calls that skip it. The item stays
+            // worn rather than being lost.
+            state.equipment.equip(leftover, slot);
+            step_context.add_event(GameEvent::PlayerInventoryFull { item_id });
+        },
+    }
 
-    step_context.add_event(GameEvent::PlayerUnequippedItem { slot });
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+    state.refresh_player_stats(step_context);
 }
 
 #[cfg(test)]
@@ -26,43 +40,79 @@ mod tests {
     use super::*;
     use crate::{
         actions::player_equip_item, actors::Actor, dungeon::DungeonMap,
-        position::Position, rng::MyRng,
+        items::ItemStack, position::Position, rng::MyRng,
     };
 
     fn setup_state() -> GameState {
-        GameState::new(
+        let mut gs = GameState::new(
             Actor::create_player(Position::new(0, 0)),
             vec![],
             DungeonMap::simple(5, 5),
             MyRng::new(),
-        )
+        );
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok()
+        );
+        gs
     }
 
     #[test]
     fn unequip_moves_item_back_to_inventory() {
         let mut gs = setup_state();
-        let mut step_context1 = StepContext::default();
-        player_equip_item(&mut gs, &mut step_context1, 0, 0);
-        let mut step_context2 = StepContext::default();
-        player_unequip_item(&mut gs, &mut step_context2, 0);
-        let result = step_context2.build();
+        let base_damage = gs.player.stats.max_damage;
+        player_equip_item(
+            &mut gs,
+            &mut StepContext::default(),
+            0,
+            EquipmentSlot::MainHand,
+        );
+        let mut step_context = StepContext::default();
+        player_unequip_item(
+            &mut gs,
+            &mut step_context,
+            EquipmentSlot::MainHand,
+        );
+        let result = step_context.build();
         assert!(matches!(
             result.events.as_slices().0,
-            [GameEvent::PlayerUnequippedItem { slot: 0 }]
+            [GameEvent::PlayerUnequippedItem { slot: EquipmentSlot::MainHand }]
         ));
-        assert!(!gs.hotbar.contains(0));
-        assert!(
-            gs.inventory
-                .iter()
-                .any(|slot| slot.as_ref().is_some_and(|s| s.id() == 0))
+        assert!(gs.equipment.get(EquipmentSlot::MainHand).is_none());
+        assert!(gs.inventory.contains(0));
+        assert_eq!(gs.player.stats.max_damage, base_damage);
+    }
+
+    #[test]
+    fn unequip_keeps_item_when_inventory_is_full() {
+        let mut gs = setup_state();
+        player_equip_item(
+            &mut gs,
+            &mut StepContext::default(),
+            0,
+            EquipmentSlot::MainHand,
         );
+        while gs.inventory.add(ItemStack { item_id: 1, count: 1 }, 1).is_ok() {}
+
+        let mut step_context = StepContext::default();
+        player_unequip_item(
+            &mut gs,
+            &mut step_context,
+            EquipmentSlot::MainHand,
+        );
+        let result = step_context.build();
+        assert!(matches!(
+            result.events.as_slices().0,
+            [GameEvent::PlayerInventoryFull { item_id: 0 }]
+        ));
+        assert!(gs.equipment.get(EquipmentSlot::MainHand).is_some());
+        assert!(result.diff.equipment.is_empty());
     }
 
     #[test]
     fn cannot_unequip_empty_slot() {
         let mut gs = setup_state();
         let mut step_context = StepContext::default();
-        player_unequip_item(&mut gs, &mut step_context, 0);
+        player_unequip_item(&mut gs, &mut step_context, EquipmentSlot::Head);
         let result = step_context.build();
         assert!(result.events.is_empty());
     }
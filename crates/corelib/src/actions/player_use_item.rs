@@ -0,0 +1,184 @@
+use crate::{
+    GameState,
+    effects::EffectId,
+    events::GameEvent,
+    items::{ItemDef, ItemId, SlotId},
+    mechanics::apply_effect,
+    step_result::StepContext,
+};
+
+/// Uses up one consumable item from the inventory.
+pub(crate) fn player_use_item(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    item_id: ItemId,
+) {
+    let Some((effect_id, duration)) = consumable_effect(state, item_id) else {
+        return;
+    };
+
+    let old_inventory = state.inventory.clone();
+    if state.inventory.take_count(item_id, 1).is_none() {
+        return;
+    }
+    step_context.diff().inventory(old_inventory.iter(), state.inventory.iter());
+
+    consume(state, step_context, item_id, effect_id, duration);
+}
+
+/// Uses up one consumable item from the specified hotbar slot.
+pub(crate) fn player_use_hotbar_item(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    slot: SlotId,
+) {
+    let Some(item_id) = state.hotbar.get(slot).map(|stack| stack.item_id)
+    else {
+        return;
+    };
+    let Some((effect_id, duration)) = consumable_effect(state, item_id) else {
+        return;
+    };
+
+    let old_hotbar = state.hotbar.clone();
+    if state.hotbar.take_count(slot, 1).is_none() {
+        return;
+    }
+    step_context.diff().hotbar(old_hotbar.iter(), state.hotbar.iter());
+
+    consume(state, step_context, item_id, effect_id, duration);
+}
+
+/// Returns the effect and its duration if the item is a consumable.
+pub(crate) fn consumable_effect(
+    state: &GameState,
+    item_id: ItemId,
+) -> Option<(EffectId, u8)> {
+    state.content.items.get(item_id).and_then(ItemDef::consumable_effect)
+}
+
+/// Applies the effect of a consumed item to the player.
+fn consume(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    item_id: ItemId,
+    effect_id: EffectId,
+    duration: u8,
+) {
+    step_context.add_event(GameEvent::ItemUsed { item_id });
+    apply_effect(
+        step_context,
+        &state.content.effects,
+        &mut state.player,
+        effect_id,
+        duration,
+    );
+    state.refresh_player_stats(step_context);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        GameState,
+        actions::{ActionError, PlayerAction},
+        actors::Actor,
+        dungeon::DungeonMap,
+        events::GameEvent,
+        items::ItemStack,
+        position::Position,
+        rng::MyRng,
+    };
+
+    const POTION: usize = 3;
+
+    fn setup_state() -> GameState {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        assert!(
+            gs.inventory
+                .add(ItemStack { item_id: POTION, count: 2 }, 10)
+                .is_ok()
+        );
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok()
+        );
+        gs
+    }
+
+    #[test]
+    fn potion_heals_over_time() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        gs.player.stats.hp = 10;
+
+        let result =
+            gs.apply_player_action(&PlayerAction::UseItem { item_id: POTION })?;
+        assert!(
+            result.events.contains(&GameEvent::ItemUsed { item_id: POTION })
+        );
+        assert!(result.events.contains(&GameEvent::EffectApplied {
+            entity_id: gs.player.id,
+            effect_id: 0,
+            name: "healing".to_string(),
+            duration: 5,
+        }));
+        assert_eq!(
+            gs.inventory.iter().next(),
+            Some(&Some(ItemStack { item_id: POTION, count: 1 }))
+        );
+        assert_eq!(gs.player.effects.len(), 1);
+
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(gs.player.stats.hp, 11);
+        Ok(())
+    }
+
+    #[test]
+    fn last_potion_empties_the_slot() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        gs.apply_player_action(&PlayerAction::UseItem { item_id: POTION })?;
+        gs.apply_player_action(&PlayerAction::UseItem { item_id: POTION })?;
+        let refused =
+            gs.apply_player_action(&PlayerAction::UseItem { item_id: POTION });
+
+        assert_eq!(gs.inventory.iter().next(), Some(&None));
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::ItemNotFound { item_id: POTION })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn weapons_cannot_be_used() {
+        let mut gs = setup_state();
+        let refused =
+            gs.apply_player_action(&PlayerAction::UseItem { item_id: 0 });
+
+        assert_eq!(refused.err(), Some(ActionError::NotUsable { item_id: 0 }));
+        assert!(gs.player.effects.is_empty());
+    }
+
+    #[test]
+    fn potion_can_be_used_from_hotbar() -> Result<(), ActionError> {
+        let mut gs = setup_state();
+        gs.apply_player_action(&PlayerAction::AssignHotbar {
+            item_id: POTION,
+            slot: 4,
+        })?;
+
+        let result =
+            gs.apply_player_action(&PlayerAction::UseHotbarItem { slot: 4 })?;
+
+        assert_eq!(result.diff.hotbar.len(), 1);
+        assert_eq!(
+            gs.hotbar.get(4),
+            Some(&ItemStack { item_id: POTION, count: 1 })
+        );
+        assert_eq!(gs.player.effects.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+use std::mem;
+
+use crate::{
+    GameState,
+    actions::{ActionError, PlayerAction},
+    dungeon::Tile,
+    events::GameEvent,
+    level::Level,
+    rng::MyRng,
+    step_result::StepContext,
+};
+
+/// The level the player arrives at by taking the stairs.
+///
+/// The level itself waits in the game state until the player arrives, so it
+/// is not lost if the player never gets to take the stairs.
+#[derive(Clone, Copy)]
+pub(crate) struct StairsDestination {
+    depth: u32,
+}
+
+/// Looks up the level behind the stairs the player is standing on.
+///
+/// Levels visited before are already kept in the game state, new ones are
+/// generated from their own seed and stored alongside them. Returns `None`
+/// for other actions.
+///
+/// # Errors
+///
+/// Returns an error if the player is not standing on matching stairs, or if
+/// the level cannot be generated.
+pub(crate) fn stairs_destination(
+    state: &mut GameState,
+    action: &PlayerAction,
+) -> Result<Option<StairsDestination>, ActionError> {
+    let tile = state.dungeon.get_tile(state.player.position);
+    let depth = match (action, tile) {
+        (PlayerAction::Descend, Tile::StairsDown) => state.depth + 1,
+        (PlayerAction::Ascend, Tile::StairsUp) if state.depth > 0 => {
+            state.depth - 1
+        },
+        (PlayerAction::Descend | PlayerAction::Ascend, _) => {
+            return Err(ActionError::NoStairs {
+                position: state.player.position,
+            });
+        },
+        _ => return Ok(None),
+    };
+
+    if !state.levels.contains_key(&depth) {
+        let seed = Level::seed(state.settings.seed, depth);
+        let level = Level::generate(
+            &state.settings,
+            depth,
+            &mut MyRng::from_seed(seed),
+            state.next_entity_id,
+            &state.content,
+        )
+        .map_err(ActionError::LevelGeneration)?;
+        state.next_entity_id +=
+            u32::try_from(level.entities.len()).unwrap_or(u32::MAX);
+        state.levels.insert(depth, level);
+    }
+
+    Ok(Some(StairsDestination { depth }))
+}
+
+/// Takes the player through the stairs to the destination level.
+///
+/// The level the player leaves is kept as it is until the player returns.
+pub(crate) fn player_use_stairs(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    destination: Option<StairsDestination>,
+) {
+    let Some(StairsDestination { depth }) = destination else {
+        return;
+    };
+    let Some(level) = state.levels.remove(&depth) else {
+        return;
+    };
+
+    let arrival_tile =
+        if depth > state.depth { Tile::StairsUp } else { Tile::StairsDown };
+    let previous = Level {
+        dungeon: mem::replace(&mut state.dungeon, level.dungeon),
+        entities: mem::replace(&mut state.entities, level.entities),
+        explored: mem::replace(&mut state.explored, level.explored),
+        items: mem::replace(&mut state.items, level.items),
+    };
+
+    for entity in &previous.entities {
+        step_context.diff().despawn(entity.id);
+    }
+    for entity in &state.entities {
+        step_context.diff().spawn(entity);
+        step_context.add_event(GameEvent::EntityCreated {
+            id: entity.id,
+            position: entity.position,
+        });
+    }
+
+    let arrival =
+        state.dungeon.find_tile(&arrival_tile).unwrap_or(Level::SPAWN);
+    step_context.diff().position(
+        state.player.id,
+        state.player.position,
+        arrival,
+    );
+    state.player.position = arrival;
+
+    state.levels.insert(state.depth, previous);
+    step_context.add_event(GameEvent::PlayerChangedDepth {
+        from: state.depth,
+        to: depth,
+    });
+    state.depth = depth;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Position, WorldSettings, catalog::EffectInstance, new_game};
+
+    fn game() -> GameState {
+        let settings = WorldSettings {
+            seed: [5; 32],
+            map_width: 21,
+            map_height: 21,
+            floor_tiles: 150,
+            enemies: 2,
+            ..WorldSettings::default()
+        };
+        let Ok(state) = new_game(&settings) else {
+            panic!("test settings must be valid");
+        };
+        state
+    }
+
+    fn stand_on(state: &mut GameState, tile: &Tile) -> Position {
+        let Some(position) = state.dungeon.find_tile(tile) else {
+            panic!("level has no {tile:?}");
+        };
+        state.player.position = position;
+        position
+    }
+
+    #[test]
+    fn descend_requires_stairs() {
+        let mut state = game();
+        let refused = state.apply_player_action(&PlayerAction::Descend);
+
+        assert_eq!(state.depth(), 0);
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::NoStairs { position: state.player.position })
+        );
+    }
+
+    #[test]
+    fn descend_arrives_on_up_stairs() -> Result<(), ActionError> {
+        let mut state = game();
+        let first_ids: Vec<_> = state.entities.iter().map(|e| e.id).collect();
+        stand_on(&mut state, &Tile::StairsDown);
+
+        let result = state.apply_player_action(&PlayerAction::Descend)?;
+
+        assert_eq!(state.depth(), 1);
+        assert!(
+            result
+                .events
+                .contains(&GameEvent::PlayerChangedDepth { from: 0, to: 1 })
+        );
+        assert_eq!(
+            state.dungeon.get_tile(state.player.position),
+            &Tile::StairsUp
+        );
+        assert_eq!(result.diff.despawned, first_ids);
+        assert!(state.entities.iter().all(|e| !first_ids.contains(&e.id)));
+        Ok(())
+    }
+
+    #[test]
+    fn arriving_entities_are_announced() -> Result<(), ActionError> {
+        let mut state = game();
+        stand_on(&mut state, &Tile::StairsDown);
+
+        let result = state.apply_player_action(&PlayerAction::Descend)?;
+
+        let created: Vec<_> = result
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::EntityCreated { id, position } => {
+                    Some((*id, *position))
+                },
+                _ => None,
+            })
+            .collect();
+        let expected: Vec<_> =
+            result.diff.spawned.iter().map(|e| (e.id, e.position)).collect();
+        assert!(!expected.is_empty());
+        assert_eq!(created, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn deepest_level_has_no_down_stairs() -> Result<(), ActionError> {
+        let mut state = game();
+        state.settings.levels = 2;
+        stand_on(&mut state, &Tile::StairsDown);
+
+        state.apply_player_action(&PlayerAction::Descend)?;
+
+        assert_eq!(state.depth(), 1);
+        assert_eq!(state.dungeon.find_tile(&Tile::StairsDown), None);
+        Ok(())
+    }
+
+    #[test]
+    fn visited_levels_are_restored() -> Result<(), ActionError> {
+        let mut state = game();
+        let stairs = stand_on(&mut state, &Tile::StairsDown);
+        state.entities.truncate(1);
+
+        state.apply_player_action(&PlayerAction::Descend)?;
+        let second_ids: Vec<_> = state.entities.iter().map(|e| e.id).collect();
+        state.apply_player_action(&PlayerAction::Ascend)?;
+
+        assert_eq!(state.depth(), 0);
+        assert_eq!(state.player.position, stairs);
+        assert_eq!(state.entities.len(), 1);
+
+        state.apply_player_action(&PlayerAction::Descend)?;
+        let ids: Vec<_> = state.entities.iter().map(|e| e.id).collect();
+        assert_eq!(ids, second_ids);
+        Ok(())
+    }
+
+    #[test]
+    fn visited_level_survives_death_on_stairs() -> Result<(), ActionError> {
+        const POISON: usize = 2;
+
+        let mut state = game();
+        stand_on(&mut state, &Tile::StairsDown);
+        state.apply_player_action(&PlayerAction::Descend)?;
+        let second_ids: Vec<_> = state.entities.iter().map(|e| e.id).collect();
+        state.apply_player_action(&PlayerAction::Ascend)?;
+
+        state.entities.clear();
+        state.player.stats.hp = 1;
+        state
+            .player
+            .effects
+            .push(EffectInstance { effect_id: POISON, remaining_turns: 1 });
+        state.apply_player_action(&PlayerAction::Descend)?;
+
+        assert!(!state.player.is_alive());
+        assert_eq!(state.depth(), 0);
+        let Some(level) = state.levels.get(&1) else {
+            panic!("visited level was lost");
+        };
+        let ids: Vec<_> = level.entities.iter().map(|e| e.id).collect();
+        assert_eq!(ids, second_ids);
+        Ok(())
+    }
+
+    #[test]
+    fn level_layout_follows_world_seed() -> Result<(), ActionError> {
+        let mut first = game();
+        let mut second = game();
+        stand_on(&mut first, &Tile::StairsDown);
+        stand_on(&mut second, &Tile::StairsDown);
+        first.apply_player_action(&PlayerAction::Skip)?;
+
+        first.apply_player_action(&PlayerAction::Descend)?;
+        second.apply_player_action(&PlayerAction::Descend)?;
+
+        let tiles = |state: &GameState| {
+            state
+                .dungeon
+                .iter()
+                .map(|(_, tile)| tile.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tiles(&first), tiles(&second));
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{
+    GameState, Tile,
+    actions::{ActionError, PlayerAction, consumable_effect},
+    actors::ActorKind,
+    items::{EquipmentSlot, Hotbar, ItemDef, ItemId, ItemStack, SlotId},
+    walk_map::WalkMap,
+};
+
+/// Checks that the player action can be carried out.
+///
+/// Runs before anything changes, so a refused action leaves the game as it
+/// was. Stairs are checked when their destination is looked up.
+pub(crate) fn validate_action(
+    state: &GameState,
+    action: &PlayerAction,
+    walk_map: &WalkMap,
+) -> Result<(), ActionError> {
+    let player_position = state.player.position;
+    match action {
+        PlayerAction::Skip | PlayerAction::Descend | PlayerAction::Ascend => {
+            Ok(())
+        },
+        PlayerAction::Move(direction) => {
+            let position = player_position + direction.to_offset_position();
+            if walk_map.is_walkable(position) {
+                Ok(())
+            } else {
+                Err(ActionError::Blocked { position })
+            }
+        },
+        PlayerAction::Attack(direction) => {
+            let position = player_position + direction.to_offset_position();
+            if state.entities.iter().any(|entity| {
+                entity.position == position && entity.kind != ActorKind::Player
+            }) {
+                Ok(())
+            } else {
+                Err(ActionError::NoTarget { position })
+            }
+        },
+        PlayerAction::Open(direction) => {
+            let position = player_position + direction.to_offset_position();
+            if state.dungeon.get_tile(position) == &Tile::DoorClosed {
+                Ok(())
+            } else {
+                Err(ActionError::NoDoor { position })
+            }
+        },
+        PlayerAction::Close(direction) => {
+            let position = player_position + direction.to_offset_position();
+            if state.dungeon.get_tile(position) != &Tile::DoorOpen {
+                Err(ActionError::NoDoor { position })
+            } else if !walk_map.is_walkable(position) {
+                Err(ActionError::Blocked { position })
+            } else {
+                Ok(())
+            }
+        },
+        PlayerAction::EquipItem { item_id, slot } => {
+            validate_in_inventory(state, *item_id)?;
+            validate_equip(state, *item_id, *slot)
+        },
+        PlayerAction::UnequipItem { slot } => {
+            let stack = state
+                .equipment
+                .get(*slot)
+                .ok_or(ActionError::NothingEquipped { slot: *slot })?;
+            validate_fits(state, stack)
+        },
+        PlayerAction::AssignHotbar { item_id, slot } => {
+            validate_slot(*slot)?;
+            if state.hotbar.contains(*slot) {
+                return Err(ActionError::SlotOccupied { slot: *slot });
+            }
+            validate_in_inventory(state, *item_id)?;
+            validate_usable(state, *item_id)
+        },
+        PlayerAction::ClearHotbar { slot } => {
+            let stack = validate_hotbar_stack(state, *slot)?;
+            validate_fits(state, stack)
+        },
+        PlayerAction::PickUp => {
+            let stacks = state.items.at(player_position);
+            let Some(first) = stacks.first() else {
+                return Err(ActionError::NoItems { position: player_position });
+            };
+            // Picking up only part of the pile is fine
+            if stacks.iter().any(|stack| {
+                validate_fits(state, &ItemStack { count: 1, ..stack.clone() })
+                    .is_ok()
+            }) {
+                Ok(())
+            } else {
+                Err(ActionError::InventoryFull { item_id: first.item_id })
+            }
+        },
+        PlayerAction::Drop { item_id, count } => {
+            if *count == Some(0) {
+                return Err(ActionError::InvalidCount);
+            }
+            validate_in_inventory(state, *item_id)
+        },
+        PlayerAction::UseItem { item_id } => {
+            validate_in_inventory(state, *item_id)?;
+            validate_usable(state, *item_id)
+        },
+        PlayerAction::UseHotbarItem { slot } => {
+            let stack = validate_hotbar_stack(state, *slot)?;
+            validate_usable(state, stack.item_id)
+        },
+    }
+}
+
+/// Checks that the item fits the equipment slot and that the hands are free
+/// for it.
+fn validate_equip(
+    state: &GameState,
+    item_id: ItemId,
+    slot: EquipmentSlot,
+) -> Result<(), ActionError> {
+    let item = state.content.items.get(item_id);
+    if item.and_then(ItemDef::equipment_slot) != Some(slot) {
+        return Err(ActionError::NotEquippable { item_id, slot });
+    }
+    if state.equipment.get(slot).is_some() {
+        return Err(ActionError::EquipmentSlotTaken { slot });
+    }
+
+    let off_hand_taken = state.equipment.get(EquipmentSlot::OffHand).is_some();
+    if item.is_some_and(ItemDef::two_handed) && off_hand_taken {
+        return Err(ActionError::TwoHanded { item_id });
+    }
+    if slot != EquipmentSlot::OffHand {
+        return Ok(());
+    }
+    match state.equipment.get(EquipmentSlot::MainHand) {
+        Some(main_hand)
+            if state
+                .content
+                .items
+                .get(main_hand.item_id)
+                .is_some_and(ItemDef::two_handed) =>
+        {
+            Err(ActionError::TwoHanded { item_id: main_hand.item_id })
+        },
+        _ => Ok(()),
+    }
+}
+
+fn validate_slot(slot: SlotId) -> Result<(), ActionError> {
+    if Hotbar::has_slot(slot) {
+        Ok(())
+    } else {
+        Err(ActionError::InvalidSlot { slot })
+    }
+}
+
+fn validate_hotbar_stack(
+    state: &GameState,
+    slot: SlotId,
+) -> Result<&ItemStack, ActionError> {
+    validate_slot(slot)?;
+    state.hotbar.get(slot).ok_or(ActionError::SlotEmpty { slot })
+}
+
+fn validate_in_inventory(
+    state: &GameState,
+    item_id: ItemId,
+) -> Result<(), ActionError> {
+    if state.inventory.contains(item_id) {
+        Ok(())
+    } else {
+        Err(ActionError::ItemNotFound { item_id })
+    }
+}
+
+fn validate_fits(
+    state: &GameState,
+    stack: &ItemStack,
+) -> Result<(), ActionError> {
+    let max_stack = state.content.items.max_stack(stack.item_id);
+    if state.inventory.fits(stack, max_stack) {
+        Ok(())
+    } else {
+        Err(ActionError::InventoryFull { item_id: stack.item_id })
+    }
+}
+
+fn validate_usable(
+    state: &GameState,
+    item_id: ItemId,
+) -> Result<(), ActionError> {
+    if consumable_effect(state, item_id).is_some() {
+        Ok(())
+    } else {
+        Err(ActionError::NotUsable { item_id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actors::Actor, direction::Direction, dungeon::DungeonMap,
+        position::Position, rng::MyRng,
+    };
+
+    fn setup_state() -> GameState {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position::new(1, 1), ActorKind::Enemy)],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok()
+        );
+        gs
+    }
+
+    #[test]
+    fn refused_action_does_not_take_a_turn() {
+        let mut gs = setup_state();
+        gs.entities[0].position = Position::new(1, 0);
+        let hp = gs.player.stats.hp;
+
+        let refused =
+            gs.apply_player_action(&PlayerAction::Move(Direction::East));
+
+        assert_eq!(
+            refused.err(),
+            Some(ActionError::Blocked { position: Position::new(1, 0) })
+        );
+        assert_eq!(gs.tick_id, 0);
+        assert_eq!(gs.player.stats.hp, hp);
+    }
+
+    #[test]
+    fn hotbar_slots_are_checked() {
+        let mut gs = setup_state();
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 3, count: 2 }, 10).is_ok()
+        );
+        let assign = |slot| PlayerAction::AssignHotbar { item_id: 3, slot };
+
+        assert_eq!(
+            gs.apply_player_action(&assign(10)).err(),
+            Some(ActionError::InvalidSlot { slot: 10 })
+        );
+        assert!(gs.apply_player_action(&assign(0)).is_ok());
+        assert_eq!(
+            gs.apply_player_action(&assign(1)).err(),
+            Some(ActionError::ItemNotFound { item_id: 3 })
+        );
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::ClearHotbar { slot: 1 })
+                .err(),
+            Some(ActionError::SlotEmpty { slot: 1 })
+        );
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 3, count: 1 }, 10).is_ok()
+        );
+        assert_eq!(
+            gs.apply_player_action(&assign(0)).err(),
+            Some(ActionError::SlotOccupied { slot: 0 })
+        );
+    }
+
+    #[test]
+    fn equipment_slots_are_checked() {
+        let mut gs = setup_state();
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::EquipItem {
+                item_id: 2,
+                slot: EquipmentSlot::Body
+            })
+            .err(),
+            Some(ActionError::ItemNotFound { item_id: 2 })
+        );
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::UnequipItem {
+                slot: EquipmentSlot::Body
+            })
+            .err(),
+            Some(ActionError::NothingEquipped { slot: EquipmentSlot::Body })
+        );
+    }
+
+    #[test]
+    fn zero_items_cannot_be_dropped() {
+        let mut gs = setup_state();
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::Drop {
+                item_id: 0,
+                count: Some(0)
+            })
+            .err(),
+            Some(ActionError::InvalidCount)
+        );
+    }
+
+    #[test]
+    fn missing_targets_are_refused() {
+        let mut gs = setup_state();
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::Attack(Direction::East))
+                .err(),
+            Some(ActionError::NoTarget { position: Position::new(1, 0) })
+        );
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::Open(Direction::East)).err(),
+            Some(ActionError::NoDoor { position: Position::new(1, 0) })
+        );
+        assert_eq!(
+            gs.apply_player_action(&PlayerAction::PickUp).err(),
+            Some(ActionError::NoItems { position: Position::new(0, 0) })
+        );
+    }
+}
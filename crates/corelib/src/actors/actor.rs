@@ -1,15 +1,12 @@
-use std::sync::atomic::{AtomicU32, Ordering};
-
 use crate::{catalog::EffectInstance, position::Position};
 
 use super::{ActorKind, stats::Stats};
 
-/// Atomic counter for generating unique entity IDs.
-static ENTITY_ID_COUNTER: AtomicU32 = AtomicU32::new(0);
-
 /// Represents the unique identifier of an entity.
-/// Uniqueness is guaranteed by the atomic counter.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The game hands out identifiers in order and never reuses them (see
+/// [`crate::new_game`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityId(u32);
 
 impl From<u32> for EntityId {
@@ -30,15 +27,11 @@ impl EntityId {
     pub fn into_inner(self) -> u32 {
         self.0
     }
-
-    /// Creates a new unique entity ID.
-    pub fn next_entity_id() -> Self {
-        EntityId(ENTITY_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
-    }
 }
 
 /// Represents an actor in the game. e.g. Player, Enemy.
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Actor {
     /// The unique identifier of the actor.
     pub(crate) id: EntityId,
@@ -54,17 +47,30 @@ pub struct Actor {
 
 impl Actor {
     /// Creates a new actor with the given position and kind.
+    ///
+    /// Test actors get their IDs from a counter, so those in one test never
+    /// share one.
+    #[cfg(test)]
     pub(crate) fn create(position: Position, kind: ActorKind) -> Self {
-        Actor {
-            id: EntityId::next_entity_id(),
-            position,
-            stats: kind.default_stats(),
-            kind,
-            effects: Vec::new(),
-        }
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+        let id = EntityId(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+        Actor::create_with_stats(id, position, kind, kind.default_stats())
+    }
+
+    /// Creates a new actor with an explicitly assigned ID and starting stats.
+    pub(crate) fn create_with_stats(
+        id: EntityId,
+        position: Position,
+        kind: ActorKind,
+        stats: Stats,
+    ) -> Self {
+        Actor { id, position, stats, kind, effects: Vec::new() }
     }
 
     /// Creates a new player actor with the given position.
+    #[cfg(test)]
     pub(crate) fn create_player(position: Position) -> Self {
         Actor::create(position, ActorKind::Player)
     }
@@ -81,6 +87,12 @@ impl Actor {
         self.position
     }
 
+    /// Returns the kind of the actor.
+    #[must_use]
+    pub fn kind(&self) -> ActorKind {
+        self.kind
+    }
+
     /// Returns the stats of the actor.
     #[must_use]
     pub fn stats(&self) -> &Stats {
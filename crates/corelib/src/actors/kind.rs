@@ -1,8 +1,48 @@
+use std::fmt;
+
 /// Represents the kind of actor.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActorKind {
     /// Represents a player character.
     Player,
-    /// Represents an enemy character.
+    /// Represents an enemy character that chases the player.
     Enemy,
+    /// Represents a goblin that runs away when badly hurt.
+    Goblin,
+    /// Represents a skeleton that guards its spot.
+    Skeleton,
+    /// Represents a rat that wanders around.
+    Rat,
+}
+
+impl ActorKind {
+    /// Every kind, the player first.
+    pub const ALL: [ActorKind; 5] = [
+        ActorKind::Player,
+        ActorKind::Enemy,
+        ActorKind::Goblin,
+        ActorKind::Skeleton,
+        ActorKind::Rat,
+    ];
+
+    /// Kinds that can be spawned as enemies.
+    pub(crate) const ENEMIES: [ActorKind; 4] = [
+        ActorKind::Enemy,
+        ActorKind::Goblin,
+        ActorKind::Skeleton,
+        ActorKind::Rat,
+    ];
+}
+
+impl fmt::Display for ActorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActorKind::Player => write!(f, "player"),
+            ActorKind::Enemy => write!(f, "enemy"),
+            ActorKind::Goblin => write!(f, "goblin"),
+            ActorKind::Skeleton => write!(f, "skeleton"),
+            ActorKind::Rat => write!(f, "rat"),
+        }
+    }
 }
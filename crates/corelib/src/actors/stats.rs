@@ -1,27 +1,52 @@
-use super::ActorKind;
-
 /// Represents the stats of an actor.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stats {
     /// The current health of the actor.
     pub(crate) hp: u32,
+    /// The health the actor cannot be healed beyond.
+    pub(crate) max_hp: u32,
     /// Minimum attack power of the actor.
     pub(crate) min_damage: u32,
     /// Maximum attack power of the actor.
     pub(crate) max_damage: u32,
     /// Defense power of the actor.
     pub(crate) defense: u32,
+    /// How many tiles far the actor can see.
+    pub(crate) sight_radius: u32,
+    /// Chance in percent to hit a target that does not evade.
+    pub(crate) accuracy: u32,
+    /// Percentage points taken off the hit chance of attackers.
+    pub(crate) evasion: u32,
+    /// Chance in percent that a hit is critical.
+    pub(crate) crit_chance: u32,
+    /// Damage of critical hits in percent of the rolled damage.
+    pub(crate) crit_multiplier: u32,
 }
 
 impl Stats {
-    /// Creates a new `Stats` instance with the given values.
+    /// Creates a new `Stats` instance with the given values, at full health.
+    ///
+    /// The actor always hits, never evades and never hits critically.
     pub(crate) fn new(
         hp: u32,
         min_damage: u32,
         max_damage: u32,
         defense: u32,
+        sight_radius: u32,
     ) -> Self {
-        Stats { hp, min_damage, max_damage, defense }
+        Stats {
+            hp,
+            max_hp: hp,
+            min_damage,
+            max_damage,
+            defense,
+            sight_radius,
+            accuracy: 100,
+            evasion: 0,
+            crit_chance: 0,
+            crit_multiplier: 100,
+        }
     }
 
     /// Returns the current health of the actor.
@@ -30,6 +55,12 @@ impl Stats {
         self.hp
     }
 
+    /// Returns the health the actor cannot be healed beyond.
+    #[must_use]
+    pub fn max_hp(&self) -> u32 {
+        self.max_hp
+    }
+
     /// Returns the minimum attack power of the actor
     #[must_use]
     pub fn min_damage(&self) -> u32 {
@@ -47,14 +78,42 @@ impl Stats {
     pub fn defense(&self) -> u32 {
         self.defense
     }
+
+    /// Returns how many tiles far the actor can see.
+    #[must_use]
+    pub fn sight_radius(&self) -> u32 {
+        self.sight_radius
+    }
+
+    /// Returns the chance in percent to hit a target that does not evade.
+    #[must_use]
+    pub fn accuracy(&self) -> u32 {
+        self.accuracy
+    }
+
+    /// Returns the percentage points taken off the hit chance of attackers.
+    #[must_use]
+    pub fn evasion(&self) -> u32 {
+        self.evasion
+    }
+
+    /// Returns the chance in percent that a hit is critical.
+    #[must_use]
+    pub fn crit_chance(&self) -> u32 {
+        self.crit_chance
+    }
+
+    /// Returns the damage of critical hits in percent of the rolled damage.
+    #[must_use]
+    pub fn crit_multiplier(&self) -> u32 {
+        self.crit_multiplier
+    }
 }
 
-impl ActorKind {
-    /// Default stats table for each actor kind.
-    pub(crate) fn default_stats(&self) -> Stats {
-        match self {
-            ActorKind::Player => Stats::new(30, 5, 10, 2),
-            ActorKind::Enemy => Stats::new(20, 3, 5, 1),
-        }
+#[cfg(test)]
+impl super::ActorKind {
+    /// Starting stats of the kind in the built-in content.
+    pub(crate) fn default_stats(self) -> Stats {
+        crate::catalog::Content::builtin().actors.stats(self)
     }
 }
@@ -0,0 +1,156 @@
+use crate::{
+    Actor, ActorKind, Direction,
+    pathfinding::{MAX_SEARCH_NODES, find_path},
+    rng::MyRng,
+    walk_map::WalkMap,
+};
+
+use super::{Coward, MeleeChaser, RandomWanderer, StationaryGuard};
+
+/// An action an AI-controlled actor intends to take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AiAction {
+    /// Do nothing this turn.
+    Wait,
+    /// Step in the given direction, or open the closed door there.
+    Move(Direction),
+    /// Attack whoever stands in the given direction.
+    Attack(Direction),
+}
+
+/// Read-only view of the game state from the point of view of one actor.
+pub(crate) struct AiView<'a> {
+    /// The actor that is deciding.
+    pub(crate) actor: &'a Actor,
+    /// The player.
+    pub(crate) player: &'a Actor,
+    /// Tiles the actor can step on.
+    pub(crate) walk_map: &'a WalkMap,
+    /// Whether the actor can see the player.
+    pub(crate) sees_player: bool,
+}
+
+impl AiView<'_> {
+    /// Manhattan distance between the actor and the player.
+    pub(crate) fn distance_to_player(&self) -> u32 {
+        self.actor.position.manhattan_distance(self.player.position)
+    }
+
+    /// Returns the direction of the player if it stands next to the actor.
+    pub(crate) fn adjacent_player(&self) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|direction| {
+            self.actor.position + direction.to_offset_position()
+                == self.player.position
+        })
+    }
+
+    /// Returns true if the actor can step in the given direction.
+    pub(crate) fn can_move(&self, direction: Direction) -> bool {
+        self.walk_map
+            .is_walkable(self.actor.position + direction.to_offset_position())
+    }
+
+    /// Returns the first step towards the player.
+    ///
+    /// Follows the shortest path when there is one, otherwise steps straight
+    /// towards the player if that tile is free.
+    pub(crate) fn step_towards_player(&self) -> Option<Direction> {
+        let path = find_path(
+            self.walk_map,
+            self.actor.position,
+            self.player.position,
+            MAX_SEARCH_NODES,
+        );
+        if let Some(direction) = path.and_then(|path| path.first().copied()) {
+            return Some(direction);
+        }
+
+        let relative = self.actor.position - self.player.position;
+        [
+            (relative.x() > 0, Direction::West),
+            (relative.x() < 0, Direction::East),
+            (relative.y() > 0, Direction::North),
+            (relative.y() < 0, Direction::South),
+        ]
+        .into_iter()
+        .find(|&(condition, direction)| condition && self.can_move(direction))
+        .map(|(_, direction)| direction)
+    }
+
+    /// Returns the free step that takes the actor furthest from the player.
+    pub(crate) fn step_away_from_player(&self) -> Option<Direction> {
+        let current = self.distance_to_player();
+        Direction::ALL
+            .into_iter()
+            .filter(|&direction| self.can_move(direction))
+            .map(|direction| {
+                let next = self.actor.position + direction.to_offset_position();
+                (next.manhattan_distance(self.player.position), direction)
+            })
+            .filter(|&(next, _)| next > current)
+            .max_by_key(|&(next, _)| next)
+            .map(|(_, direction)| direction)
+    }
+}
+
+/// Decides what an AI-controlled actor does on its turn.
+pub(crate) trait AiBehavior {
+    /// Returns the action the actor intends to take.
+    fn decide(&self, view: &AiView<'_>, rng: &mut MyRng) -> AiAction;
+}
+
+/// Goblins run away once they are down to this many hit points.
+pub(super) const GOBLIN_FLEE_AT_HP: u32 = 5;
+
+impl ActorKind {
+    /// Behavior table for each actor kind.
+    pub(crate) fn behavior(self) -> &'static dyn AiBehavior {
+        match self {
+            // The player is never driven by the AI.
+            ActorKind::Player | ActorKind::Enemy => &MeleeChaser,
+            ActorKind::Goblin => &Coward { flee_at_hp: GOBLIN_FLEE_AT_HP },
+            ActorKind::Skeleton => &StationaryGuard,
+            ActorKind::Rat => &RandomWanderer,
+        }
+    }
+}
+
+/// Builds an open 11x11 walk map with the given actors standing on it.
+#[cfg(test)]
+pub(super) fn open_walk_map(actors: &[&Actor]) -> WalkMap {
+    let mut walk_map: WalkMap = (-5..=5)
+        .flat_map(|x| (-5..=5).map(move |y| crate::Position::new(x, y)))
+        .collect();
+    for actor in actors {
+        walk_map.occupy(actor.position);
+    }
+    walk_map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Position, ai::seeing_view};
+
+    #[test]
+    fn step_away_increases_distance() {
+        let actor = Actor::create(Position::new(1, 0), ActorKind::Goblin);
+        let player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        assert_eq!(view.adjacent_player(), Some(Direction::West));
+        assert_eq!(view.step_away_from_player(), Some(Direction::East));
+    }
+
+    #[test]
+    fn no_step_away_when_cornered() {
+        let actor = Actor::create(Position::new(5, 5), ActorKind::Goblin);
+        let player = Actor::create_player(Position::new(3, 5));
+        let mut walk_map = open_walk_map(&[&actor, &player]);
+        walk_map.occupy(Position::new(5, 4));
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        assert_eq!(view.step_away_from_player(), None);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::rng::MyRng;
+
+use super::{AiAction, AiBehavior, AiView};
+
+/// Walks towards the player once it is in sight and attacks it in melee.
+pub(crate) struct MeleeChaser;
+
+impl AiBehavior for MeleeChaser {
+    fn decide(&self, view: &AiView<'_>, _rng: &mut MyRng) -> AiAction {
+        if let Some(direction) = view.adjacent_player() {
+            return AiAction::Attack(direction);
+        }
+        if !view.sees_player {
+            return AiAction::Wait;
+        }
+        view.step_towards_player().map_or(AiAction::Wait, AiAction::Move)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Direction,
+        actors::Actor,
+        actors::ActorKind,
+        ai::{behavior::open_walk_map, seeing_view},
+        position::Position,
+    };
+
+    #[test]
+    fn attacks_adjacent_player() {
+        let actor = Actor::create(Position::new(0, 1), ActorKind::Enemy);
+        let player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let action = MeleeChaser.decide(&view, &mut MyRng::from_seed([0; 32]));
+        assert_eq!(action, AiAction::Attack(Direction::North));
+    }
+
+    #[test]
+    fn moves_towards_player() {
+        let actor = Actor::create(Position::new(3, 0), ActorKind::Enemy);
+        let player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let action = MeleeChaser.decide(&view, &mut MyRng::from_seed([0; 32]));
+        assert_eq!(action, AiAction::Move(Direction::West));
+    }
+
+    #[test]
+    fn waits_while_player_is_out_of_sight() {
+        let actor = Actor::create(Position::new(3, 0), ActorKind::Enemy);
+        let player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = AiView {
+            sees_player: false,
+            ..seeing_view(&actor, &player, &walk_map)
+        };
+
+        let action = MeleeChaser.decide(&view, &mut MyRng::from_seed([0; 32]));
+        assert_eq!(action, AiAction::Wait);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::rng::MyRng;
+
+use super::{AiAction, AiBehavior, AiView, MeleeChaser};
+
+/// Fights like a [`MeleeChaser`] but runs away once badly hurt.
+///
+/// A cornered coward still fights back.
+pub(crate) struct Coward {
+    /// The actor flees when its hp drops to this value or below.
+    pub(crate) flee_at_hp: u32,
+}
+
+impl AiBehavior for Coward {
+    fn decide(&self, view: &AiView<'_>, rng: &mut MyRng) -> AiAction {
+        if view.actor.stats.hp > self.flee_at_hp || !view.sees_player {
+            return MeleeChaser.decide(view, rng);
+        }
+        if let Some(direction) = view.step_away_from_player() {
+            return AiAction::Move(direction);
+        }
+        view.adjacent_player().map_or(AiAction::Wait, AiAction::Attack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Direction,
+        actors::Actor,
+        actors::ActorKind,
+        ai::{
+            behavior::{GOBLIN_FLEE_AT_HP, open_walk_map},
+            seeing_view,
+        },
+        position::Position,
+    };
+
+    const COWARD: Coward = Coward { flee_at_hp: GOBLIN_FLEE_AT_HP };
+
+    #[test]
+    fn fights_while_healthy() {
+        let actor = Actor::create(Position::new(1, 0), ActorKind::Goblin);
+        let player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let action = COWARD.decide(&view, &mut MyRng::from_seed([0; 32]));
+        assert_eq!(action, AiAction::Attack(Direction::West));
+    }
+
+    #[test]
+    fn flees_at_low_hp() {
+        let mut actor = Actor::create(Position::new(1, 0), ActorKind::Goblin);
+        actor.stats.hp = GOBLIN_FLEE_AT_HP;
+        let player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let action = COWARD.decide(&view, &mut MyRng::from_seed([0; 32]));
+        assert_eq!(action, AiAction::Move(Direction::East));
+    }
+
+    #[test]
+    fn fights_back_when_cornered() {
+        let mut actor = Actor::create(Position::new(5, 5), ActorKind::Goblin);
+        actor.stats.hp = 1;
+        let player = Actor::create_player(Position::new(4, 5));
+        let mut walk_map = open_walk_map(&[&actor, &player]);
+        walk_map.occupy(Position::new(5, 4));
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let action = COWARD.decide(&view, &mut MyRng::from_seed([0; 32]));
+        assert_eq!(action, AiAction::Attack(Direction::West));
+    }
+}
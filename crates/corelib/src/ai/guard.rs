@@ -0,0 +1,41 @@
+use crate::rng::MyRng;
+
+use super::{AiAction, AiBehavior, AiView};
+
+/// Never moves, but attacks the player when it comes close.
+pub(crate) struct StationaryGuard;
+
+impl AiBehavior for StationaryGuard {
+    fn decide(&self, view: &AiView<'_>, _rng: &mut MyRng) -> AiAction {
+        view.adjacent_player().map_or(AiAction::Wait, AiAction::Attack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Direction,
+        actors::Actor,
+        actors::ActorKind,
+        ai::{behavior::open_walk_map, seeing_view},
+        position::Position,
+    };
+
+    #[test]
+    fn waits_until_player_is_adjacent() {
+        let actor = Actor::create(Position::new(2, 0), ActorKind::Skeleton);
+        let mut player = Actor::create_player(Position::new(0, 0));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+        let mut rng = MyRng::from_seed([0; 32]);
+        assert_eq!(StationaryGuard.decide(&view, &mut rng), AiAction::Wait);
+
+        player.position = Position::new(1, 0);
+        let view = seeing_view(&actor, &player, &walk_map);
+        assert_eq!(
+            StationaryGuard.decide(&view, &mut rng),
+            AiAction::Attack(Direction::West)
+        );
+    }
+}
@@ -1,3 +1,23 @@
+mod behavior;
+mod chaser;
+mod coward;
+mod guard;
 mod simple;
+mod wanderer;
 
+pub(crate) use behavior::{AiAction, AiBehavior, AiView};
+pub(crate) use chaser::MeleeChaser;
+pub(crate) use coward::Coward;
+pub(crate) use guard::StationaryGuard;
 pub(crate) use simple::simple_ai;
+pub(crate) use wanderer::RandomWanderer;
+
+/// Builds the view of an actor that sees the player.
+#[cfg(test)]
+fn seeing_view<'a>(
+    actor: &'a crate::Actor,
+    player: &'a crate::Actor,
+    walk_map: &'a crate::walk_map::WalkMap,
+) -> AiView<'a> {
+    AiView { actor, player, walk_map, sees_player: true }
+}
@@ -1,55 +1,116 @@
+use std::collections::BTreeMap;
+
 use crate::{
-    Direction, GameState,
+    EntityId, GameState,
     events::GameEvent,
-    mechanics::{try_attack, try_move},
+    mechanics::{
+        enter_tile, entity_turns, inflict_on_hit, try_attack, try_move,
+        try_open_door,
+    },
     step_result::StepContext,
     walk_map::WalkMap,
 };
 
-/// Simple AI implementation for entities.
-/// 1. Moves towards the player.
-/// 2. Attacks the player if within range.
+use super::{AiAction, AiView};
+
+/// Runs the AI of every entity in entity order.
+///
+/// Each entity asks the behavior of its [`ActorKind`](crate::ActorKind) what
+/// to do, and the intended action is then carried out. Entities act as many
+/// times as `turns` says, which the caller reads before effects tick; entities
+/// missing from it are asked about their current effects. Entities killed by
+/// the terrain are left for the caller to remove.
 pub(crate) fn simple_ai(
     state: &mut GameState,
     step_context: &mut StepContext,
     walk_map: &mut WalkMap,
+    turns: &BTreeMap<EntityId, usize>,
 ) {
-    for entity in &mut state.entities {
-        if !entity.is_alive() {
-            continue;
+    for index in 0..state.entities.len() {
+        let entity = &state.entities[index];
+        let turns = turns.get(&entity.id).copied().unwrap_or_else(|| {
+            entity_turns(&state.content.effects, entity, state.tick_id)
+        });
+        for _ in 0..turns {
+            if !state.player.is_alive() {
+                return;
+            }
+            if !state.entities[index].is_alive() {
+                break;
+            }
+            act(state, step_context, walk_map, index);
         }
+    }
+}
 
-        let relative = entity.position - state.player.position;
-        let dist = relative.x().abs() + relative.y().abs();
-
-        if dist == 1 {
-            let damage = try_attack(entity, &mut state.player, &mut state.rng);
-            step_context.add_event(GameEvent::EntityAttacked {
-                id: entity.id(),
-                target: state.player.position,
-                damage,
-            });
-            continue;
-        }
+/// Lets the entity at the given index decide on an action and carries it out.
+///
+/// Hits on the player inflict the effects of the entity's kind.
+fn act(
+    state: &mut GameState,
+    step_context: &mut StepContext,
+    walk_map: &mut WalkMap,
+    index: usize,
+) {
+    let actor = &state.entities[index];
+    let view = AiView {
+        actor,
+        player: &state.player,
+        walk_map,
+        sees_player: state.can_see(actor, state.player.position),
+    };
+    let action = actor.kind.behavior().decide(&view, &mut state.rng);
 
-        for direction in [
-            (relative.x > 0, Direction::West),
-            (relative.x < 0, Direction::East),
-            (relative.y > 0, Direction::North),
-            (relative.y < 0, Direction::South),
-        ] {
-            let (condition, direction) = direction;
-            if condition
-                && let Some((from, to)) = try_move(entity, direction, walk_map)
+    let entity = &mut state.entities[index];
+    match action {
+        AiAction::Wait => {},
+        AiAction::Move(direction) => {
+            let target = entity.position + direction.to_offset_position();
+            if try_open_door(step_context, &mut state.dungeon, walk_map, target)
+            {
+                step_context.add_event(GameEvent::EntityOpenedDoor {
+                    id: entity.id(),
+                    position: target,
+                });
+            } else if let Some((from, to)) =
+                try_move(step_context, entity, direction, walk_map)
             {
                 step_context.add_event(GameEvent::EntityMoved {
                     id: entity.id(),
                     from,
                     to,
                 });
-                break;
+                enter_tile(step_context, entity, &state.dungeon);
             }
-        }
+        },
+        AiAction::Attack(direction) => {
+            let target = entity.position + direction.to_offset_position();
+            if target != state.player.position {
+                return;
+            }
+            let outcome = try_attack(
+                step_context,
+                entity,
+                &mut state.player,
+                &mut state.rng,
+            );
+            step_context.add_event(GameEvent::EntityAttacked {
+                id: entity.id(),
+                target,
+                outcome,
+            });
+            if outcome.is_hit()
+                && inflict_on_hit(
+                    step_context,
+                    &state.content.effects,
+                    state.content.actors.on_hit(entity.kind),
+                    &mut state.player,
+                    &mut state.rng,
+                )
+            {
+                state.refresh_player_stats(step_context);
+            }
+        },
     }
 }
 
@@ -57,15 +118,18 @@ pub(crate) fn simple_ai(
 mod tests {
     use super::*;
     use crate::{
-        actions::PlayerAction,
+        actions::{ActionError, PlayerAction},
         actors::{Actor, ActorKind},
+        catalog::EffectInstance,
         dungeon::DungeonMap,
+        effects::OnHit,
         position::Position,
         rng::MyRng,
+        step_result::StepResult,
     };
 
     fn setup_state(player_pos: Position, enemies: Vec<Position>) -> GameState {
-        GameState::new(
+        let mut gs = GameState::new(
             Actor::create_player(player_pos),
             enemies
                 .into_iter()
@@ -73,14 +137,16 @@ mod tests {
                 .collect(),
             DungeonMap::simple(10, 10),
             MyRng::new(),
-        )
+        );
+        gs.sure_hits();
+        gs
     }
 
     #[test]
-    fn enemy_moves_towards_player() {
+    fn enemy_moves_towards_player() -> Result<(), ActionError> {
         let mut gs =
             setup_state(Position::new(0, 0), vec![Position::new(2, 0)]);
-        let result = gs.apply_player_action(&PlayerAction::Skip);
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
         let events: Vec<_> = result.events.into_iter().collect();
         match events[1] {
             GameEvent::EntityMoved { from, to, .. } => {
@@ -89,28 +155,30 @@ mod tests {
             },
             _ => panic!("expected entity movement"),
         }
+        Ok(())
     }
 
     #[test]
-    fn enemy_attacks_when_adjacent() {
+    fn enemy_attacks_when_adjacent() -> Result<(), ActionError> {
         let mut gs =
             setup_state(Position::new(0, 0), vec![Position::new(1, 0)]);
         let hp_before = gs.player.stats.hp;
-        let result = gs.apply_player_action(&PlayerAction::Skip);
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
         assert!(result
             .events
             .iter()
             .any(|e| matches!(e, GameEvent::EntityAttacked { target, .. } if *target == Position::new(0,0))));
         assert!(gs.player.stats.hp < hp_before);
+        Ok(())
     }
 
     #[test]
-    fn enemies_act_in_entity_id_order() {
+    fn enemies_act_in_entity_id_order() -> Result<(), ActionError> {
         let mut gs = setup_state(
             Position::new(0, 0),
             vec![Position::new(2, 0), Position::new(0, 2)],
         );
-        let result = gs.apply_player_action(&PlayerAction::Skip);
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
         let events: Vec<_> = result.events.into_iter().collect();
         match events[1] {
             GameEvent::EntityMoved { id, .. } => {
@@ -124,22 +192,139 @@ mod tests {
             },
             _ => panic!("expected second entity event"),
         }
+        Ok(())
     }
 
     #[test]
-    fn enemy_blocked_by_other_entity() {
+    fn enemy_routes_around_other_entity() -> Result<(), ActionError> {
         let mut gs = setup_state(
             Position::new(0, 0),
             vec![Position::new(2, 0), Position::new(1, 0)],
         );
-        let before = gs.entities[0].position;
-        let result = gs.apply_player_action(&PlayerAction::Skip);
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
         let events: Vec<_> = result.events.into_iter().collect();
         assert!(
             events
                 .iter()
                 .any(|e| matches!(e, GameEvent::EntityAttacked { .. }))
         );
-        assert_eq!(gs.entities[0].position, before);
+        assert_eq!(gs.entities[0].position, Position::new(2, -1));
+
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(gs.entities[0].position, Position::new(0, -1));
+        Ok(())
+    }
+
+    #[test]
+    fn enemy_steps_straight_without_path() -> Result<(), ActionError> {
+        let mut gs = setup_state(
+            Position::new(0, 0),
+            vec![
+                Position::new(3, 0),
+                Position::new(1, 0),
+                Position::new(-1, 0),
+                Position::new(0, 1),
+                Position::new(0, -1),
+            ],
+        );
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        let distance = gs.entities[0].position - gs.player.position;
+        assert_eq!(distance.x().abs() + distance.y().abs(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn behavior_follows_actor_kind() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![
+                Actor::create(Position::new(3, 0), ActorKind::Skeleton),
+                Actor::create(Position::new(0, 3), ActorKind::Enemy),
+            ],
+            DungeonMap::simple(10, 10),
+            MyRng::new(),
+        );
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(gs.entities[0].position, Position::new(3, 0));
+        assert_eq!(gs.entities[1].position, Position::new(0, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn enemy_ignores_player_out_of_sight() -> Result<(), ActionError> {
+        let mut gs =
+            setup_state(Position::new(0, 0), vec![Position::new(9, 0)]);
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(gs.entities[0].position, Position::new(9, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn enemy_hits_inflict_effects() -> Result<(), ActionError> {
+        const POISON: usize = 2;
+
+        let mut gs =
+            setup_state(Position::new(0, 0), vec![Position::new(1, 0)]);
+        gs.content.actors.on_hit = vec![(
+            ActorKind::Enemy,
+            vec![OnHit { effect_id: POISON, duration: 2, chance: 100 }],
+        )];
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+
+        assert!(result.events.contains(&GameEvent::EffectApplied {
+            entity_id: gs.player.id(),
+            effect_id: POISON,
+            name: "poison".to_string(),
+            duration: 2,
+        }));
+        assert_eq!(gs.player.effects.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn one_turn_stun_skips_next_enemy_turn() -> Result<(), ActionError> {
+        const STUN: usize = 7;
+
+        let mut gs =
+            setup_state(Position::new(0, 0), vec![Position::new(1, 0)]);
+        gs.entities[0]
+            .effects
+            .push(EffectInstance { effect_id: STUN, remaining_turns: 1 });
+        let attacked = |result: &StepResult| {
+            result
+                .events
+                .iter()
+                .any(|event| matches!(event, GameEvent::EntityAttacked { .. }))
+        };
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+        assert!(!attacked(&result));
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+        assert!(attacked(&result));
+        Ok(())
+    }
+
+    #[test]
+    fn stunned_enemy_loses_turn() -> Result<(), ActionError> {
+        const STUN: usize = 7;
+
+        let mut gs =
+            setup_state(Position::new(0, 0), vec![Position::new(1, 0)]);
+        gs.entities[0]
+            .effects
+            .push(EffectInstance { effect_id: STUN, remaining_turns: 2 });
+        let hp = gs.player.stats.hp;
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+        assert!(
+            !result
+                .events
+                .iter()
+                .any(|event| matches!(event, GameEvent::EntityAttacked { .. }))
+        );
+        assert_eq!(gs.player.stats.hp, hp);
+        Ok(())
     }
 }
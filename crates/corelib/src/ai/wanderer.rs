@@ -0,0 +1,67 @@
+use crate::{Direction, rng::MyRng};
+
+use super::{AiAction, AiBehavior, AiView};
+
+/// Wanders around at random and bites the player when it comes close.
+pub(crate) struct RandomWanderer;
+
+impl AiBehavior for RandomWanderer {
+    fn decide(&self, view: &AiView<'_>, rng: &mut MyRng) -> AiAction {
+        if let Some(direction) = view.adjacent_player() {
+            return AiAction::Attack(direction);
+        }
+        let directions: Vec<_> = Direction::ALL
+            .into_iter()
+            .filter(|&direction| view.can_move(direction))
+            .collect();
+        // Rolling one past the last direction makes the actor rest.
+        let roll = rng.range(0..=directions.len());
+        directions.get(roll).copied().map_or(AiAction::Wait, AiAction::Move)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actors::Actor,
+        actors::ActorKind,
+        ai::{behavior::open_walk_map, seeing_view},
+        position::Position,
+    };
+
+    #[test]
+    fn only_steps_on_free_tiles() {
+        let actor = Actor::create(Position::new(5, 5), ActorKind::Rat);
+        let player = Actor::create_player(Position::new(-5, -5));
+        let mut walk_map = open_walk_map(&[&actor, &player]);
+        walk_map.occupy(Position::new(4, 5));
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let mut rng = MyRng::from_seed([5; 32]);
+        for _ in 0..20 {
+            let action = RandomWanderer.decide(&view, &mut rng);
+            assert!(matches!(
+                action,
+                AiAction::Wait | AiAction::Move(Direction::North)
+            ));
+        }
+    }
+
+    #[test]
+    fn deterministic_for_same_seed() {
+        let actor = Actor::create(Position::new(0, 0), ActorKind::Rat);
+        let player = Actor::create_player(Position::new(5, 5));
+        let walk_map = open_walk_map(&[&actor, &player]);
+        let view = seeing_view(&actor, &player, &walk_map);
+
+        let mut rng1 = MyRng::from_seed([6; 32]);
+        let mut rng2 = MyRng::from_seed([6; 32]);
+        for _ in 0..10 {
+            assert_eq!(
+                RandomWanderer.decide(&view, &mut rng1),
+                RandomWanderer.decide(&view, &mut rng2)
+            );
+        }
+    }
+}
@@ -3,7 +3,8 @@ use crate::position::Position;
 /// Heap allocated 2D array.
 /// Center is always at (0, 0).
 /// No reallocation. No size changes.
-#[derive(Debug)]
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Array2D<T>
 where
     T: Clone + Default,
@@ -0,0 +1,34 @@
+use crate::{
+    actors::{ActorKind, Stats},
+    effects::OnHit,
+};
+
+/// Starting stats of every actor kind and the effects their attacks inflict.
+#[derive(Debug, Clone, Hash)]
+pub struct ActorsCatalog {
+    pub(crate) stats: Vec<(ActorKind, Stats)>,
+    pub(crate) on_hit: Vec<(ActorKind, Vec<OnHit>)>,
+}
+
+impl ActorsCatalog {
+    /// Get the starting stats of an actor kind
+    ///
+    /// Kinds missing from the catalog get a single hit point and nothing else.
+    pub(crate) fn stats(&self, kind: ActorKind) -> Stats {
+        self.stats
+            .iter()
+            .find(|(stats_kind, _)| *stats_kind == kind)
+            .map_or_else(
+                || Stats::new(1, 0, 0, 0, 0),
+                |(_, stats)| stats.clone(),
+            )
+    }
+
+    /// Get the effects a hit of an actor kind inflicts
+    pub(crate) fn on_hit(&self, kind: ActorKind) -> &[OnHit] {
+        self.on_hit
+            .iter()
+            .find(|(on_hit_kind, _)| *on_hit_kind == kind)
+            .map_or(&[], |(_, on_hit)| on_hit)
+    }
+}
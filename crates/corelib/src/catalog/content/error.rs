@@ -0,0 +1,94 @@
+use std::{fmt, io};
+
+use crate::actors::ActorKind;
+
+/// Reasons why a content file was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentErrorKind {
+    /// The file could not be read.
+    Io(io::ErrorKind),
+    /// The file is not valid JSON or does not match the expected layout.
+    Syntax(String),
+    /// Two entries share the same name.
+    DuplicateName(String),
+    /// An item or actor refers to an effect that does not exist.
+    UnknownEffect(String),
+    /// A loot table refers to an item that does not exist.
+    UnknownItem(String),
+    /// An actor kind has no entry.
+    MissingActor(ActorKind),
+    /// A value of an entry is out of range.
+    InvalidValue {
+        /// Name of the entry.
+        name: String,
+        /// What is wrong with the value.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for ContentErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentErrorKind::Io(kind) => {
+                write!(f, "the file could not be read ({kind})")
+            },
+            ContentErrorKind::Syntax(message) => write!(f, "{message}"),
+            ContentErrorKind::DuplicateName(name) => {
+                write!(f, "\"{name}\" is defined more than once")
+            },
+            ContentErrorKind::UnknownEffect(name) => {
+                write!(f, "there is no effect named \"{name}\"")
+            },
+            ContentErrorKind::UnknownItem(name) => {
+                write!(f, "there is no item named \"{name}\"")
+            },
+            ContentErrorKind::MissingActor(kind) => {
+                write!(f, "{kind:?} has no entry")
+            },
+            ContentErrorKind::InvalidValue { name, reason } => {
+                write!(f, "\"{name}\": {reason}")
+            },
+        }
+    }
+}
+
+/// A content file was rejected, with the place the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentError {
+    pub(crate) file: String,
+    pub(crate) line: usize,
+    pub(crate) kind: ContentErrorKind,
+}
+
+impl ContentError {
+    /// Returns the name of the rejected file.
+    #[must_use]
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the line the problem was found on, or 0 if it concerns the
+    /// whole file.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns what is wrong with the file.
+    #[must_use]
+    pub fn kind(&self) -> &ContentErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}: {}", self.file, self.kind)
+        } else {
+            write!(f, "{}:{}: {}", self.file, self.line, self.kind)
+        }
+    }
+}
+
+impl std::error::Error for ContentError {}
@@ -0,0 +1,203 @@
+//! Layout of the content files.
+//!
+//! Entries refer to each other by name. The loader resolves the names to IDs
+//! and turns the entries into catalog definitions.
+
+use serde::Deserialize;
+
+use crate::{actors::ActorKind, effects::Stacking, items::EquipmentSlot};
+
+fn one() -> u32 {
+    1
+}
+
+fn always() -> u8 {
+    100
+}
+
+fn hundred() -> u32 {
+    100
+}
+
+/// An entry of the effects file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct EffectEntry {
+    pub(super) name: String,
+    pub(super) description: String,
+    pub(super) kind: EffectKindEntry,
+    #[serde(default)]
+    pub(super) stacking: StackingEntry,
+}
+
+#[derive(Deserialize)]
+pub(super) enum EffectKindEntry {
+    Heal {
+        hp_per_turn: u32,
+    },
+    Damage {
+        hp_per_turn: u32,
+    },
+    Modify {
+        #[serde(default)]
+        attack: i32,
+        #[serde(default)]
+        defense: i32,
+        #[serde(default)]
+        max_hp: i32,
+    },
+    Stun,
+    Haste,
+    Slow,
+}
+
+#[derive(Deserialize, Default)]
+pub(super) enum StackingEntry {
+    Refresh,
+    #[default]
+    Stack,
+    Ignore,
+}
+
+impl From<StackingEntry> for Stacking {
+    fn from(stacking: StackingEntry) -> Self {
+        match stacking {
+            StackingEntry::Refresh => Stacking::Refresh,
+            StackingEntry::Stack => Stacking::Stack,
+            StackingEntry::Ignore => Stacking::Ignore,
+        }
+    }
+}
+
+/// An entry of the items file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ItemEntry {
+    pub(super) name: String,
+    pub(super) title: String,
+    pub(super) description: String,
+    pub(super) kind: ItemKindEntry,
+    #[serde(default = "one")]
+    pub(super) max_stack: u32,
+}
+
+#[derive(Deserialize)]
+pub(super) enum ItemKindEntry {
+    Weapon {
+        min_damage: u32,
+        max_damage: u32,
+        #[serde(default)]
+        two_handed: bool,
+        #[serde(default)]
+        on_hit: Vec<OnHitEntry>,
+    },
+    Armor {
+        defense: u32,
+        slot: SlotEntry,
+    },
+    Consumable {
+        effect: String,
+        duration: u8,
+    },
+}
+
+#[derive(Deserialize)]
+pub(super) enum SlotEntry {
+    MainHand,
+    OffHand,
+    Head,
+    Body,
+    Ring,
+}
+
+impl From<SlotEntry> for EquipmentSlot {
+    fn from(slot: SlotEntry) -> Self {
+        match slot {
+            SlotEntry::MainHand => EquipmentSlot::MainHand,
+            SlotEntry::OffHand => EquipmentSlot::OffHand,
+            SlotEntry::Head => EquipmentSlot::Head,
+            SlotEntry::Body => EquipmentSlot::Body,
+            SlotEntry::Ring => EquipmentSlot::Ring,
+        }
+    }
+}
+
+/// An effect inflicted by a hit, part of weapon and actor entries.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct OnHitEntry {
+    pub(super) effect: String,
+    pub(super) duration: u8,
+    #[serde(default = "always")]
+    pub(super) chance: u8,
+}
+
+/// An entry of the actors file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ActorEntry {
+    pub(super) kind: ActorKindEntry,
+    pub(super) stats: StatsEntry,
+    #[serde(default)]
+    pub(super) on_hit: Vec<OnHitEntry>,
+    #[serde(default)]
+    pub(super) loot: Option<LootEntryTable>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub(super) enum ActorKindEntry {
+    Player,
+    Enemy,
+    Goblin,
+    Skeleton,
+    Rat,
+}
+
+impl From<ActorKindEntry> for ActorKind {
+    fn from(kind: ActorKindEntry) -> Self {
+        match kind {
+            ActorKindEntry::Player => ActorKind::Player,
+            ActorKindEntry::Enemy => ActorKind::Enemy,
+            ActorKindEntry::Goblin => ActorKind::Goblin,
+            ActorKindEntry::Skeleton => ActorKind::Skeleton,
+            ActorKindEntry::Rat => ActorKind::Rat,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct StatsEntry {
+    pub(super) hp: u32,
+    pub(super) min_damage: u32,
+    pub(super) max_damage: u32,
+    pub(super) defense: u32,
+    pub(super) sight_radius: u32,
+    #[serde(default = "hundred")]
+    pub(super) accuracy: u32,
+    #[serde(default)]
+    pub(super) evasion: u32,
+    #[serde(default)]
+    pub(super) crit_chance: u32,
+    #[serde(default = "hundred")]
+    pub(super) crit_multiplier: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct LootEntryTable {
+    #[serde(default)]
+    pub(super) nothing_weight: u32,
+    pub(super) drops: Vec<DropEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct DropEntry {
+    pub(super) item: String,
+    pub(super) weight: u32,
+    #[serde(default = "one")]
+    pub(super) min_count: u32,
+    #[serde(default = "one")]
+    pub(super) max_count: u32,
+}
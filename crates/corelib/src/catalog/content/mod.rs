@@ -0,0 +1,553 @@
+mod error;
+mod files;
+
+use std::{fs, path::Path, sync::LazyLock};
+
+use serde::de::DeserializeOwned;
+
+pub use error::{ContentError, ContentErrorKind};
+use files::{
+    ActorEntry, EffectEntry, EffectKindEntry, ItemEntry, ItemKindEntry,
+    OnHitEntry,
+};
+
+use super::{
+    ActorsCatalog, EffectsCatalog, ItemsCatalog, LootCatalog,
+    loot::{LootEntry, LootTable},
+};
+use crate::{
+    actors::{ActorKind, Stats},
+    effects::{EffectDef, EffectKind, OnHit},
+    items::{ItemDef, ItemKind},
+};
+
+const EFFECTS_FILE: &str = "effects.json";
+const ITEMS_FILE: &str = "items.json";
+const ACTORS_FILE: &str = "actors.json";
+
+static BUILTIN: LazyLock<Content> = LazyLock::new(|| {
+    match Content::parse(
+        include_str!("../../../content/effects.json"),
+        include_str!("../../../content/items.json"),
+        include_str!("../../../content/actors.json"),
+    ) {
+        Ok(content) => content,
+        Err(error) => panic!("built-in content is invalid: {error}"),
+    }
+});
+
+/// Every item, effect and actor definition a game is played with.
+#[derive(Debug, Clone, Hash)]
+pub struct Content {
+    pub(crate) items: ItemsCatalog,
+    pub(crate) effects: EffectsCatalog,
+    pub(crate) actors: ActorsCatalog,
+    pub(crate) loot: LootCatalog,
+}
+
+impl Content {
+    /// Returns the content shipped with the game.
+    #[must_use]
+    pub fn builtin() -> &'static Content {
+        &BUILTIN
+    }
+
+    /// Loads a content pack from a directory holding `effects.json`,
+    /// `items.json` and `actors.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file and line if a file cannot be read,
+    /// is malformed, or refers to definitions that do not exist.
+    pub fn load(dir: &Path) -> Result<Content, ContentError> {
+        let read = |file: &str| {
+            fs::read_to_string(dir.join(file)).map_err(|error| ContentError {
+                file: file.to_string(),
+                line: 0,
+                kind: ContentErrorKind::Io(error.kind()),
+            })
+        };
+        Content::parse(
+            &read(EFFECTS_FILE)?,
+            &read(ITEMS_FILE)?,
+            &read(ACTORS_FILE)?,
+        )
+    }
+
+    fn parse(
+        effects_text: &str,
+        items_text: &str,
+        actors_text: &str,
+    ) -> Result<Content, ContentError> {
+        let effect_entries: Vec<EffectEntry> =
+            parse_file(EFFECTS_FILE, effects_text)?;
+        let item_entries: Vec<ItemEntry> = parse_file(ITEMS_FILE, items_text)?;
+        let actor_entries: Vec<ActorEntry> =
+            parse_file(ACTORS_FILE, actors_text)?;
+
+        let effects = build_effects(effect_entries, effects_text)?;
+        let items = build_items(item_entries, items_text, &effects)?;
+        let (actors, loot) =
+            build_actors(actor_entries, actors_text, &effects, &items)?;
+
+        Ok(Content {
+            items: ItemsCatalog {
+                items: items.into_iter().map(|(_, item)| item).collect(),
+            },
+            effects: EffectsCatalog {
+                effects: effects
+                    .into_iter()
+                    .map(|(_, effect)| effect)
+                    .collect(),
+            },
+            actors,
+            loot,
+        })
+    }
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Content::builtin().clone()
+    }
+}
+
+fn parse_file<T: DeserializeOwned>(
+    file: &str,
+    text: &str,
+) -> Result<T, ContentError> {
+    serde_json::from_str(text).map_err(|error| {
+        let message = error.to_string();
+        // The location is reported separately
+        let message = message
+            .rsplit_once(" at line ")
+            .map_or(message.as_str(), |(message, _)| message);
+        ContentError {
+            file: file.to_string(),
+            line: error.line(),
+            kind: ContentErrorKind::Syntax(message.to_string()),
+        }
+    })
+}
+
+/// Finds the line of the `nth` occurrence of `"key": "value"`, or 0 if there
+/// is none.
+///
+/// Matching the key as well keeps a definition apart from references to it,
+/// and from other entries that happen to share the name.
+fn line_of(text: &str, key: &str, value: &str, nth: usize) -> usize {
+    let key = format!("\"{key}\"");
+    let value = format!("\"{value}\"");
+    text.match_indices(&key)
+        .filter(|(offset, _)| {
+            text[offset + key.len()..]
+                .trim_start()
+                .strip_prefix(':')
+                .is_some_and(|rest| rest.trim_start().starts_with(&value))
+        })
+        .nth(nth)
+        .map_or(0, |(offset, _)| text[..offset].lines().count().max(1))
+}
+
+fn build_effects(
+    entries: Vec<EffectEntry>,
+    text: &str,
+) -> Result<Vec<(String, EffectDef)>, ContentError> {
+    let mut effects: Vec<(String, EffectDef)> = Vec::new();
+    for entry in entries {
+        let error = |kind| ContentError {
+            file: EFFECTS_FILE.to_string(),
+            line: line_of(text, "name", &entry.name, 0),
+            kind,
+        };
+        if effects.iter().any(|(name, _)| *name == entry.name) {
+            return Err(ContentError {
+                line: line_of(text, "name", &entry.name, 1),
+                ..error(ContentErrorKind::DuplicateName(entry.name.clone()))
+            });
+        }
+
+        let kind = match entry.kind {
+            EffectKindEntry::Heal { hp_per_turn } => {
+                EffectKind::Heal { hp_per_turn }
+            },
+            EffectKindEntry::Damage { hp_per_turn } => {
+                EffectKind::Damage { hp_per_turn }
+            },
+            EffectKindEntry::Modify { attack, defense, max_hp } => {
+                EffectKind::Modify { attack, defense, max_hp }
+            },
+            EffectKindEntry::Stun => EffectKind::Stun,
+            EffectKindEntry::Haste => EffectKind::Haste,
+            EffectKindEntry::Slow => EffectKind::Slow,
+        };
+        effects.push((
+            entry.name.clone(),
+            EffectDef {
+                name: entry.name,
+                description: entry.description,
+                kind,
+                stacking: entry.stacking.into(),
+            },
+        ));
+    }
+    Ok(effects)
+}
+
+fn build_items(
+    entries: Vec<ItemEntry>,
+    text: &str,
+    effects: &[(String, EffectDef)],
+) -> Result<Vec<(String, ItemDef)>, ContentError> {
+    let mut items: Vec<(String, ItemDef)> = Vec::new();
+    for entry in entries {
+        let error = |kind| ContentError {
+            file: ITEMS_FILE.to_string(),
+            line: line_of(text, "name", &entry.name, 0),
+            kind,
+        };
+        let invalid = |reason| {
+            error(ContentErrorKind::InvalidValue {
+                name: entry.name.clone(),
+                reason,
+            })
+        };
+        if items.iter().any(|(name, _)| *name == entry.name) {
+            return Err(ContentError {
+                line: line_of(text, "name", &entry.name, 1),
+                ..error(ContentErrorKind::DuplicateName(entry.name.clone()))
+            });
+        }
+        if entry.max_stack == 0 {
+            return Err(invalid("max_stack must be at least 1"));
+        }
+
+        let kind = match entry.kind {
+            ItemKindEntry::Weapon {
+                min_damage,
+                max_damage,
+                two_handed,
+                on_hit,
+            } => {
+                if min_damage > max_damage {
+                    return Err(invalid("min_damage is above max_damage"));
+                }
+                ItemKind::Weapon {
+                    min_damage,
+                    max_damage,
+                    two_handed,
+                    on_hit: build_on_hit(on_hit, effects, text, error)?,
+                }
+            },
+            ItemKindEntry::Armor { defense, slot } => {
+                ItemKind::Armor { defense, slot: slot.into() }
+            },
+            ItemKindEntry::Consumable { effect, duration } => {
+                let effect_id = find_effect(effect, effects, text, error)?;
+                ItemKind::Consumable { effect_id, duration }
+            },
+        };
+        items.push((
+            entry.name.clone(),
+            ItemDef {
+                name: entry.name,
+                title: entry.title,
+                description: entry.description,
+                kind,
+                stackable: entry.max_stack > 1,
+                max_stack: entry.max_stack,
+            },
+        ));
+    }
+    Ok(items)
+}
+
+/// Resolves the name of an effect to its ID.
+fn find_effect(
+    name: String,
+    effects: &[(String, EffectDef)],
+    text: &str,
+    error: impl Fn(ContentErrorKind) -> ContentError,
+) -> Result<usize, ContentError> {
+    effects.iter().position(|(effect, _)| *effect == name).ok_or_else(|| {
+        ContentError {
+            line: line_of(text, "effect", &name, 0),
+            ..error(ContentErrorKind::UnknownEffect(name))
+        }
+    })
+}
+
+fn build_on_hit(
+    entries: Vec<OnHitEntry>,
+    effects: &[(String, EffectDef)],
+    text: &str,
+    error: impl Fn(ContentErrorKind) -> ContentError,
+) -> Result<Vec<OnHit>, ContentError> {
+    entries
+        .into_iter()
+        .map(|entry| {
+            if entry.chance > 100 {
+                return Err(error(ContentErrorKind::InvalidValue {
+                    name: entry.effect,
+                    reason: "chance is above 100",
+                }));
+            }
+            Ok(OnHit {
+                effect_id: find_effect(entry.effect, effects, text, &error)?,
+                duration: entry.duration,
+                chance: entry.chance,
+            })
+        })
+        .collect()
+}
+
+fn build_actors(
+    entries: Vec<ActorEntry>,
+    text: &str,
+    effects: &[(String, EffectDef)],
+    items: &[(String, ItemDef)],
+) -> Result<(ActorsCatalog, LootCatalog), ContentError> {
+    let mut stats: Vec<(ActorKind, Stats)> = Vec::new();
+    let mut on_hit: Vec<(ActorKind, Vec<OnHit>)> = Vec::new();
+    let mut tables: Vec<(ActorKind, LootTable)> = Vec::new();
+    for entry in entries {
+        let kind = ActorKind::from(entry.kind);
+        let name = format!("{kind:?}");
+        let error = |error_kind| ContentError {
+            file: ACTORS_FILE.to_string(),
+            line: line_of(text, "kind", &name, 0),
+            kind: error_kind,
+        };
+        let invalid = |reason| {
+            error(ContentErrorKind::InvalidValue { name: name.clone(), reason })
+        };
+        if stats.iter().any(|(stats_kind, _)| *stats_kind == kind) {
+            return Err(ContentError {
+                line: line_of(text, "kind", &name, 1),
+                ..error(ContentErrorKind::DuplicateName(name.clone()))
+            });
+        }
+
+        let entry_stats = entry.stats;
+        if entry_stats.hp == 0 {
+            return Err(invalid("hp must be at least 1"));
+        }
+        if entry_stats.min_damage > entry_stats.max_damage {
+            return Err(invalid("min_damage is above max_damage"));
+        }
+        if entry_stats.crit_chance > 100 {
+            return Err(invalid("crit_chance is above 100"));
+        }
+        if entry_stats.crit_multiplier < 100 {
+            return Err(invalid("crit_multiplier is below 100"));
+        }
+        stats.push((
+            kind,
+            Stats {
+                accuracy: entry_stats.accuracy,
+                evasion: entry_stats.evasion,
+                crit_chance: entry_stats.crit_chance,
+                crit_multiplier: entry_stats.crit_multiplier,
+                ..Stats::new(
+                    entry_stats.hp,
+                    entry_stats.min_damage,
+                    entry_stats.max_damage,
+                    entry_stats.defense,
+                    entry_stats.sight_radius,
+                )
+            },
+        ));
+        on_hit.push((kind, build_on_hit(entry.on_hit, effects, text, error)?));
+
+        let Some(loot) = entry.loot else {
+            continue;
+        };
+        let mut table = LootTable {
+            nothing_weight: loot.nothing_weight,
+            entries: Vec::new(),
+        };
+        let mut total_weight = loot.nothing_weight;
+        for drop in loot.drops {
+            let Some(item_id) =
+                items.iter().position(|(name, _)| *name == drop.item)
+            else {
+                return Err(ContentError {
+                    line: line_of(text, "item", &drop.item, 0),
+                    ..error(ContentErrorKind::UnknownItem(drop.item))
+                });
+            };
+            if drop.min_count == 0 || drop.min_count > drop.max_count {
+                return Err(invalid(
+                    "drop counts must be at least 1 and min_count at most \
+                     max_count",
+                ));
+            }
+            // Rolling adds up the weights, so their total has to fit
+            total_weight =
+                total_weight.checked_add(drop.weight).ok_or_else(|| {
+                    invalid("the loot weights add up to too much")
+                })?;
+            table.entries.push(LootEntry {
+                item_id,
+                weight: drop.weight,
+                min_count: drop.min_count,
+                max_count: drop.max_count,
+            });
+        }
+        tables.push((kind, table));
+    }
+
+    if let Some(kind) = ActorKind::ALL
+        .into_iter()
+        .find(|kind| stats.iter().all(|(stats_kind, _)| stats_kind != kind))
+    {
+        return Err(ContentError {
+            file: ACTORS_FILE.to_string(),
+            line: 0,
+            kind: ContentErrorKind::MissingActor(kind),
+        });
+    }
+
+    Ok((ActorsCatalog { stats, on_hit }, LootCatalog { tables }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    const EFFECTS: &str = include_str!("../../../content/effects.json");
+    const ITEMS: &str = include_str!("../../../content/items.json");
+    const ACTORS: &str = include_str!("../../../content/actors.json");
+
+    #[test]
+    fn builtin_content_is_valid() {
+        let content = Content::builtin();
+        assert_eq!(content.items.items.len(), 11);
+        assert_eq!(content.items.max_stack(3), 10);
+        assert!(content.items.get(1).is_some_and(ItemDef::two_handed));
+        for kind in ActorKind::ALL {
+            assert!(content.actors.stats(kind).hp() > 0);
+        }
+    }
+
+    #[test]
+    fn duplicate_names_report_the_second_entry() {
+        let items = ITEMS.replacen("\"sword_2\"", "\"sword_1\"", 1);
+        let Err(error) = Content::parse(EFFECTS, &items, ACTORS) else {
+            panic!("duplicate item names must be rejected");
+        };
+        assert_eq!(
+            error.kind(),
+            &ContentErrorKind::DuplicateName("sword_1".to_string())
+        );
+        assert_eq!(error.file(), "items.json");
+        assert_eq!(error.line(), line_of(&items, "name", "sword_1", 1));
+        assert!(error.line() > line_of(&items, "name", "sword_1", 0));
+    }
+
+    #[test]
+    fn names_shared_with_references_report_the_definition() {
+        // Both items take the name of the effect the first one references
+        let items = ITEMS.replacen("\"potion_1\"", "\"healing\"", 1).replacen(
+            "\"shield_1\"",
+            "\"healing\"",
+            1,
+        );
+        let Err(error) = Content::parse(EFFECTS, &items, ACTORS) else {
+            panic!("duplicate item names must be rejected");
+        };
+        let Some(shield) =
+            items.lines().position(|line| line.contains("Wooden Shield"))
+        else {
+            panic!("the shield must be in the items file");
+        };
+        assert_eq!(
+            error.kind(),
+            &ContentErrorKind::DuplicateName("healing".to_string())
+        );
+        // The shield's name sits on the line above its title
+        assert_eq!(error.line(), shield);
+    }
+
+    #[test]
+    fn loot_weights_must_fit_when_added_up() {
+        let actors = ACTORS.replacen(
+            "\"nothing_weight\": 6",
+            &format!("\"nothing_weight\": {}", u32::MAX - 1),
+            1,
+        );
+        let Err(error) = Content::parse(EFFECTS, ITEMS, &actors) else {
+            panic!("overflowing loot weights must be rejected");
+        };
+        assert_eq!(
+            error.kind(),
+            &ContentErrorKind::InvalidValue {
+                name: "Enemy".to_string(),
+                reason: "the loot weights add up to too much",
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_references_are_rejected() {
+        let items = ITEMS.replace("\"healing\"", "\"venom\"");
+        let Err(error) = Content::parse(EFFECTS, &items, ACTORS) else {
+            panic!("unknown effects must be rejected");
+        };
+        assert_eq!(
+            error.kind(),
+            &ContentErrorKind::UnknownEffect("venom".to_string())
+        );
+
+        let actors = ACTORS.replace("\"ring_1\"", "\"ring_9\"");
+        let Err(error) = Content::parse(EFFECTS, ITEMS, &actors) else {
+            panic!("unknown loot items must be rejected");
+        };
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "actors.json:{}: there is no item named \"ring_9\"",
+                line_of(&actors, "item", "ring_9", 0)
+            )
+        );
+    }
+
+    #[test]
+    fn syntax_errors_report_the_line() {
+        let Err(error) = Content::parse("[\n{\n}", ITEMS, ACTORS) else {
+            panic!("malformed files must be rejected");
+        };
+        assert_eq!(error.file(), "effects.json");
+        assert_eq!(error.line(), 3);
+        assert!(matches!(error.kind(), ContentErrorKind::Syntax(_)));
+    }
+
+    #[test]
+    fn packs_load_from_a_directory() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
+        assert!(Content::load(&dir).is_ok());
+
+        let Err(error) = Content::load(&dir.join("missing")) else {
+            panic!("missing files must be rejected");
+        };
+        assert_eq!(error.file(), "effects.json");
+        assert_eq!(
+            error.kind(),
+            &ContentErrorKind::Io(io::ErrorKind::NotFound)
+        );
+    }
+
+    #[test]
+    fn every_actor_kind_needs_stats() {
+        let Err(error) = Content::parse(EFFECTS, ITEMS, "[]") else {
+            panic!("missing actors must be rejected");
+        };
+        assert_eq!(
+            error.kind(),
+            &ContentErrorKind::MissingActor(ActorKind::Player)
+        );
+        assert_eq!(error.to_string(), "actors.json: Player has no entry");
+    }
+}
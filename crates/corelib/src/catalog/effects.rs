@@ -1,24 +1,29 @@
+use super::EffectInstance;
 use crate::effects::{EffectDef, EffectId, EffectKind};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
 pub struct EffectsCatalog {
-    effects: Vec<EffectDef>,
+    pub(crate) effects: Vec<EffectDef>,
 }
 
 impl EffectsCatalog {
-    pub(crate) fn new() -> Self {
-        EffectsCatalog {
-            effects: vec![EffectDef {
-                name: "Healing".to_string(),
-                description: "Restores health.".to_string(),
-                kind: EffectKind::Heal { hp_per_turn: 1 },
-            }],
-        }
-    }
-
-    /// Get an item by its ID
+    /// Get an effect by its ID
     #[must_use]
     pub fn get(&self, id: EffectId) -> Option<&EffectDef> {
         self.effects.get(id)
     }
+
+    /// Get the kinds of the effects that act on the next tick
+    ///
+    /// Instances without remaining turns or with unknown effects are skipped.
+    pub(crate) fn active<'a>(
+        &'a self,
+        effects: &'a [EffectInstance],
+    ) -> impl Iterator<Item = &'a EffectKind> {
+        effects
+            .iter()
+            .filter(|effect| effect.remaining_turns > 0)
+            .filter_map(|effect| self.get(effect.effect_id))
+            .map(|effect_def| &effect_def.kind)
+    }
 }
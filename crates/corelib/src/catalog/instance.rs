@@ -1,8 +1,29 @@
 use crate::effects::EffectId;
 
 /// Represents an instance of an effect in the game.
-#[derive(Debug)]
-pub(crate) struct EffectInstance {
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectInstance {
     pub(crate) effect_id: EffectId,
     pub(crate) remaining_turns: u8,
 }
+
+impl EffectInstance {
+    /// Creates an instance of the effect lasting the given number of turns.
+    #[must_use]
+    pub fn new(effect_id: EffectId, remaining_turns: u8) -> Self {
+        EffectInstance { effect_id, remaining_turns }
+    }
+
+    /// Returns the ID of the effect definition.
+    #[must_use]
+    pub fn effect_id(&self) -> EffectId {
+        self.effect_id
+    }
+
+    /// Returns the number of turns the effect stays active.
+    #[must_use]
+    pub fn remaining_turns(&self) -> u8 {
+        self.remaining_turns
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    actors::ActorKind,
+    items::{ItemId, ItemStack},
+    rng::MyRng,
+};
+
+/// A possible drop of a loot table.
+#[derive(Debug, Clone, Hash)]
+pub(crate) struct LootEntry {
+    /// Id of the dropped item in the items catalog.
+    pub(crate) item_id: ItemId,
+    /// Relative chance of the entry to be picked.
+    pub(crate) weight: u32,
+    /// Smallest number of items dropped.
+    pub(crate) min_count: u32,
+    /// Largest number of items dropped.
+    pub(crate) max_count: u32,
+}
+
+/// Weighted drops of one actor kind.
+#[derive(Debug, Clone, Hash)]
+pub(crate) struct LootTable {
+    /// Relative chance of dropping nothing at all.
+    pub(crate) nothing_weight: u32,
+    /// Drops to pick from.
+    pub(crate) entries: Vec<LootEntry>,
+}
+
+impl LootTable {
+    /// Picks at most one entry by weight and rolls its count.
+    pub(crate) fn roll(&self, rng: &mut MyRng) -> Option<ItemStack> {
+        let total = self.nothing_weight
+            + self.entries.iter().map(|entry| entry.weight).sum::<u32>();
+        if total == 0 {
+            return None;
+        }
+
+        let mut roll = rng.range(0..total);
+        if roll < self.nothing_weight {
+            return None;
+        }
+        roll -= self.nothing_weight;
+
+        let entry = self.entries.iter().find(|entry| {
+            if roll < entry.weight {
+                return true;
+            }
+            roll -= entry.weight;
+            false
+        })?;
+        Some(ItemStack {
+            item_id: entry.item_id,
+            count: rng.range(entry.min_count..=entry.max_count),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct LootCatalog {
+    pub(crate) tables: Vec<(ActorKind, LootTable)>,
+}
+
+impl LootCatalog {
+    /// Get the loot table of an actor kind
+    pub(crate) fn get(&self, kind: ActorKind) -> Option<&LootTable> {
+        self.tables
+            .iter()
+            .find(|(table_kind, _)| *table_kind == kind)
+            .map(|(_, table)| table)
+    }
+
+    /// Rolls the loot table of an actor kind.
+    ///
+    /// Kinds without a table drop nothing.
+    pub(crate) fn roll(
+        &self,
+        kind: ActorKind,
+        rng: &mut MyRng,
+    ) -> Option<ItemStack> {
+        self.get(kind)?.roll(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Content;
+
+    #[test]
+    fn tables_reference_known_items() {
+        let Content { items, loot, .. } = Content::builtin();
+        for kind in ActorKind::ENEMIES {
+            let Some(table) = loot.get(kind) else {
+                panic!("{kind:?} has no loot table");
+            };
+            for entry in &table.entries {
+                assert!(items.get(entry.item_id).is_some());
+                assert!(entry.min_count <= entry.max_count);
+            }
+        }
+        assert!(loot.get(ActorKind::Player).is_none());
+    }
+
+    #[test]
+    fn rolls_follow_weights() {
+        let table = LootTable {
+            nothing_weight: 0,
+            entries: vec![
+                LootEntry { item_id: 0, weight: 0, min_count: 1, max_count: 1 },
+                LootEntry { item_id: 2, weight: 1, min_count: 2, max_count: 4 },
+            ],
+        };
+        let mut rng = MyRng::from_seed([3; 32]);
+        for _ in 0..50 {
+            let Some(stack) = table.roll(&mut rng) else {
+                panic!("table without nothing weight always drops");
+            };
+            assert_eq!(stack.item_id, 2);
+            assert!((2..=4).contains(&stack.count));
+        }
+
+        let empty = LootTable { nothing_weight: 1, entries: Vec::new() };
+        assert!(empty.roll(&mut rng).is_none());
+    }
+}
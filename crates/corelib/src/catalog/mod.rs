@@ -1,7 +1,15 @@
+mod actors;
+mod content;
 mod effects;
 mod instance;
 mod items;
+mod loot;
 
+pub(crate) use actors::ActorsCatalog;
+pub use content::{Content, ContentError, ContentErrorKind};
 pub(crate) use effects::EffectsCatalog;
-pub(crate) use instance::EffectInstance;
+pub use instance::EffectInstance;
 pub(crate) use items::ItemsCatalog;
+pub(crate) use loot::LootCatalog;
+#[cfg(test)]
+pub(crate) use loot::{LootEntry, LootTable};
@@ -1,24 +1,98 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, btree_map::Entry};
 
-use crate::{EntityId, Position, Stats};
+use crate::{
+    Actor, EntityId, EquipmentSlot, Position, Stats, Tile,
+    catalog::EffectInstance,
+    items::{ItemStack, SlotId},
+};
 
 use super::{
     state::StateDiff,
-    utils::{EntityDiff, SingleDiff},
+    utils::{EntityDiff, SingleDiff, SlotDiff, TileDiff},
 };
 
-type DiffMap<D> = HashMap<EntityId, SingleDiff<D>>;
+/// Changes by key, kept in key order so that the same step always builds the
+/// same diff.
+type DiffMap<K, D> = BTreeMap<K, SingleDiff<D>>;
 
 /// Builder for entity diffs.
 #[derive(Default)]
 pub struct DiffBuilder {
     positions: Vec<EntityDiff<Position>>,
-    stats: DiffMap<Stats>,
+    stats: DiffMap<EntityId, Stats>,
+    effects: DiffMap<EntityId, Vec<EffectInstance>>,
+    inventory: DiffMap<SlotId, Option<ItemStack>>,
+    hotbar: DiffMap<SlotId, Option<ItemStack>>,
+    equipment: DiffMap<EquipmentSlot, Option<ItemStack>>,
+    spawned: Vec<Actor>,
+    despawned: Vec<EntityId>,
+    tiles: DiffMap<Position, Tile>,
+}
+
+/// Records a change, keeping the first old value and the latest new value.
+fn record<K: Ord, D>(map: &mut DiffMap<K, D>, key: K, old: D, new: D) {
+    match map.entry(key) {
+        Entry::Occupied(mut entry) => entry.get_mut().new = new,
+        Entry::Vacant(entry) => {
+            entry.insert(SingleDiff { old, new });
+        },
+    }
+}
+
+/// Records every slot whose content differs between the two snapshots.
+fn record_slots<'a>(
+    map: &mut DiffMap<SlotId, Option<ItemStack>>,
+    old: impl Iterator<Item = &'a Option<ItemStack>>,
+    new: impl Iterator<Item = &'a Option<ItemStack>>,
+) {
+    let old: Vec<_> = old.collect();
+    let new: Vec<_> = new.collect();
+    for slot in 0..old.len().max(new.len()) {
+        let old = old.get(slot).and_then(|stack| (*stack).clone());
+        let new = new.get(slot).and_then(|stack| (*stack).clone());
+        if old != new {
+            record(map, slot, old, new);
+        }
+    }
+}
+
+/// Collects the changed entities in ID order, dropping the ones that ended up
+/// unchanged.
+fn entity_diffs<D: PartialEq>(map: DiffMap<EntityId, D>) -> Vec<EntityDiff<D>> {
+    map.into_iter()
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .map(|(entity_id, diff)| EntityDiff {
+            entity_id,
+            old: diff.old,
+            new: diff.new,
+        })
+        .collect()
+}
+
+/// Collects the changed tiles in position order, dropping the ones that ended
+/// up unchanged.
+fn tile_diffs(map: DiffMap<Position, Tile>) -> Vec<TileDiff> {
+    map.into_iter()
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .map(|(position, diff)| TileDiff {
+            position,
+            old: diff.old,
+            new: diff.new,
+        })
+        .collect()
+}
+
+/// Collects the changed slots in slot order, dropping the ones that ended up
+/// unchanged.
+fn slot_diffs<S, D: PartialEq>(map: DiffMap<S, D>) -> Vec<SlotDiff<D, S>> {
+    map.into_iter()
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .map(|(slot, diff)| SlotDiff { slot, old: diff.old, new: diff.new })
+        .collect()
 }
 
 impl DiffBuilder {
     /// Adds a position diff to the builder.
-    #[allow(dead_code)]
     pub(crate) fn position(
         &mut self,
         entity_id: EntityId,
@@ -28,24 +102,146 @@ impl DiffBuilder {
         self.positions.push(EntityDiff { entity_id, old, new });
     }
 
-    #[allow(dead_code)]
+    /// Adds a stats diff to the builder.
     pub(crate) fn stat(&mut self, entity_id: EntityId, old: Stats, new: Stats) {
-        self.stats.entry(entity_id).or_insert(SingleDiff { old, new });
+        record(&mut self.stats, entity_id, old, new);
+    }
+
+    /// Adds an active effects diff to the builder.
+    pub(crate) fn effects(
+        &mut self,
+        entity_id: EntityId,
+        old: Vec<EffectInstance>,
+        new: Vec<EffectInstance>,
+    ) {
+        record(&mut self.effects, entity_id, old, new);
+    }
+
+    /// Adds the inventory slots that differ between the two snapshots.
+    pub(crate) fn inventory<'a>(
+        &mut self,
+        old: impl Iterator<Item = &'a Option<ItemStack>>,
+        new: impl Iterator<Item = &'a Option<ItemStack>>,
+    ) {
+        record_slots(&mut self.inventory, old, new);
+    }
+
+    /// Adds the hotbar slots that differ between the two snapshots.
+    pub(crate) fn hotbar<'a>(
+        &mut self,
+        old: impl Iterator<Item = &'a Option<ItemStack>>,
+        new: impl Iterator<Item = &'a Option<ItemStack>>,
+    ) {
+        record_slots(&mut self.hotbar, old, new);
+    }
+
+    /// Adds an equipment slot diff to the builder.
+    pub(crate) fn equipment(
+        &mut self,
+        slot: EquipmentSlot,
+        old: Option<ItemStack>,
+        new: Option<ItemStack>,
+    ) {
+        record(&mut self.equipment, slot, old, new);
+    }
+
+    /// Adds a spawned entity to the builder.
+    pub(crate) fn spawn(&mut self, actor: &Actor) {
+        self.spawned.push(actor.clone());
+    }
+
+    /// Adds a removed entity to the builder.
+    pub(crate) fn despawn(&mut self, entity_id: EntityId) {
+        self.despawned.push(entity_id);
+    }
+
+    /// Adds a dungeon tile diff to the builder.
+    pub(crate) fn tile(&mut self, position: Position, old: Tile, new: Tile) {
+        record(&mut self.tiles, position, old, new);
     }
 
     /// Builds the entity diffs.
     pub(crate) fn build(self) -> StateDiff {
         StateDiff {
             positions: self.positions,
-            stats: self
-                .stats
-                .into_iter()
-                .map(|(entity_id, diff)| EntityDiff {
-                    entity_id,
-                    old: diff.old,
-                    new: diff.new,
-                })
-                .collect(),
+            stats: entity_diffs(self.stats),
+            effects: entity_diffs(self.effects),
+            inventory: slot_diffs(self.inventory),
+            hotbar: slot_diffs(self.hotbar),
+            equipment: slot_diffs(self.equipment),
+            spawned: self.spawned,
+            despawned: self.despawned,
+            tiles: tile_diffs(self.tiles),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ActorKind;
+
+    #[test]
+    fn stat_keeps_first_old_and_latest_new() {
+        let id = EntityId::from(1);
+        let stats = |hp| Stats::new(hp, 1, 2, 0, 5);
+        let mut builder = DiffBuilder::default();
+        builder.stat(id, stats(10), stats(8));
+        builder.stat(id, stats(8), stats(5));
+
+        let diff = builder.build();
+        assert_eq!(diff.stats.len(), 1);
+        assert_eq!(diff.stats[0].old, stats(10));
+        assert_eq!(diff.stats[0].new, stats(5));
+    }
+
+    #[test]
+    fn unchanged_values_are_dropped() {
+        let id = EntityId::from(1);
+        let stats = ActorKind::Enemy.default_stats();
+        let mut builder = DiffBuilder::default();
+        builder.stat(id, stats.clone(), stats);
+        builder.effects(id, Vec::new(), Vec::new());
+
+        let diff = builder.build();
+        assert!(diff.stats.is_empty());
+        assert!(diff.effects.is_empty());
+    }
+
+    #[test]
+    fn entity_diffs_are_in_id_order() {
+        let stats = |hp| Stats::new(hp, 1, 2, 0, 5);
+        let mut builder = DiffBuilder::default();
+        for id in [7, 2, 9, 4] {
+            builder.stat(EntityId::from(id), stats(10), stats(8));
+            builder.effects(
+                EntityId::from(id),
+                Vec::new(),
+                vec![EffectInstance::new(0, 1)],
+            );
         }
+
+        let diff = builder.build();
+        let order = [2, 4, 7, 9].map(EntityId::from);
+        let ids: Vec<_> = diff.stats.iter().map(|d| d.entity_id).collect();
+        assert_eq!(ids, order);
+        let ids: Vec<_> = diff.effects.iter().map(|d| d.entity_id).collect();
+        assert_eq!(ids, order);
+    }
+
+    #[test]
+    fn records_changed_slots_in_order() {
+        let stack = |item_id| Some(ItemStack { item_id, count: 1 });
+        let old = [stack(0), None, stack(2), stack(3)];
+        let new = [None, None, stack(4), stack(3), stack(5)];
+        let mut builder = DiffBuilder::default();
+        builder.inventory(old.iter(), new.iter());
+
+        let diff = builder.build();
+        let slots: Vec<_> = diff.inventory.iter().map(|d| d.slot).collect();
+        assert_eq!(slots, vec![0, 2, 4]);
+        assert_eq!(diff.inventory[0].old, stack(0));
+        assert_eq!(diff.inventory[0].new, None);
+        assert_eq!(diff.inventory[2].new, stack(5));
     }
 }
@@ -4,4 +4,4 @@ mod utils;
 
 pub(crate) use builder::DiffBuilder;
 pub use state::StateDiff;
-pub use utils::EntityDiff;
+pub use utils::{EntityDiff, SlotDiff, TileDiff};
@@ -1,6 +1,9 @@
-use crate::{Position, Stats};
+use crate::{
+    Actor, EntityId, EquipmentSlot, Position, Stats, catalog::EffectInstance,
+    items::ItemStack,
+};
 
-use super::utils::EntityDiff;
+use super::utils::{EntityDiff, SlotDiff, TileDiff};
 
 /// Represents the changes in state between two steps.
 pub struct StateDiff {
@@ -8,4 +11,18 @@ pub struct StateDiff {
     pub positions: Vec<EntityDiff<Position>>,
     /// The stats that changed during the step.
     pub stats: Vec<EntityDiff<Stats>>,
+    /// The active effects that changed during the step.
+    pub effects: Vec<EntityDiff<Vec<EffectInstance>>>,
+    /// The inventory slots that changed during the step.
+    pub inventory: Vec<SlotDiff<Option<ItemStack>>>,
+    /// The hotbar slots that changed during the step.
+    pub hotbar: Vec<SlotDiff<Option<ItemStack>>>,
+    /// The equipment slots that changed during the step.
+    pub equipment: Vec<SlotDiff<Option<ItemStack>, EquipmentSlot>>,
+    /// The entities that were spawned during the step.
+    pub spawned: Vec<Actor>,
+    /// The entities that were removed during the step.
+    pub despawned: Vec<EntityId>,
+    /// The dungeon tiles that changed during the step.
+    pub tiles: Vec<TileDiff>,
 }
@@ -1,4 +1,4 @@
-use crate::EntityId;
+use crate::{EntityId, Position, Tile, items::SlotId};
 
 /// Container for entity diffs.
 pub struct EntityDiff<D> {
@@ -10,8 +10,35 @@ pub struct EntityDiff<D> {
     pub new: D,
 }
 
+/// Container for inventory, hotbar or equipment slot diffs.
+pub struct SlotDiff<D, S = SlotId> {
+    /// Slot index.
+    pub slot: S,
+    /// Old slot content.
+    pub old: D,
+    /// New slot content.
+    pub new: D,
+}
+
+/// Container for dungeon tile diffs.
+pub struct TileDiff {
+    /// Position of the tile.
+    pub position: Position,
+    /// Old tile.
+    pub old: Tile,
+    /// New tile.
+    pub new: Tile,
+}
+
 /// Container for single entity diffs.
 pub(crate) struct SingleDiff<D> {
     pub(crate) old: D,
     pub(crate) new: D,
 }
+
+impl<D: PartialEq> SingleDiff<D> {
+    /// Returns true if the value ended up where it started.
+    pub(crate) fn is_unchanged(&self) -> bool {
+        self.old == self.new
+    }
+}
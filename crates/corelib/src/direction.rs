@@ -1,7 +1,8 @@
 use crate::position::Position;
 
 /// Represents a direction in the game world.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// North direction.
     North,
@@ -14,8 +15,13 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// All directions, in the order they are tried when searching paths.
+    pub const ALL: [Direction; 4] =
+        [Direction::North, Direction::South, Direction::East, Direction::West];
+
     /// Returns the offset of the direction.
-    pub(crate) fn to_offset_position(self) -> Position {
+    #[must_use]
+    pub fn to_offset_position(self) -> Position {
         match self {
             Direction::North => Position::new(0, -1),
             Direction::South => Position::new(0, 1),
@@ -0,0 +1,154 @@
+use crate::{Array2D, Direction, position::Position};
+
+use super::{DungeonMap, Tile};
+
+impl DungeonMap {
+    /// Returns the floor tiles that can be walked to from the start without
+    /// getting hurt.
+    ///
+    /// Closed doors do not stop the search, as they can be opened, but
+    /// harmful tiles like lava do.
+    #[cfg(test)]
+    pub(crate) fn reachable_from(
+        &self,
+        start: Position,
+    ) -> std::collections::HashSet<Position> {
+        let mut reached = Array2D::empty(self.width(), self.height());
+        self.flood(start, &mut reached);
+        self.iter()
+            .map(|(position, _)| position)
+            .filter(|position| reached.get(*position) == Some(&true))
+            .collect()
+    }
+
+    /// Marks the passable, harmless tiles connected to the start as reached.
+    ///
+    /// Tiles reached before stop the search, so flooding a region that was
+    /// just joined to them only visits the new tiles.
+    fn flood(&self, start: Position, reached: &mut Array2D<bool>) {
+        if !is_open(self.get_tile(start)) || reached.get(start) != Some(&false)
+        {
+            return;
+        }
+
+        reached.set(start, true);
+        let mut queue = vec![start];
+        while let Some(position) = queue.pop() {
+            for direction in Direction::ALL {
+                let next = position + direction.to_offset_position();
+                if is_open(self.get_tile(next))
+                    && reached.get(next) == Some(&false)
+                {
+                    reached.set(next, true);
+                    queue.push(next);
+                }
+            }
+        }
+    }
+
+    /// Carves tunnels to every pocket of floor that cannot be reached from
+    /// the spawn, so the whole map becomes connected.
+    ///
+    /// Pockets are joined in map order, each to the closest reachable tile.
+    /// Tunnels are carved as floor, so they also bridge harmful terrain.
+    /// The map is flooded once from the spawn, and then once more from every
+    /// pocket to take in the pocket and its tunnel.
+    pub(crate) fn connect_pockets(&mut self, spawn: Position) {
+        let mut reached = Array2D::empty(self.width(), self.height());
+        self.flood(spawn, &mut reached);
+        if reached.get(spawn) != Some(&true) {
+            return;
+        }
+
+        let floor: Vec<_> = self
+            .iter()
+            .filter(|(_, tile)| tile.is_walkable() && !tile.is_harmful())
+            .map(|(position, _)| position)
+            .collect();
+        for pocket in floor {
+            if reached.get(pocket) == Some(&true) {
+                continue;
+            }
+            let Some(target) = closest_reached(&reached, pocket) else {
+                return;
+            };
+            self.carve_tunnel(pocket, target);
+            self.flood(pocket, &mut reached);
+        }
+    }
+
+    /// Turns a horizontal then vertical line of tiles into floor.
+    fn carve_tunnel(&mut self, from: Position, to: Position) {
+        for x in from.x.min(to.x)..=from.x.max(to.x) {
+            self.set_tile(Position::new(x, from.y), Tile::Floor);
+        }
+        for y in from.y.min(to.y)..=from.y.max(to.y) {
+            self.set_tile(Position::new(to.x, y), Tile::Floor);
+        }
+    }
+}
+
+/// Returns true if the tile can be crossed without getting hurt.
+fn is_open(tile: &Tile) -> bool {
+    tile.is_passable() && !tile.is_harmful()
+}
+
+/// Finds the reached tile closest to the given position.
+///
+/// The search widens one step of Manhattan distance at a time, and ties are
+/// broken by position.
+fn closest_reached(
+    reached: &Array2D<bool>,
+    from: Position,
+) -> Option<Position> {
+    let max_radius =
+        i32::try_from(reached.width() + reached.height()).unwrap_or(i32::MAX);
+    (1..=max_radius).find_map(|radius| {
+        (-radius..=radius)
+            .flat_map(|dx| {
+                let dy = radius - dx.abs();
+                [
+                    Position::new(from.x + dx, from.y - dy),
+                    Position::new(from.x + dx, from.y + dy),
+                ]
+            })
+            .filter(|position| reached.get(*position) == Some(&true))
+            .min()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn finds_only_connected_floor() {
+        let map = DungeonMap::simple(30, 30);
+        let reached = map.reachable_from(Position::new(0, 0));
+
+        assert!(reached.contains(&Position::new(9, 9)));
+        assert!(reached.contains(&Position::new(14, 14)));
+        assert!(!reached.contains(&Position::new(10, -10)));
+        assert!(map.reachable_from(Position::new(12, -12)).is_empty());
+    }
+
+    #[test]
+    fn tunnels_connect_every_pocket() {
+        let mut map = DungeonMap::simple(30, 30);
+        for pocket in [Position::new(-14, 14), Position::new(13, -12)] {
+            map.set_tile(pocket, Tile::Floor);
+        }
+
+        map.connect_pockets(Position::new(0, 0));
+
+        let floor: HashSet<_> = map
+            .iter()
+            .filter(|(_, tile)| tile.is_walkable() && !tile.is_harmful())
+            .map(|(position, _)| position)
+            .collect();
+        assert!(floor.contains(&Position::new(-14, 14)));
+        assert_eq!(map.reachable_from(Position::new(0, 0)), floor);
+    }
+}
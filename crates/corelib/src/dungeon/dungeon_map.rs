@@ -1,46 +1,17 @@
-use crate::{
-    Array2D, array2d::Array2DIterator, position::Position, rng::MyRng,
-};
+use crate::{Array2D, array2d::Array2DIterator, position::Position};
 
 use super::tile::Tile;
 
 /// Represents a dungeon map.
-#[derive(Debug)]
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DungeonMap {
     tiles: Array2D<Tile>,
 }
 
 impl DungeonMap {
-    /// Generates a new dungeon map with the given width and height.
-    ///
-    /// Temporary generates a simple dungeon map with floor tiles.
-    pub(crate) fn generate(
-        width: usize,
-        height: usize,
-        rng: &mut MyRng,
-        floor_tiles: usize,
-    ) -> Self {
-        let mut tiles = Array2D::empty(width, height);
-        let mut current_position = Position { x: 0, y: 0 };
-        let mut total_tiles = 0;
-        while total_tiles < floor_tiles {
-            if tiles.get(current_position) == Some(&Tile::Empty) {
-                tiles.set(current_position, Tile::Floor);
-                total_tiles += 1;
-            }
-
-            let walk_to_x = rng.range(0..=1) != 0;
-            if walk_to_x {
-                current_position += Position { x: rng.range(-1..=1), y: 0 };
-            } else {
-                current_position += Position { x: 0, y: rng.range(-1..=1) };
-            }
-
-            current_position.x = current_position.x.min(tiles.half_width());
-            current_position.x = current_position.x.max(-tiles.half_width());
-            current_position.y = current_position.y.min(tiles.half_height());
-            current_position.y = current_position.y.max(-tiles.half_height());
-        }
+    /// Wraps already generated tiles into a dungeon map.
+    pub(crate) fn from_tiles(tiles: Array2D<Tile>) -> Self {
         Self { tiles }
     }
 
@@ -82,12 +53,47 @@ impl DungeonMap {
         self.tiles.get(position).unwrap_or(&Tile::Empty)
     }
 
+    /// Replaces the tile at the given position.
+    ///
+    /// Positions outside of the map are ignored.
+    pub(crate) fn set_tile(&mut self, position: Position, tile: Tile) {
+        self.tiles.set(position, tile);
+    }
+
+    /// Surrounds the walkable parts of the map with walls, so they stand out
+    /// from the void.
+    pub(crate) fn build_walls(&mut self) {
+        let walls: Vec<_> = self
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::Empty)
+            .map(|(position, _)| position)
+            .filter(|position| {
+                (-1..=1).any(|dx| {
+                    (-1..=1).any(|dy| {
+                        self.is_walkable(*position + Position::new(dx, dy))
+                    })
+                })
+            })
+            .collect();
+        for position in walls {
+            self.set_tile(position, Tile::Wall);
+        }
+    }
+
     /// Returns true if the tile at the given position is walkable.
     #[must_use]
     pub fn is_walkable(&self, position: Position) -> bool {
         self.get_tile(position).is_walkable()
     }
 
+    /// Returns the first position holding the given tile.
+    #[must_use]
+    pub fn find_tile(&self, tile: &Tile) -> Option<Position> {
+        self.iter()
+            .find(|(_, candidate)| *candidate == tile)
+            .map(|(position, _)| position)
+    }
+
     /// Returns an iterator over the tiles in the dungeon map.
     pub fn iter(&self) -> impl Iterator<Item = (Position, &Tile)> {
         <&Self as IntoIterator>::into_iter(self)
@@ -0,0 +1,154 @@
+use crate::{
+    Array2D,
+    dungeon::{DungeonMap, Tile},
+    position::Position,
+    rng::MyRng,
+};
+
+use super::{
+    MapGenerator,
+    room::{Room, carve_corridor},
+};
+
+/// Partitions are never split below this size.
+const MIN_PARTITION_SIZE: i32 = 8;
+/// Smallest side of a room inside a partition.
+const MIN_ROOM_SIZE: i32 = 3;
+
+/// Recursively splits the map in two with binary space partitioning, puts a
+/// room in every partition and connects the halves of every split.
+pub(super) struct BspGenerator;
+
+impl MapGenerator for BspGenerator {
+    fn generate(
+        &self,
+        width: usize,
+        height: usize,
+        rng: &mut MyRng,
+    ) -> DungeonMap {
+        let mut tiles = Array2D::empty(width, height);
+        if width == 0 || height == 0 {
+            return DungeonMap::from_tiles(tiles);
+        }
+
+        let top_left = tiles.top_left();
+        let area = Room::new(
+            top_left.x,
+            top_left.y,
+            i32::try_from(width).unwrap_or(i32::MAX),
+            i32::try_from(height).unwrap_or(i32::MAX),
+        );
+        let mut rooms = Vec::new();
+        partition(&mut tiles, area, rng, &mut rooms);
+        for room in &rooms {
+            room.place_doors(&mut tiles);
+        }
+
+        // The player spawns at the origin, which may lie between rooms.
+        let origin = Position::new(0, 0);
+        if tiles.get(origin) != Some(&Tile::Floor) {
+            let nearest = rooms
+                .iter()
+                .map(Room::center)
+                .min_by_key(|center| origin.manhattan_distance(*center))
+                .unwrap_or(origin);
+            carve_corridor(&mut tiles, origin, nearest, rng);
+        }
+
+        DungeonMap::from_tiles(tiles)
+    }
+}
+
+/// Splits the area until partitions are too small, carving a room in every
+/// leaf. Returns one of the rooms carved inside the area.
+fn partition(
+    tiles: &mut Array2D<Tile>,
+    area: Room,
+    rng: &mut MyRng,
+    rooms: &mut Vec<Room>,
+) -> Room {
+    let width = area.right - area.left + 1;
+    let height = area.bottom - area.top + 1;
+    let can_split_x = width >= MIN_PARTITION_SIZE * 2;
+    let can_split_y = height >= MIN_PARTITION_SIZE * 2;
+
+    let split_x = match (can_split_x, can_split_y) {
+        (false, false) => {
+            let room = leaf_room(area, rng);
+            room.carve(tiles);
+            rooms.push(room);
+            return room;
+        },
+        (true, true) => rng.range(0..=1) == 0,
+        (split_x, _) => split_x,
+    };
+
+    let (first, second) = if split_x {
+        let x = rng.range(
+            area.left + MIN_PARTITION_SIZE
+                ..=area.right + 1 - MIN_PARTITION_SIZE,
+        );
+        (Room { right: x - 1, ..area }, Room { left: x, ..area })
+    } else {
+        let y = rng.range(
+            area.top + MIN_PARTITION_SIZE
+                ..=area.bottom + 1 - MIN_PARTITION_SIZE,
+        );
+        (Room { bottom: y - 1, ..area }, Room { top: y, ..area })
+    };
+
+    let first = partition(tiles, first, rng, rooms);
+    let second = partition(tiles, second, rng, rooms);
+    carve_corridor(tiles, first.center(), second.center(), rng);
+    first
+}
+
+/// Picks a room inside the partition, leaving a wall on its right and bottom
+/// so rooms of neighbouring partitions do not merge.
+fn leaf_room(area: Room, rng: &mut MyRng) -> Room {
+    let inner_width = (area.right - area.left).max(1);
+    let inner_height = (area.bottom - area.top).max(1);
+
+    let width = rng.range(MIN_ROOM_SIZE.min(inner_width)..=inner_width);
+    let height = rng.range(MIN_ROOM_SIZE.min(inner_height)..=inner_height);
+    let left = rng.range(area.left..=area.left + inner_width - width);
+    let top = rng.range(area.top..=area.top + inner_height - height);
+
+    Room::new(left, top, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::generators::map_generator::floor;
+
+    #[test]
+    fn rooms_stay_inside_their_partition() {
+        let mut tiles = Array2D::empty(64, 48);
+        let top_left = tiles.top_left();
+        let area = Room::new(top_left.x, top_left.y, 64, 48);
+        let mut rooms = Vec::new();
+        partition(&mut tiles, area, &mut MyRng::from_seed([2; 32]), &mut rooms);
+
+        assert!(rooms.len() >= 4);
+        for (i, room) in rooms.iter().enumerate() {
+            assert!(area.left <= room.left && room.right <= area.right);
+            assert!(area.top <= room.top && room.bottom <= area.bottom);
+            assert!(rooms[i + 1..].iter().all(|other| !room.intersects(other)));
+        }
+    }
+
+    #[test]
+    fn origin_is_connected_to_every_room() {
+        let map =
+            BspGenerator.generate(101, 101, &mut MyRng::from_seed([6; 32]));
+        assert!(map.is_walkable(Position::new(0, 0)));
+        assert_eq!(map.reachable_from(Position::new(0, 0)), floor(&map));
+    }
+
+    #[test]
+    fn tiny_map_does_not_panic() {
+        let map = BspGenerator.generate(2, 1, &mut MyRng::from_seed([1; 32]));
+        assert!(map.is_walkable(Position::new(0, 0)));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    Array2D,
+    dungeon::{DungeonMap, Tile},
+    position::Position,
+    rng::MyRng,
+};
+
+use super::MapGenerator;
+
+/// Chance in percent that a tile starts as a wall.
+const WALL_CHANCE: u32 = 45;
+/// Number of smoothing passes of the automaton.
+const SMOOTHING_STEPS: usize = 5;
+/// Radius of the clearing carved around the origin for the player.
+const SPAWN_CLEARING: i32 = 1;
+/// Number of tiles of the map per pool of water or lava.
+const TILES_PER_POOL: usize = 300;
+/// Chance in percent that a pool is lava rather than water.
+const LAVA_CHANCE: u32 = 30;
+/// Largest distance of a pool tile from the center of the pool.
+const MAX_POOL_RADIUS: i32 = 2;
+
+/// Grows caves with a cellular automaton.
+///
+/// The map starts as random noise and is smoothed by turning tiles with many
+/// wall neighbours into walls. Pools of water and lava are then flooded into
+/// the caves. Separate caves are kept as they are and joined when the game is
+/// created, with tunnels bridging any lava in the way.
+pub(super) struct CellularCaves;
+
+impl MapGenerator for CellularCaves {
+    fn generate(
+        &self,
+        width: usize,
+        height: usize,
+        rng: &mut MyRng,
+    ) -> DungeonMap {
+        let mut walls = Array2D::empty(width, height);
+        for position in positions(&walls) {
+            walls.set(position, rng.range(0..100) < WALL_CHANCE);
+        }
+
+        for _ in 0..SMOOTHING_STEPS {
+            walls = smooth(&walls);
+        }
+
+        for x in -SPAWN_CLEARING..=SPAWN_CLEARING {
+            for y in -SPAWN_CLEARING..=SPAWN_CLEARING {
+                walls.set(Position::new(x, y), false);
+            }
+        }
+
+        let mut tiles = floor_tiles(&walls);
+        add_pools(&mut tiles, rng);
+        DungeonMap::from_tiles(tiles)
+    }
+}
+
+/// Returns every position of the array.
+fn positions<T: Clone + Default>(
+    array: &Array2D<T>,
+) -> impl Iterator<Item = Position> + use<T> {
+    let top_left = array.top_left();
+    let width = i32::try_from(array.width()).unwrap_or(i32::MAX);
+    let height = i32::try_from(array.height()).unwrap_or(i32::MAX);
+    (top_left.y..top_left.y + height).flat_map(move |y| {
+        (top_left.x..top_left.x + width).map(move |x| Position::new(x, y))
+    })
+}
+
+/// Runs one step of the automaton. Tiles outside of the map count as walls.
+fn smooth(walls: &Array2D<bool>) -> Array2D<bool> {
+    let mut next = Array2D::empty(walls.width(), walls.height());
+    for position in positions(walls) {
+        let neighbours = (-1..=1)
+            .flat_map(|dx| (-1..=1).map(move |dy| Position::new(dx, dy)))
+            .filter(|offset| *offset != Position::new(0, 0))
+            .filter(|offset| {
+                walls.get(position + *offset).is_none_or(|wall| *wall)
+            })
+            .count();
+        let is_wall = walls.get(position).is_some_and(|wall| *wall);
+        next.set(position, neighbours >= 5 || (is_wall && neighbours >= 4));
+    }
+    next
+}
+
+/// Turns every open tile into floor.
+fn floor_tiles(walls: &Array2D<bool>) -> Array2D<Tile> {
+    let mut tiles = Array2D::empty(walls.width(), walls.height());
+    for position in positions(walls) {
+        if walls.get(position) == Some(&false) {
+            tiles.set(position, Tile::Floor);
+        }
+    }
+    tiles
+}
+
+/// Floods round pools of water or lava into the floor, away from the spawn.
+fn add_pools(tiles: &mut Array2D<Tile>, rng: &mut MyRng) {
+    let all: Vec<_> = positions(tiles).collect();
+    if all.is_empty() {
+        return;
+    }
+
+    for _ in 0..all.len() / TILES_PER_POOL {
+        let center = all[rng.range(0..all.len())];
+        let radius = rng.range(1..=MAX_POOL_RADIUS);
+        let liquid = if rng.range(0..100) < LAVA_CHANCE {
+            Tile::Lava
+        } else {
+            Tile::Water
+        };
+        for dx in -radius..=radius {
+            let reach = radius - dx.abs();
+            for dy in -reach..=reach {
+                let position = center + Position::new(dx, dy);
+                let near_spawn = position.x.abs() <= SPAWN_CLEARING
+                    && position.y.abs() <= SPAWN_CLEARING;
+                if !near_spawn && tiles.get(position) == Some(&Tile::Floor) {
+                    tiles.set(position, liquid.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::generators::map_generator::floor;
+
+    #[test]
+    fn smoothing_fills_isolated_floor() {
+        let mut walls = Array2D::empty(5, 5);
+        for position in positions(&walls).collect::<Vec<_>>() {
+            walls.set(position, position != Position::new(0, 0));
+        }
+
+        let smoothed = smooth(&walls);
+        assert!(positions(&smoothed).all(|p| smoothed.get(p) == Some(&true)));
+    }
+
+    #[test]
+    fn separate_caves_are_joined_by_tunnels() {
+        let mut map =
+            CellularCaves.generate(101, 101, &mut MyRng::from_seed([7; 32]));
+        assert!(
+            map.reachable_from(Position::new(0, 0)).len() < floor(&map).len()
+        );
+
+        map.connect_pockets(Position::new(0, 0));
+        assert_eq!(map.reachable_from(Position::new(0, 0)), floor(&map));
+    }
+
+    #[test]
+    fn caves_hold_water_and_lava_and_stay_connected() {
+        let mut map =
+            CellularCaves.generate(101, 101, &mut MyRng::from_seed([7; 32]));
+        assert!(map.iter().any(|(_, tile)| *tile == Tile::Water));
+        assert!(map.iter().any(|(_, tile)| *tile == Tile::Lava));
+
+        map.connect_pockets(Position::new(0, 0));
+        assert_eq!(map.reachable_from(Position::new(0, 0)), floor(&map));
+    }
+
+    #[test]
+    fn empty_map_has_no_floor() {
+        let map = CellularCaves.generate(0, 0, &mut MyRng::from_seed([1; 32]));
+        assert!(floor(&map).is_empty());
+    }
+}
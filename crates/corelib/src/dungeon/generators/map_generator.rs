@@ -0,0 +1,97 @@
+use crate::{dungeon::DungeonMap, rng::MyRng};
+
+use super::{
+    bsp::BspGenerator, cellular::CellularCaves, random_walk::RandomWalk,
+    rooms::RoomsAndCorridors,
+};
+
+/// Produces the tiles of a dungeon map.
+///
+/// Generators must only draw randomness from the given rng, so the same seed
+/// always produces the same map. The player spawns at the origin, so it must
+/// be a floor tile. Pockets of floor that cannot be reached from the origin
+/// are joined by [`crate::new_game`].
+pub(crate) trait MapGenerator {
+    /// Generates a map of the given size.
+    fn generate(
+        &self,
+        width: usize,
+        height: usize,
+        rng: &mut MyRng,
+    ) -> DungeonMap;
+}
+
+/// Algorithm used to generate the dungeon map.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GeneratorKind {
+    /// Carves a cave by walking randomly from the center of the map.
+    #[default]
+    RandomWalk,
+    /// Places non-overlapping rectangular rooms and joins them with
+    /// L-shaped corridors.
+    RoomsAndCorridors,
+    /// Splits the map with binary space partitioning and puts a room in
+    /// every partition.
+    Bsp,
+    /// Grows natural looking caves with a cellular automaton, with pools of
+    /// water and lava.
+    CellularCaves,
+}
+
+impl GeneratorKind {
+    /// Generator table for each kind.
+    ///
+    /// `floor_tiles` is only used by [`GeneratorKind::RandomWalk`].
+    pub(crate) fn generator(self, floor_tiles: usize) -> Box<dyn MapGenerator> {
+        match self {
+            GeneratorKind::RandomWalk => Box::new(RandomWalk { floor_tiles }),
+            GeneratorKind::RoomsAndCorridors => Box::new(RoomsAndCorridors),
+            GeneratorKind::Bsp => Box::new(BspGenerator),
+            GeneratorKind::CellularCaves => Box::new(CellularCaves),
+        }
+    }
+}
+
+/// Returns all harmless floor tiles of the map, including closed doors.
+#[cfg(test)]
+pub(super) fn floor(
+    map: &DungeonMap,
+) -> std::collections::HashSet<crate::Position> {
+    map.iter()
+        .filter(|(_, tile)| tile.is_passable() && !tile.is_harmful())
+        .map(|(position, _)| position)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn every_generator_is_deterministic_and_connectable() {
+        for kind in [
+            GeneratorKind::RandomWalk,
+            GeneratorKind::RoomsAndCorridors,
+            GeneratorKind::Bsp,
+            GeneratorKind::CellularCaves,
+        ] {
+            let generator = kind.generator(500);
+            let mut first =
+                generator.generate(61, 41, &mut MyRng::from_seed([4; 32]));
+            let second =
+                generator.generate(61, 41, &mut MyRng::from_seed([4; 32]));
+
+            assert_eq!(floor(&first), floor(&second), "{kind:?}");
+            assert!(first.is_walkable(Position::new(0, 0)), "{kind:?}");
+
+            first.connect_pockets(Position::new(0, 0));
+            assert_eq!(
+                first.reachable_from(Position::new(0, 0)),
+                floor(&first),
+                "{kind:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod bsp;
+mod cellular;
+mod map_generator;
+mod random_walk;
+mod room;
+mod rooms;
+
+pub use map_generator::GeneratorKind;
+pub(crate) use map_generator::MapGenerator;
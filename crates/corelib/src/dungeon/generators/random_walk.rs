@@ -0,0 +1,47 @@
+use crate::{
+    Array2D,
+    dungeon::{DungeonMap, Tile},
+    position::Position,
+    rng::MyRng,
+};
+
+use super::MapGenerator;
+
+/// Carves a cave by walking randomly from the center of the map until
+/// enough floor tiles are dug out.
+pub(super) struct RandomWalk {
+    /// Number of floor tiles to carve.
+    pub(super) floor_tiles: usize,
+}
+
+impl MapGenerator for RandomWalk {
+    fn generate(
+        &self,
+        width: usize,
+        height: usize,
+        rng: &mut MyRng,
+    ) -> DungeonMap {
+        let mut tiles = Array2D::empty(width, height);
+        let mut current_position = Position { x: 0, y: 0 };
+        let mut total_tiles = 0;
+        while total_tiles < self.floor_tiles {
+            if tiles.get(current_position) == Some(&Tile::Empty) {
+                tiles.set(current_position, Tile::Floor);
+                total_tiles += 1;
+            }
+
+            let walk_to_x = rng.range(0..=1) != 0;
+            if walk_to_x {
+                current_position += Position { x: rng.range(-1..=1), y: 0 };
+            } else {
+                current_position += Position { x: 0, y: rng.range(-1..=1) };
+            }
+
+            current_position.x = current_position.x.min(tiles.half_width());
+            current_position.x = current_position.x.max(-tiles.half_width());
+            current_position.y = current_position.y.min(tiles.half_height());
+            current_position.y = current_position.y.max(-tiles.half_height());
+        }
+        DungeonMap::from_tiles(tiles)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Array2D, dungeon::Tile, position::Position, rng::MyRng};
+
+/// Rectangular room. Bounds are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Room {
+    pub(super) left: i32,
+    pub(super) top: i32,
+    pub(super) right: i32,
+    pub(super) bottom: i32,
+}
+
+impl Room {
+    /// Creates a room from its top-left corner and size.
+    pub(super) fn new(left: i32, top: i32, width: i32, height: i32) -> Self {
+        Room { left, top, right: left + width - 1, bottom: top + height - 1 }
+    }
+
+    /// Returns true if the rooms overlap or touch each other.
+    pub(super) fn intersects(&self, other: &Room) -> bool {
+        self.left <= other.right + 1
+            && other.left <= self.right + 1
+            && self.top <= other.bottom + 1
+            && other.top <= self.bottom + 1
+    }
+
+    /// Returns the center of the room.
+    pub(super) fn center(&self) -> Position {
+        Position::new(
+            self.left + (self.right - self.left) / 2,
+            self.top + (self.bottom - self.top) / 2,
+        )
+    }
+
+    /// Puts closed doors into the single tile wide openings around the room.
+    ///
+    /// Wider openings, such as a corridor running along the room, are left
+    /// open.
+    pub(super) fn place_doors(&self, tiles: &mut Array2D<Tile>) {
+        let (left, top) = (self.left - 1, self.top - 1);
+        let (right, bottom) = (self.right + 1, self.bottom + 1);
+        let horizontal = (self.left..=self.right).flat_map(|x| {
+            [top, bottom].map(|y| (Position::new(x, y), Position::new(1, 0)))
+        });
+        let vertical = (self.top..=self.bottom).flat_map(|y| {
+            [left, right].map(|x| (Position::new(x, y), Position::new(0, 1)))
+        });
+
+        let doors: Vec<_> = horizontal
+            .chain(vertical)
+            .filter(|(position, along)| {
+                let is_floor =
+                    |position| tiles.get(position) == Some(&Tile::Floor);
+                is_floor(*position)
+                    && !is_floor(*position + *along)
+                    && !is_floor(*position - *along)
+            })
+            .map(|(position, _)| position)
+            .collect();
+        for position in doors {
+            tiles.set(position, Tile::DoorClosed);
+        }
+    }
+
+    /// Turns every tile of the room into floor.
+    pub(super) fn carve(&self, tiles: &mut Array2D<Tile>) {
+        for x in self.left..=self.right {
+            for y in self.top..=self.bottom {
+                tiles.set(Position::new(x, y), Tile::Floor);
+            }
+        }
+    }
+}
+
+/// Carves an L-shaped corridor between two points, randomly choosing which
+/// leg goes first.
+pub(super) fn carve_corridor(
+    tiles: &mut Array2D<Tile>,
+    from: Position,
+    to: Position,
+    rng: &mut MyRng,
+) {
+    let corner = if rng.range(0..=1) == 0 {
+        Position::new(to.x, from.y)
+    } else {
+        Position::new(from.x, to.y)
+    };
+
+    for (start, end) in [(from, corner), (corner, to)] {
+        Room {
+            left: start.x.min(end.x),
+            top: start.y.min(end.y),
+            right: start.x.max(end.x),
+            bottom: start.y.max(end.y),
+        }
+        .carve(tiles);
+    }
+}
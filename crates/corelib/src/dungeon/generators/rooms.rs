@@ -0,0 +1,165 @@
+use crate::{
+    Array2D,
+    dungeon::{DungeonMap, Tile},
+    rng::MyRng,
+};
+
+use super::{
+    MapGenerator,
+    room::{Room, carve_corridor},
+};
+
+/// Smallest side of a room.
+const MIN_ROOM_SIZE: i32 = 4;
+/// Largest side of a room.
+const MAX_ROOM_SIZE: i32 = 10;
+/// Map area reserved for every room when deciding how many rooms to place.
+const AREA_PER_ROOM: usize = 150;
+/// Number of placement attempts per wanted room.
+const ATTEMPTS_PER_ROOM: usize = 4;
+
+/// Places non-overlapping rectangular rooms and joins them with L-shaped
+/// corridors.
+///
+/// The first room always covers the center of the map, where the player
+/// spawns. Every following room is connected to the previous one, so all
+/// rooms are reachable.
+pub(super) struct RoomsAndCorridors;
+
+impl MapGenerator for RoomsAndCorridors {
+    fn generate(
+        &self,
+        width: usize,
+        height: usize,
+        rng: &mut MyRng,
+    ) -> DungeonMap {
+        let mut tiles = Array2D::empty(width, height);
+        if width == 0 || height == 0 {
+            return DungeonMap::from_tiles(tiles);
+        }
+
+        let rooms = place_rooms(&tiles, rng);
+        for room in &rooms {
+            room.carve(&mut tiles);
+        }
+        for pair in rooms.windows(2) {
+            carve_corridor(&mut tiles, pair[0].center(), pair[1].center(), rng);
+        }
+        for room in &rooms {
+            room.place_doors(&mut tiles);
+        }
+
+        DungeonMap::from_tiles(tiles)
+    }
+}
+
+/// Picks the rooms of the map. The first room is centered on the origin.
+fn place_rooms(tiles: &Array2D<Tile>, rng: &mut MyRng) -> Vec<Room> {
+    let top_left = tiles.top_left();
+    let map_width = i32::try_from(tiles.width()).unwrap_or(i32::MAX);
+    let map_height = i32::try_from(tiles.height()).unwrap_or(i32::MAX);
+    let max_room_width = MAX_ROOM_SIZE.min(map_width);
+    let max_room_height = MAX_ROOM_SIZE.min(map_height);
+
+    let random_size = |rng: &mut MyRng| {
+        (
+            rng.range(MIN_ROOM_SIZE.min(max_room_width)..=max_room_width),
+            rng.range(MIN_ROOM_SIZE.min(max_room_height)..=max_room_height),
+        )
+    };
+
+    let (width, height) = random_size(rng);
+    let mut rooms = vec![Room::new(
+        (-width / 2).max(top_left.x),
+        (-height / 2).max(top_left.y),
+        width,
+        height,
+    )];
+
+    let wanted = (tiles.width() * tiles.height() / AREA_PER_ROOM).max(1);
+    for _ in 0..wanted * ATTEMPTS_PER_ROOM {
+        if rooms.len() >= wanted {
+            break;
+        }
+
+        let (width, height) = random_size(rng);
+        let left = rng.range(top_left.x..=top_left.x + map_width - width);
+        let top = rng.range(top_left.y..=top_left.y + map_height - height);
+        let room = Room::new(left, top, width, height);
+
+        if rooms.iter().all(|other| !room.intersects(other)) {
+            rooms.push(room);
+        }
+    }
+
+    rooms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dungeon::generators::map_generator::floor, position::Position,
+    };
+
+    #[test]
+    fn same_seed_produces_same_map() {
+        let first =
+            RoomsAndCorridors.generate(61, 41, &mut MyRng::from_seed([3; 32]));
+        let second =
+            RoomsAndCorridors.generate(61, 41, &mut MyRng::from_seed([3; 32]));
+        assert_eq!(floor(&first), floor(&second));
+    }
+
+    #[test]
+    fn rooms_do_not_overlap() {
+        let tiles = Array2D::<Tile>::empty(101, 101);
+        let rooms = place_rooms(&tiles, &mut MyRng::from_seed([5; 32]));
+        assert!(rooms.len() > 1);
+
+        for (i, room) in rooms.iter().enumerate() {
+            assert!(tiles.in_bounds(Position::new(room.left, room.top)));
+            assert!(tiles.in_bounds(Position::new(room.right, room.bottom)));
+            assert!(rooms[i + 1..].iter().all(|other| !room.intersects(other)));
+        }
+    }
+
+    #[test]
+    fn every_floor_tile_is_reachable_from_origin() {
+        let map = RoomsAndCorridors.generate(
+            101,
+            101,
+            &mut MyRng::from_seed([8; 32]),
+        );
+        assert!(map.is_walkable(Position::new(0, 0)));
+        assert_eq!(map.reachable_from(Position::new(0, 0)), floor(&map));
+    }
+
+    #[test]
+    fn doors_close_single_tile_openings() {
+        let mut tiles = Array2D::<Tile>::empty(11, 11);
+        let room = Room::new(-1, -1, 3, 3);
+        room.carve(&mut tiles);
+        for x in 2..5 {
+            tiles.set(Position::new(x, 0), Tile::Floor);
+        }
+        for x in -1..=1 {
+            tiles.set(Position::new(x, -2), Tile::Floor);
+        }
+
+        room.place_doors(&mut tiles);
+
+        assert_eq!(tiles.get(Position::new(2, 0)), Some(&Tile::DoorClosed));
+        assert_eq!(tiles.get(Position::new(3, 0)), Some(&Tile::Floor));
+        for x in -1..=1 {
+            assert_eq!(tiles.get(Position::new(x, -2)), Some(&Tile::Floor));
+        }
+    }
+
+    #[test]
+    fn tiny_map_is_a_single_room() {
+        let map =
+            RoomsAndCorridors.generate(3, 2, &mut MyRng::from_seed([1; 32]));
+        assert_eq!(floor(&map).len(), 6);
+    }
+}
@@ -1,5 +1,8 @@
+mod connectivity;
 mod dungeon_map;
+mod generators;
 mod tile;
 
 pub use dungeon_map::DungeonMap;
-pub use tile::Tile;
+pub use generators::GeneratorKind;
+pub use tile::{Tile, TileProperties};
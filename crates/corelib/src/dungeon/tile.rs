@@ -1,5 +1,6 @@
 /// Represents a tile in the dungeon.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Tile {
     #[default]
@@ -7,11 +8,138 @@ pub enum Tile {
     Empty = 0,
     /// A tile that can be walked on.
     Floor = 1,
+    /// Stairs leading one level deeper.
+    StairsDown = 2,
+    /// Stairs leading one level up.
+    StairsUp = 3,
+    /// Solid rock bordering the walkable parts of the map.
+    Wall = 4,
+    /// A closed door, blocking movement and sight until opened.
+    DoorClosed = 5,
+    /// Shallow water that pathing avoids when there is a dry way round.
+    Water = 6,
+    /// Lava that burns whatever steps on it.
+    Lava = 7,
+    /// An open door that can be walked and seen through.
+    DoorOpen = 8,
+}
+
+/// Properties shared by every tile of the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileProperties {
+    pub(crate) walkable: bool,
+    pub(crate) blocks_sight: bool,
+    pub(crate) path_weight: u32,
+    pub(crate) damage_on_enter: u32,
+}
+
+impl TileProperties {
+    /// Properties of tiles that can be neither entered nor seen through.
+    const SOLID: Self = Self {
+        walkable: false,
+        blocks_sight: true,
+        path_weight: 1,
+        damage_on_enter: 0,
+    };
+    /// Properties of plain walkable ground.
+    const GROUND: Self = Self {
+        walkable: true,
+        blocks_sight: false,
+        path_weight: 1,
+        damage_on_enter: 0,
+    };
+
+    /// Returns true if the tile can be walked on.
+    #[must_use]
+    pub fn walkable(&self) -> bool {
+        self.walkable
+    }
+
+    /// Returns true if the tile cannot be seen through.
+    #[must_use]
+    pub fn blocks_sight(&self) -> bool {
+        self.blocks_sight
+    }
+
+    /// Returns how strongly pathing avoids the tile.
+    ///
+    /// This only weights route finding. Stepping on any walkable tile takes
+    /// a single turn.
+    #[must_use]
+    pub fn path_weight(&self) -> u32 {
+        self.path_weight
+    }
+
+    /// Returns the damage dealt to whatever steps on the tile.
+    #[must_use]
+    pub fn damage_on_enter(&self) -> u32 {
+        self.damage_on_enter
+    }
 }
 
 impl Tile {
-    /// Gets the tile at the given coordinates.
+    /// Returns the properties of the tile.
+    #[must_use]
+    pub fn properties(&self) -> TileProperties {
+        match self {
+            Tile::Empty | Tile::Wall | Tile::DoorClosed => {
+                TileProperties::SOLID
+            },
+            Tile::Floor
+            | Tile::StairsDown
+            | Tile::StairsUp
+            | Tile::DoorOpen => TileProperties::GROUND,
+            Tile::Water => {
+                TileProperties { path_weight: 2, ..TileProperties::GROUND }
+            },
+            Tile::Lava => {
+                TileProperties { damage_on_enter: 5, ..TileProperties::GROUND }
+            },
+        }
+    }
+
+    /// Returns true if the tile can be walked on.
     pub(crate) fn is_walkable(&self) -> bool {
-        matches!(self, Tile::Floor)
+        self.properties().walkable
+    }
+
+    /// Returns true if the tile can be walked on, possibly after opening it.
+    pub(crate) fn is_passable(&self) -> bool {
+        self.is_walkable() || *self == Tile::DoorClosed
+    }
+
+    /// Returns true if stepping on the tile hurts.
+    pub(crate) fn is_harmful(&self) -> bool {
+        self.properties().damage_on_enter > 0
+    }
+
+    /// Returns true if the tile cannot be seen through.
+    pub(crate) fn blocks_sight(&self) -> bool {
+        self.properties().blocks_sight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walls_differ_from_void_only_in_kind() {
+        assert_ne!(Tile::Wall, Tile::Empty);
+        assert_eq!(Tile::Wall.properties(), Tile::Empty.properties());
+        assert!(!Tile::Wall.is_walkable());
+        assert!(Tile::Wall.blocks_sight());
+    }
+
+    #[test]
+    fn terrain_has_own_properties() {
+        assert!(!Tile::DoorClosed.is_walkable());
+        assert!(Tile::DoorClosed.blocks_sight());
+        assert!(Tile::DoorClosed.is_passable());
+        assert!(Tile::DoorOpen.is_walkable());
+        assert!(!Tile::DoorOpen.blocks_sight());
+        assert_eq!(Tile::Water.properties().path_weight(), 2);
+        assert_eq!(Tile::Lava.properties().damage_on_enter(), 5);
+        assert_eq!(Tile::Floor.properties().damage_on_enter(), 0);
     }
 }
@@ -1,11 +1,12 @@
-use super::kind::EffectKind;
+use super::kind::{EffectKind, Stacking};
 
 /// Represents a definition of an effect.
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
 pub(crate) struct EffectDef {
-    #[allow(dead_code)]
     pub(crate) name: String,
     #[allow(dead_code)]
     pub(crate) description: String,
     pub(crate) kind: EffectKind,
+    /// What happens when the effect is applied again while active.
+    pub(crate) stacking: Stacking,
 }
@@ -1,9 +1,41 @@
 /// Represents the kind of an effect.
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
 pub(crate) enum EffectKind {
     /// Represents a healing effect.
     Heal {
         /// The amount of health to restore every turn.
         hp_per_turn: u32,
     },
+    /// Represents damage over time, like poison.
+    Damage {
+        /// The amount of health lost every turn.
+        hp_per_turn: u32,
+    },
+    /// Represents a buff or debuff of the derived stats.
+    Modify {
+        /// Added to the minimum and maximum attack power.
+        attack: i32,
+        /// Added to the defense power.
+        defense: i32,
+        /// Added to the maximum health.
+        max_hp: i32,
+    },
+    /// Represents a stun: the affected actor loses its turns.
+    Stun,
+    /// Represents haste: the affected actor acts twice as often.
+    Haste,
+    /// Represents slowness: the affected actor acts half as often.
+    Slow,
+}
+
+/// What happens when an effect is applied to an actor it already affects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum Stacking {
+    /// The running instance starts over with the new duration.
+    Refresh,
+    /// Another instance is added and both are active.
+    #[default]
+    Stack,
+    /// The new application has no effect.
+    Ignore,
 }
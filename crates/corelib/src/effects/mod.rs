@@ -2,6 +2,8 @@ pub(crate) type EffectId = usize; // TODO: Maybe use u32 for optimization?
 
 mod effect;
 mod kind;
+mod on_hit;
 
 pub(crate) use effect::EffectDef;
-pub(crate) use kind::EffectKind;
+pub(crate) use kind::{EffectKind, Stacking};
+pub(crate) use on_hit::OnHit;
@@ -0,0 +1,12 @@
+use super::EffectId;
+
+/// An effect inflicted on the target of an attack that hits.
+#[derive(Debug, Clone, Hash)]
+pub(crate) struct OnHit {
+    /// Id of the inflicted effect in the effects catalog.
+    pub(crate) effect_id: EffectId,
+    /// Number of turns the effect lasts.
+    pub(crate) duration: u8,
+    /// Chance in percent that a hit inflicts the effect.
+    pub(crate) chance: u8,
+}
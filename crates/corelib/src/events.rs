@@ -1,17 +1,21 @@
 use crate::{
-    Direction, Position,
+    Position,
     actors::EntityId,
     effects::EffectId,
-    items::{ItemId, SlotId},
+    items::{EquipmentSlot, ItemId, SlotId},
+    mechanics::AttackOutcome,
 };
 
 /// All events in the game.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GameEvent {
     /* --- Player events --- */
     /// Player skipped move event
     PlayerSkippedMove,
     /// Player died event
     PlayerDied,
+    /// Player lost their turn to a stun
+    PlayerStunned,
     /// Player moved
     PlayerMoved {
         /// Player's previous position
@@ -19,39 +23,106 @@ pub enum GameEvent {
         /// Player's new position
         to: Position,
     },
-    /// Player bumped into something
-    PlayerBumped {
-        /// Player's position
-        position: Position,
-        /// Player's direction
-        direction: Direction,
-    },
     /// Player attacked
     PlayerAttacked {
         /// Player's target entity ID
         target: EntityId,
-        /// Player's damage
-        damage: u32,
+        /// Whether the attack hit and for how much damage
+        outcome: AttackOutcome,
     },
-    /// Player attack missed
-    PlayerAttackMissed,
     /// Player equipped item
     PlayerEquippedItem {
         /// Item ID
         item_id: ItemId,
-        /// Slot ID
-        slot: SlotId,
+        /// Equipment slot
+        slot: EquipmentSlot,
     },
     /// Player unequipped item
     PlayerUnequippedItem {
+        /// Equipment slot
+        slot: EquipmentSlot,
+    },
+    /// Player put items into a hotbar slot
+    PlayerAssignedHotbar {
+        /// Item ID
+        item_id: ItemId,
+        /// Slot ID
+        slot: SlotId,
+    },
+    /// Player moved the items of a hotbar slot back to the inventory
+    PlayerClearedHotbar {
         /// Slot ID
         slot: SlotId,
     },
+    /// Player picked up an item from the floor
+    PlayerPickedUpItem {
+        /// Item ID
+        item_id: ItemId,
+        /// Number of items picked up
+        count: u32,
+    },
+    /// Player dropped an item on the floor
+    PlayerDroppedItem {
+        /// Item ID
+        item_id: ItemId,
+        /// Number of items dropped
+        count: u32,
+    },
+    /// Player's inventory has no room for an item
+    PlayerInventoryFull {
+        /// Item ID
+        item_id: ItemId,
+    },
+    /// Player took the stairs to another level
+    PlayerChangedDepth {
+        /// Depth the player left
+        from: u32,
+        /// Depth the player arrived at
+        to: u32,
+    },
+    /// Player opened a door
+    PlayerOpenedDoor {
+        /// Position of the door
+        position: Position,
+    },
+    /// Player closed a door
+    PlayerClosedDoor {
+        /// Position of the door
+        position: Position,
+    },
     /* --- Entity events --- */
-    // /// Entity created
-    // EntityCreated { id: EntityId, position: Position },
-    // /// Entity died event
-    // EntityDied { id: EntityId },
+    /// Entity, including the player, was hurt by the tile it stepped on
+    EntityHurtByTerrain {
+        /// Entity ID
+        id: EntityId,
+        /// Position of the tile
+        position: Position,
+        /// Damage dealt
+        damage: u32,
+    },
+    /// Entity appeared on the player's level
+    EntityCreated {
+        /// Entity ID
+        id: EntityId,
+        /// Entity's position
+        position: Position,
+    },
+    /// Entity died event
+    EntityDied {
+        /// Entity ID
+        id: EntityId,
+    },
+    /// Dead entity left loot on the floor
+    EntityDroppedLoot {
+        /// Entity ID
+        id: EntityId,
+        /// Position of the loot
+        position: Position,
+        /// Item ID
+        item_id: ItemId,
+        /// Number of items dropped
+        count: u32,
+    },
     /// Entity moved
     EntityMoved {
         /// Entity ID
@@ -61,14 +132,21 @@ pub enum GameEvent {
         /// Entity's new position
         to: Position,
     },
+    /// Entity opened a door
+    EntityOpenedDoor {
+        /// Entity ID
+        id: EntityId,
+        /// Position of the door
+        position: Position,
+    },
     /// Entity attacked
     EntityAttacked {
         /// Entity ID
         id: EntityId,
         /// Entity's target position
         target: Position,
-        /// Entity's damage
-        damage: u32,
+        /// Whether the attack hit and for how much damage
+        outcome: AttackOutcome,
     },
     /// Entity effect ticked
     EffectTick {
@@ -84,4 +162,20 @@ pub enum GameEvent {
         /// Effect ID
         effect_id: EffectId,
     },
+    /// Player used up an item
+    ItemUsed {
+        /// Item ID
+        item_id: ItemId,
+    },
+    /// Effect applied to entity
+    EffectApplied {
+        /// Entity ID
+        entity_id: EntityId,
+        /// Effect ID
+        effect_id: EffectId,
+        /// Name of the effect
+        name: String,
+        /// Number of turns the effect lasts
+        duration: u8,
+    },
 }
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use crate::{Array2D, Position};
+
+/// How much the player knows about a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TileVisibility {
+    /// The tile has never been seen.
+    Unknown,
+    /// The tile was seen before but is out of sight now.
+    Remembered,
+    /// The tile is in sight.
+    Visible,
+}
+
+/// What the player currently sees and remembers of the dungeon.
+pub struct FogOfWar<'a> {
+    explored: &'a Array2D<bool>,
+    visible: HashSet<Position>,
+}
+
+impl<'a> FogOfWar<'a> {
+    /// Creates a view over the explored layer and the tiles in sight.
+    pub(crate) fn new(
+        explored: &'a Array2D<bool>,
+        visible: HashSet<Position>,
+    ) -> Self {
+        Self { explored, visible }
+    }
+
+    /// Returns how much the player knows about the tile.
+    #[must_use]
+    pub fn visibility(&self, position: Position) -> TileVisibility {
+        if self.visible.contains(&position) {
+            TileVisibility::Visible
+        } else if self.explored.get(position).copied().unwrap_or_default() {
+            TileVisibility::Remembered
+        } else {
+            TileVisibility::Unknown
+        }
+    }
+
+    /// Returns true if the tile is in sight.
+    #[must_use]
+    pub fn is_visible(&self, position: Position) -> bool {
+        self.visible.contains(&position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_wins_over_remembered() {
+        let mut explored = Array2D::empty(5, 5);
+        explored.set(Position::new(1, 0), true);
+        explored.set(Position::new(2, 0), true);
+        let fog =
+            FogOfWar::new(&explored, HashSet::from([Position::new(1, 0)]));
+
+        assert_eq!(
+            fog.visibility(Position::new(1, 0)),
+            TileVisibility::Visible
+        );
+        assert_eq!(
+            fog.visibility(Position::new(2, 0)),
+            TileVisibility::Remembered
+        );
+        assert_eq!(
+            fog.visibility(Position::new(0, 1)),
+            TileVisibility::Unknown
+        );
+        assert_eq!(
+            fog.visibility(Position::new(9, 9)),
+            TileVisibility::Unknown
+        );
+    }
+}
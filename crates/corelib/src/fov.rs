@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::Position;
+
+/// Transforms from octant-local coordinates to map coordinates.
+const OCTANTS: [(i32, i32, i32, i32); 8] = [
+    (1, 0, 0, 1),
+    (0, 1, 1, 0),
+    (0, -1, 1, 0),
+    (-1, 0, 0, 1),
+    (-1, 0, 0, -1),
+    (0, -1, -1, 0),
+    (0, 1, -1, 0),
+    (1, 0, 0, -1),
+];
+
+/// Computes the set of positions visible from `origin` with recursive
+/// shadowcasting.
+///
+/// Tiles for which `blocks_sight` returns true are visible themselves but hide
+/// everything behind them.
+pub(crate) fn compute_fov(
+    origin: Position,
+    radius: u32,
+    blocks_sight: impl Fn(Position) -> bool,
+) -> HashSet<Position> {
+    let mut visible = HashSet::from([origin]);
+    let radius = i32::try_from(radius).unwrap_or(i32::MAX);
+
+    let mut shadowcaster =
+        Shadowcaster { origin, radius, blocks_sight, visible: &mut visible };
+    for octant in OCTANTS {
+        shadowcaster.cast_light(1, 1.0, 0.0, octant);
+    }
+
+    visible
+}
+
+struct Shadowcaster<'a, F> {
+    origin: Position,
+    radius: i32,
+    blocks_sight: F,
+    visible: &'a mut HashSet<Position>,
+}
+
+impl<F: Fn(Position) -> bool> Shadowcaster<'_, F> {
+    /// Scans one octant row by row, starting at `row`, between the `start` and
+    /// `end` slopes.
+    fn cast_light(
+        &mut self,
+        row: i32,
+        mut start: f64,
+        end: f64,
+        (xx, xy, yx, yy): (i32, i32, i32, i32),
+    ) {
+        if start < end {
+            return;
+        }
+        let radius_squared = self.radius.saturating_mul(self.radius);
+
+        let mut next_start = start;
+        for distance in row..=self.radius {
+            let dy = -distance;
+            let mut blocked = false;
+
+            for dx in -distance..=0 {
+                let left_slope = (f64::from(dx) - 0.5) / (f64::from(dy) + 0.5);
+                let right_slope = (f64::from(dx) + 0.5) / (f64::from(dy) - 0.5);
+                if start < right_slope {
+                    continue;
+                }
+                if end > left_slope {
+                    break;
+                }
+
+                let position = Position::new(
+                    self.origin.x + dx * xx + dy * xy,
+                    self.origin.y + dx * yx + dy * yy,
+                );
+                if dx * dx + dy * dy <= radius_squared {
+                    self.visible.insert(position);
+                }
+
+                let opaque = (self.blocks_sight)(position);
+                if blocked {
+                    if opaque {
+                        next_start = right_slope;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && distance < self.radius {
+                    blocked = true;
+                    self.cast_light(
+                        distance + 1,
+                        start,
+                        left_slope,
+                        (xx, xy, yx, yy),
+                    );
+                    next_start = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_area_is_visible_within_radius() {
+        let visible = compute_fov(Position::new(0, 0), 3, |_| false);
+        assert!(visible.contains(&Position::new(0, 0)));
+        assert!(visible.contains(&Position::new(3, 0)));
+        assert!(visible.contains(&Position::new(-2, 2)));
+        assert!(!visible.contains(&Position::new(4, 0)));
+        assert!(!visible.contains(&Position::new(3, 3)));
+    }
+
+    #[test]
+    fn walls_hide_what_is_behind_them() {
+        let wall =
+            |position: Position| position.x == 2 && position.y.abs() <= 1;
+        let visible = compute_fov(Position::new(0, 0), 6, wall);
+        assert!(visible.contains(&Position::new(2, 0)));
+        assert!(!visible.contains(&Position::new(3, 0)));
+        assert!(!visible.contains(&Position::new(5, 0)));
+        assert!(visible.contains(&Position::new(0, 5)));
+    }
+
+    #[test]
+    fn corridor_walls_limit_sight() {
+        let corridor = |position: Position| position.y != 0;
+        let visible = compute_fov(Position::new(0, 0), 5, corridor);
+        assert!(visible.contains(&Position::new(5, 0)));
+        assert!(visible.contains(&Position::new(-5, 0)));
+        assert!(visible.contains(&Position::new(1, 1)));
+        assert!(!visible.contains(&Position::new(3, 2)));
+    }
+}
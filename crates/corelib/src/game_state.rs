@@ -1,16 +1,31 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    mem,
+};
+
 use crate::{
-    Stats,
+    Array2D, FogOfWar, GameStatus, Position, Stats, WorldSettings,
     actions::{
-        PlayerAction, player_attack, player_equip_item, player_move,
-        player_unequip_item,
+        ActionError, PlayerAction, StairsDestination, player_assign_hotbar,
+        player_attack, player_clear_hotbar, player_close_door,
+        player_drop_item, player_equip_item, player_move, player_open_door,
+        player_pick_up, player_unequip_item, player_use_hotbar_item,
+        player_use_item, player_use_stairs, stairs_destination,
+        validate_action,
     },
     actors::Actor,
     ai::simple_ai,
-    catalog::{EffectsCatalog, ItemsCatalog},
-    dungeon::DungeonMap,
+    catalog::{Content, ItemsCatalog},
+    dungeon::{DungeonMap, Tile},
     effects::EffectKind,
     events::GameEvent,
-    items::{Hotbar, Inventory, ItemKind},
+    fov::compute_fov,
+    items::{Equipment, FloorItems, Hotbar, Inventory, ItemKind},
+    level::Level,
+    mechanics::{
+        drop_loot, entity_turns, modify_stats, refresh_entity_stats,
+        tick_effects,
+    },
     rng::MyRng,
     step_result::{StepContext, StepResult},
     walk_map::WalkMap,
@@ -18,25 +33,45 @@ use crate::{
 
 /// Represents the state of the game.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameState {
     /// The ID of the current tick.
     pub(crate) tick_id: u64,
+    /// The status of the game.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) status: GameStatus,
     /// The player
     pub(crate) player: Actor,
     /// The player's inventory.
     pub(crate) inventory: Inventory,
     /// The player's hotbar.
     pub(crate) hotbar: Hotbar,
+    /// The items the player wears.
+    pub(crate) equipment: Equipment,
     /// Other entities in the game.
     pub(crate) entities: Vec<Actor>,
     /// The dungeon map.
     pub(crate) dungeon: DungeonMap,
+    /// Tiles the player has seen at least once.
+    pub(crate) explored: Array2D<bool>,
+    /// Items lying on the floor of the level.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) items: FloorItems,
+    /// Depth of the level the player is on. The first level has depth 0.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) depth: u32,
+    /// Visited levels the player is not on, by depth.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) levels: BTreeMap<u32, Level>,
+    /// Settings used to generate new levels.
+    pub(crate) settings: WorldSettings,
+    /// ID given to the next generated entity.
+    pub(crate) next_entity_id: u32,
     /// The random number generator.
     pub(crate) rng: MyRng,
-    /// Global items catalog.
-    pub(crate) items_catalog: ItemsCatalog,
-    /// Global effects catalog.
-    pub(crate) effects_catalog: EffectsCatalog,
+    /// Item, effect and actor definitions the game is played with.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) content: Content,
 }
 
 impl GameState {
@@ -47,69 +82,195 @@ impl GameState {
         map: DungeonMap,
         rng: MyRng,
     ) -> Self {
-        GameState {
+        let next_entity_id = entities
+            .iter()
+            .chain([&player])
+            .map(|actor| actor.id.into_inner() + 1)
+            .max()
+            .unwrap_or_default();
+        let mut state = GameState {
             tick_id: 0,
+            status: GameStatus::Running,
             player,
             entities,
+            explored: Array2D::empty(map.width(), map.height()),
             dungeon: map,
+            items: FloorItems::default(),
+            depth: 0,
+            levels: BTreeMap::new(),
+            settings: WorldSettings::default(),
+            next_entity_id,
             rng,
             hotbar: Hotbar::empty(),
+            equipment: Equipment::empty(),
             inventory: Inventory::empty(),
-            items_catalog: ItemsCatalog::new(),
-            effects_catalog: EffectsCatalog::new(),
-        }
+            content: Content::default(),
+        };
+        state.explore();
+        state
     }
 
     /// Applies the given player action to the game state.
-    pub fn apply_player_action(&mut self, action: &PlayerAction) -> StepResult {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the action was refused. A refused action does not
+    /// advance the game.
+    pub fn apply_player_action(
+        &mut self,
+        action: &PlayerAction,
+    ) -> Result<StepResult, ActionError> {
+        if !self.player.is_alive() {
+            return Err(ActionError::Dead);
+        }
+        if self.status.is_over() {
+            return Err(ActionError::GameOver { status: self.status });
+        }
+
         let mut walk_map = self.recalculate_walk_map();
-        // TODO: add "dirty" flag, recalculate only after player action
-        self.player.stats = self.calculate_hotbar_stats();
+        validate_action(self, action, &walk_map)?;
+
+        // Turns are read before effects tick, so an effect lasting n turns
+        // costs or grants its victim exactly n turns.
+        let stunned =
+            self.player_affected(|kind| matches!(kind, EffectKind::Stun));
+        let enemy_turns = self.enemy_turns();
+        let entity_turns: BTreeMap<_, _> = self
+            .entities
+            .iter()
+            .map(|entity| {
+                (
+                    entity.id,
+                    entity_turns(&self.content.effects, entity, self.tick_id),
+                )
+            })
+            .collect();
+
+        // Levels are generated before anything changes, so a level that
+        // cannot be generated refuses the action. A stunned player goes
+        // nowhere.
+        let destination =
+            if stunned { None } else { stairs_destination(self, action)? };
 
         let mut step_context = StepContext::default();
 
-        Self::tick_effects(
-            &mut step_context,
-            &self.effects_catalog,
-            &mut self.player,
-        );
+        self.tick_all_effects(&mut step_context, &mut walk_map);
+
+        // TODO: add "dirty" flag, recalculate only after player action
+        self.refresh_player_stats(&mut step_context);
+
+        // A player killed by an effect does not get to act
+        if self.player.is_alive() {
+            if stunned {
+                step_context.add_event(GameEvent::PlayerStunned);
+            } else {
+                self.apply_action(
+                    &mut step_context,
+                    action,
+                    destination,
+                    &mut walk_map,
+                );
+            }
+        }
+
+        for _ in 0..enemy_turns {
+            if !self.player.is_alive() {
+                break;
+            }
+            simple_ai(self, &mut step_context, &mut walk_map, &entity_turns);
+        }
+        self.remove_dead_entities(&mut step_context);
 
+        if !self.player.is_alive() {
+            self.status = GameStatus::PlayerDead;
+            step_context.add_event(GameEvent::PlayerDied);
+        }
+
+        self.explore();
+        self.tick_id += 1;
+
+        Ok(step_context.build())
+    }
+
+    /// Carries out the player action.
+    fn apply_action(
+        &mut self,
+        step_context: &mut StepContext,
+        action: &PlayerAction,
+        destination: Option<StairsDestination>,
+        walk_map: &mut WalkMap,
+    ) {
         match action {
             PlayerAction::Skip => {
                 step_context.add_event(GameEvent::PlayerSkippedMove);
             },
             PlayerAction::Move(direction) => {
-                player_move(self, &mut step_context, *direction, &mut walk_map);
+                player_move(self, step_context, *direction, walk_map);
             },
             PlayerAction::Attack(direction) => {
-                player_attack(self, &mut step_context, *direction);
+                player_attack(self, step_context, *direction, walk_map);
             },
             PlayerAction::EquipItem { item_id, slot } => {
-                player_equip_item(self, &mut step_context, *item_id, *slot);
+                player_equip_item(self, step_context, *item_id, *slot);
             },
             PlayerAction::UnequipItem { slot } => {
-                player_unequip_item(self, &mut step_context, *slot);
+                player_unequip_item(self, step_context, *slot);
+            },
+            PlayerAction::AssignHotbar { item_id, slot } => {
+                player_assign_hotbar(self, step_context, *item_id, *slot);
+            },
+            PlayerAction::ClearHotbar { slot } => {
+                player_clear_hotbar(self, step_context, *slot);
+            },
+            PlayerAction::Open(direction) => {
+                player_open_door(self, step_context, *direction, walk_map);
+            },
+            PlayerAction::Close(direction) => {
+                player_close_door(self, step_context, *direction, walk_map);
+            },
+            PlayerAction::PickUp => {
+                player_pick_up(self, step_context);
+            },
+            PlayerAction::Drop { item_id, count } => {
+                player_drop_item(self, step_context, *item_id, *count);
+            },
+            PlayerAction::UseItem { item_id } => {
+                player_use_item(self, step_context, *item_id);
+            },
+            PlayerAction::UseHotbarItem { slot } => {
+                player_use_hotbar_item(self, step_context, *slot);
+            },
+            PlayerAction::Descend | PlayerAction::Ascend => {
+                player_use_stairs(self, step_context, destination);
+                *walk_map = self.recalculate_walk_map();
             },
         }
-
-        simple_ai(self, &mut step_context, &mut walk_map);
-
-        self.tick_id += 1;
-
-        step_context.build()
     }
 
     /// Recalculates the walk map based on the current dungeon and entities.
+    ///
+    /// Damage dealt by a tile counts towards its path weight, so the AI
+    /// steps around lava when it can.
     #[must_use]
-    fn recalculate_walk_map(&self) -> WalkMap {
+    pub(crate) fn recalculate_walk_map(&self) -> WalkMap {
         let mut walk_map = self
             .dungeon
             .iter()
-            .filter_map(|(position, tile)| {
-                (tile.is_walkable()).then_some(position)
+            .map(|(position, tile)| (position, tile.properties()))
+            .filter(|(_, properties)| properties.walkable())
+            .map(|(position, properties)| {
+                (
+                    position,
+                    properties.path_weight() + properties.damage_on_enter(),
+                )
             })
             .collect::<WalkMap>();
 
+        for (position, tile) in self.dungeon.iter() {
+            if *tile == Tile::DoorClosed {
+                walk_map.close_door(position);
+            }
+        }
         for entity in &self.entities {
             walk_map.occupy(entity.position);
         }
@@ -118,66 +279,177 @@ impl GameState {
         walk_map
     }
 
-    /// Calculates the stats for the player by iterating over the hotbar items and calculating their stats.
+    /// Removes the entities killed by something other than the player and
+    /// drops their loot.
+    ///
+    /// Losing the last enemy of the deepest level this way wins the game as
+    /// well.
+    fn remove_dead_entities(&mut self, step_context: &mut StepContext) {
+        if self.entities.iter().all(Actor::is_alive) {
+            return;
+        }
+
+        let (alive, dead): (Vec<_>, Vec<_>) = mem::take(&mut self.entities)
+            .into_iter()
+            .partition(Actor::is_alive);
+        self.entities = alive;
+        for corpse in dead {
+            step_context.diff().despawn(corpse.id);
+            step_context.add_event(GameEvent::EntityDied { id: corpse.id });
+            drop_loot(
+                step_context,
+                &self.content.loot,
+                &mut self.items,
+                &corpse,
+                &mut self.rng,
+            );
+        }
+
+        self.check_victory();
+    }
+
+    /// Wins the game once the deepest level is cleared of enemies.
+    pub(crate) fn check_victory(&mut self) {
+        if self.entities.is_empty() && self.depth + 1 >= self.settings.levels {
+            self.status = GameStatus::Victory;
+        }
+    }
+
+    /// Recalculates the player stats from the equipment and records the
+    /// change.
+    pub(crate) fn refresh_player_stats(
+        &mut self,
+        step_context: &mut StepContext,
+    ) {
+        let stats = self.calculate_player_stats();
+        step_context.diff().stat(
+            self.player.id,
+            self.player.stats.clone(),
+            stats.clone(),
+        );
+        self.player.stats = stats;
+    }
+
+    /// Calculates the stats for the player by adding up the stats of the worn
+    /// items and the modifiers of the effects on the player.
+    ///
+    /// Health above the resulting maximum is lost.
     #[must_use]
-    fn calculate_hotbar_stats(&self) -> Stats {
-        let mut stats = self.player.kind.default_stats();
-        stats.hp = self.player.stats.hp;
+    fn calculate_player_stats(&self) -> Stats {
+        let mut stats = self.content.actors.stats(self.player.kind);
 
-        for stack in self.hotbar.iter().flatten() {
-            let Some(item) = self.items_catalog.get(stack.item_id) else {
+        for stack in self.equipment.items() {
+            let Some(item) = self.content.items.get(stack.item_id) else {
                 continue;
             };
             match item.kind {
-                ItemKind::Weapon { min_damage, max_damage } => {
+                ItemKind::Weapon { min_damage, max_damage, .. } => {
                     stats.min_damage += min_damage;
                     stats.max_damage += max_damage;
                 },
-                ItemKind::Armor { defense } => {
+                ItemKind::Armor { defense, .. } => {
                     stats.defense += defense;
                 },
+                ItemKind::Consumable { .. } => {},
             }
         }
 
+        modify_stats(&self.content.effects, &mut stats, &self.player.effects);
+        stats.hp = self.player.stats.hp.min(stats.max_hp);
         stats
     }
 
-    /// Ticks the effects of an entity.
-    fn tick_effects(
+    /// Ticks the effects of every actor: the player first, then the entities
+    /// in entity order.
+    ///
+    /// Entities have their stats recalculated as their modifiers change.
+    /// Entities killed by their effects are removed right away, so they
+    /// neither act nor block the way.
+    fn tick_all_effects(
+        &mut self,
         step_context: &mut StepContext,
-        effects_catalog: &EffectsCatalog,
-        entity: &mut Actor,
+        walk_map: &mut WalkMap,
     ) {
-        entity.effects.retain_mut(|effect| {
-            if effect.remaining_turns == 0 {
-                step_context.add_event(GameEvent::EffectExpired {
-                    entity_id: entity.id,
-                    effect_id: effect.effect_id,
-                });
-                return false;
-            }
-            effect.remaining_turns = effect.remaining_turns.saturating_sub(1);
-
-            let Some(effect_def) = effects_catalog.get(effect.effect_id) else {
-                // Unknown effect, remove it
-                step_context.add_event(GameEvent::EffectExpired {
-                    entity_id: entity.id,
-                    effect_id: effect.effect_id,
-                });
-                return false;
-            };
-            match effect_def.kind {
-                EffectKind::Heal { hp_per_turn } => {
-                    entity.stats.hp += hp_per_turn;
-                },
+        tick_effects(step_context, &self.content.effects, &mut self.player);
+
+        let mut killed = false;
+        for entity in &mut self.entities {
+            if entity.effects.is_empty() {
+                continue;
             }
-            step_context.add_event(GameEvent::EffectTick {
-                entity_id: entity.id,
-                effect_id: effect.effect_id,
-            });
+            tick_effects(step_context, &self.content.effects, entity);
+            refresh_entity_stats(step_context, &self.content, entity);
+            killed |= !entity.is_alive();
+        }
+        if killed {
+            self.remove_dead_entities(step_context);
+            *walk_map = self.recalculate_walk_map();
+        }
+    }
+
+    /// Returns true if an effect acting on the player this turn matches.
+    fn player_affected(&self, matches: fn(&EffectKind) -> bool) -> bool {
+        self.content.effects.active(&self.player.effects).any(matches)
+    }
+
+    /// Returns how many turns the enemies take after the player's turn.
+    ///
+    /// A hasted player acts twice for every enemy turn, a slowed one lets the
+    /// enemies act twice. Both together cancel out.
+    fn enemy_turns(&self) -> usize {
+        let hasted =
+            self.player_affected(|kind| matches!(kind, EffectKind::Haste));
+        let slowed =
+            self.player_affected(|kind| matches!(kind, EffectKind::Slow));
+        match (hasted, slowed) {
+            (true, false) => usize::from(self.tick_id % 2 == 1),
+            (false, true) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the positions the actor can currently see.
+    ///
+    /// Walls limit the view, and so does the actor's sight radius.
+    #[must_use]
+    pub fn visible_tiles(&self, actor: &Actor) -> HashSet<Position> {
+        compute_fov(actor.position, actor.stats.sight_radius, |position| {
+            self.dungeon.get_tile(position).blocks_sight()
+        })
+    }
+
+    /// Marks every tile the player can see as explored.
+    fn explore(&mut self) {
+        for position in self.visible_tiles(&self.player) {
+            self.explored.set(position, true);
+        }
+    }
 
-            true
-        });
+    /// Returns what the player currently sees and remembers.
+    #[must_use]
+    pub fn fog_of_war(&self) -> FogOfWar<'_> {
+        FogOfWar::new(&self.explored, self.visible_tiles(&self.player))
+    }
+
+    /// Returns true if the actor can see the given position.
+    #[must_use]
+    pub fn can_see(&self, actor: &Actor, position: Position) -> bool {
+        let offset = position - actor.position;
+        let distance = offset.x().unsigned_abs().max(offset.y().unsigned_abs());
+        distance <= actor.stats.sight_radius
+            && self.visible_tiles(actor).contains(&position)
+    }
+
+    /// Returns the status of the game.
+    #[must_use]
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// Returns the depth of the level the player is on.
+    #[must_use]
+    pub fn depth(&self) -> u32 {
+        self.depth
     }
 
     /// Returns a reference to the player.
@@ -201,7 +473,7 @@ impl GameState {
     /// Returns a reference to the items catalog.
     #[must_use]
     pub fn items_catalog(&self) -> &ItemsCatalog {
-        &self.items_catalog
+        &self.content.items
     }
 
     /// Returns a reference to the hotbar.
@@ -210,6 +482,18 @@ impl GameState {
         &self.hotbar
     }
 
+    /// Returns the items the player wears.
+    #[must_use]
+    pub fn equipment(&self) -> &Equipment {
+        &self.equipment
+    }
+
+    /// Returns the items lying on the floor of the level.
+    #[must_use]
+    pub fn floor_items(&self) -> &FloorItems {
+        &self.items
+    }
+
     /// Returns a reference to the inventory.
     #[must_use]
     pub fn inventory(&self) -> &Inventory {
@@ -217,15 +501,64 @@ impl GameState {
     }
 }
 
+#[cfg(test)]
+impl GameState {
+    /// Makes every attack hit without critical hits, so tests relying on
+    /// damage do not depend on the rolls.
+    pub(crate) fn sure_hits(&mut self) {
+        let actors =
+            std::iter::once(&mut self.player).chain(&mut self.entities);
+        let stats = self
+            .content
+            .actors
+            .stats
+            .iter_mut()
+            .map(|(_, stats)| stats)
+            .chain(actors.map(|actor| &mut actor.stats));
+        for stats in stats {
+            stats.accuracy = 100;
+            stats.evasion = 0;
+            stats.crit_chance = 0;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::{ActorKind, Direction, position::Position};
+    use crate::{
+        ActorKind, Direction, EquipmentSlot, TileVisibility, effects::EffectId,
+        items::ItemStack, mechanics::apply_effect, position::Position,
+    };
 
     use super::*;
 
+    const STRENGTH: EffectId = 3;
+    const VIGOR: EffectId = 6;
+    const STUN: EffectId = 7;
+    const HASTE: EffectId = 8;
+    const SLOW: EffectId = 9;
+
+    fn affect(gs: &mut GameState, effect_id: EffectId, duration: u8) {
+        apply_effect(
+            &mut StepContext::default(),
+            &gs.content.effects,
+            &mut gs.player,
+            effect_id,
+            duration,
+        );
+    }
+
+    fn enemy_moves(result: &StepResult) -> usize {
+        result
+            .events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::EntityMoved { .. }))
+            .count()
+    }
+
     #[test]
-    fn test_tick_increment() {
+    fn test_tick_increment() -> Result<(), ActionError> {
         let mut gs = GameState::new(
             Actor::create_player(Position::new(0, 0)),
             vec![],
@@ -233,13 +566,14 @@ mod tests {
             MyRng::new(),
         );
 
-        gs.apply_player_action(&PlayerAction::Skip);
+        gs.apply_player_action(&PlayerAction::Skip)?;
 
         assert_eq!(gs.tick_id, 1);
+        Ok(())
     }
 
     #[test]
-    fn player_event_before_npc_event() {
+    fn player_event_before_npc_event() -> Result<(), ActionError> {
         let mut gs = GameState::new(
             Actor::create_player(Position::new(0, 0)),
             vec![Actor::create(Position::new(3, 0), ActorKind::Enemy)],
@@ -247,14 +581,44 @@ mod tests {
             MyRng::new(),
         );
         let result =
-            gs.apply_player_action(&PlayerAction::Move(Direction::East));
+            gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
         let events: Vec<_> = result.events.into_iter().collect();
         assert!(matches!(events[0], GameEvent::PlayerMoved { .. }));
         assert!(matches!(events[1], GameEvent::EntityMoved { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn enemy_killed_by_lava_is_removed() -> Result<(), ActionError> {
+        let mut dungeon = DungeonMap::simple(30, 30);
+        for y in -10..10 {
+            dungeon.set_tile(Position::new(1, y), Tile::Wall);
+        }
+        dungeon.set_tile(Position::new(1, 0), Tile::Lava);
+        let mut enemy = Actor::create(Position::new(2, 0), ActorKind::Enemy);
+        enemy.stats.hp = 5;
+        let enemy_id = enemy.id;
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![enemy],
+            dungeon,
+            MyRng::new(),
+        );
+        gs.settings.levels = 1;
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+
+        assert!(gs.entities.is_empty());
+        assert_eq!(result.diff.despawned, vec![enemy_id]);
+        assert!(
+            result.events.contains(&GameEvent::EntityDied { id: enemy_id })
+        );
+        assert_eq!(gs.status(), GameStatus::Victory);
+        Ok(())
     }
 
     #[test]
-    fn determinism_same_seed_and_actions() {
+    fn determinism_same_seed_and_actions() -> Result<(), ActionError> {
         let seed = [4; 32];
         let mut gs1 = GameState::new(
             Actor::create_player(Position::new(0, 0)),
@@ -269,8 +633,8 @@ mod tests {
             MyRng::from_seed(seed),
         );
         for _ in 0..2 {
-            let r1 = gs1.apply_player_action(&PlayerAction::Skip);
-            let r2 = gs2.apply_player_action(&PlayerAction::Skip);
+            let r1 = gs1.apply_player_action(&PlayerAction::Skip)?;
+            let r2 = gs2.apply_player_action(&PlayerAction::Skip)?;
             let e1: Vec<_> = r1.events.into_iter().collect();
             let e2: Vec<_> = r2.events.into_iter().collect();
             assert_eq!(e1.len(), e2.len());
@@ -280,22 +644,306 @@ mod tests {
                     std::mem::discriminant(b)
                 );
                 if let (
-                        GameEvent::EntityAttacked {
-                            target: t1,
-                            damage: d1,
-                            ..
-                        },
-                        GameEvent::EntityAttacked {
-                            target: t2,
-                            damage: d2,
-                            ..
-                        },
-                    ) = (a, b) {
+                    GameEvent::EntityAttacked {
+                        target: t1, outcome: o1, ..
+                    },
+                    GameEvent::EntityAttacked {
+                        target: t2, outcome: o2, ..
+                    },
+                ) = (a, b)
+                {
                     assert_eq!(t1, t2);
-                    assert_eq!(d1, d2);
+                    assert_eq!(o1, o2);
                 }
             }
             assert_eq!(gs1.player.stats.hp, gs2.player.stats.hp);
         }
+        Ok(())
+    }
+
+    #[test]
+    fn player_death_ends_game() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position::new(1, 0), ActorKind::Enemy)],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        gs.sure_hits();
+        gs.player.stats.hp = 1;
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(result.events.back(), Some(&GameEvent::PlayerDied));
+        assert_eq!(gs.status(), GameStatus::PlayerDead);
+
+        let refused = gs.apply_player_action(&PlayerAction::Skip).err();
+        assert_eq!(refused, Some(ActionError::Dead));
+        assert_eq!(gs.tick_id, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn killing_last_enemy_wins_game() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position::new(1, 0), ActorKind::Enemy)],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        gs.sure_hits();
+        gs.settings.levels = 1;
+        gs.entities[0].stats.hp = 1;
+
+        gs.apply_player_action(&PlayerAction::Attack(Direction::East))?;
+        assert_eq!(gs.status(), GameStatus::Victory);
+        assert!(gs.apply_player_action(&PlayerAction::Skip).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn clearing_upper_level_keeps_game_running() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position::new(1, 0), ActorKind::Enemy)],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        gs.sure_hits();
+        gs.settings.levels = 3;
+        gs.entities[0].stats.hp = 1;
+
+        gs.apply_player_action(&PlayerAction::Attack(Direction::East))?;
+        assert!(gs.entities.is_empty());
+        assert_eq!(gs.depth(), 0);
+        assert_eq!(gs.status(), GameStatus::Running);
+        assert!(gs.apply_player_action(&PlayerAction::Skip).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn step_diff_records_moves_and_damage() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position::new(3, 0), ActorKind::Enemy)],
+            DungeonMap::simple(10, 10),
+            MyRng::new(),
+        );
+        gs.sure_hits();
+        let player_id = gs.player.id;
+        let enemy_id = gs.entities[0].id;
+
+        let diff =
+            gs.apply_player_action(&PlayerAction::Move(Direction::East))?.diff;
+        let moves: Vec<_> = diff
+            .positions
+            .iter()
+            .map(|d| (d.entity_id, d.old, d.new))
+            .collect();
+        assert_eq!(
+            moves,
+            vec![
+                (player_id, Position::new(0, 0), Position::new(1, 0)),
+                (enemy_id, Position::new(3, 0), Position::new(2, 0)),
+            ]
+        );
+        assert!(diff.stats.is_empty());
+
+        let diff = gs
+            .apply_player_action(&PlayerAction::Attack(Direction::East))?
+            .diff;
+        let enemy = diff.stats.iter().find(|d| d.entity_id == enemy_id);
+        assert!(enemy.is_some_and(|d| d.new.hp < d.old.hp));
+        let player = diff.stats.iter().find(|d| d.entity_id == player_id);
+        assert!(player.is_some_and(|d| d.new.hp < d.old.hp));
+        Ok(())
+    }
+
+    #[test]
+    fn step_diff_records_equipment_and_despawn() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![Actor::create(Position::new(1, 0), ActorKind::Enemy)],
+            DungeonMap::simple(5, 5),
+            MyRng::new(),
+        );
+        gs.sure_hits();
+        let enemy_id = gs.entities[0].id;
+        gs.entities[0].stats.hp = 1;
+        assert!(
+            gs.inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok()
+        );
+
+        let diff = gs
+            .apply_player_action(&PlayerAction::EquipItem {
+                item_id: 0,
+                slot: EquipmentSlot::MainHand,
+            })?
+            .diff;
+        assert_eq!(diff.inventory.len(), 1);
+        assert!(diff.inventory[0].new.is_none());
+        assert_eq!(diff.equipment.len(), 1);
+        assert_eq!(diff.equipment[0].slot, EquipmentSlot::MainHand);
+        assert!(diff.equipment[0].new.as_ref().is_some_and(|s| s.id() == 0));
+        assert!(diff.stats.iter().any(|d| d.entity_id == gs.player.id
+            && d.new.max_damage > d.old.max_damage));
+
+        let diff = gs
+            .apply_player_action(&PlayerAction::Attack(Direction::East))?
+            .diff;
+        assert_eq!(diff.despawned, vec![enemy_id]);
+        Ok(())
+    }
+
+    #[test]
+    fn visible_tiles_follow_sight_radius() {
+        let gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(30, 30),
+            MyRng::new(),
+        );
+        let radius = gs.player.stats.sight_radius;
+        let edge = i32::try_from(radius).unwrap_or(i32::MAX);
+
+        let visible = gs.visible_tiles(&gs.player);
+        assert!(visible.contains(&Position::new(edge, 0)));
+        assert!(!visible.contains(&Position::new(edge + 1, 0)));
+        assert!(gs.can_see(&gs.player, Position::new(0, -edge)));
+        assert!(!gs.can_see(&gs.player, Position::new(0, edge + 1)));
+    }
+
+    #[test]
+    fn player_remembers_explored_tiles() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(30, 30),
+            MyRng::new(),
+        );
+        let behind = Position::new(-8, 0);
+        let far = Position::new(0, 9);
+        assert_eq!(gs.fog_of_war().visibility(behind), TileVisibility::Visible);
+        assert_eq!(gs.fog_of_war().visibility(far), TileVisibility::Unknown);
+
+        gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
+        gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
+        assert_eq!(
+            gs.fog_of_war().visibility(behind),
+            TileVisibility::Remembered
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stunned_player_loses_turn() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(10, 10),
+            MyRng::new(),
+        );
+        affect(&mut gs, STUN, 1);
+
+        let result =
+            gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
+        assert!(result.events.contains(&GameEvent::PlayerStunned));
+        assert_eq!(gs.player.position, Position::new(0, 0));
+        assert_eq!(gs.tick_id, 1);
+
+        gs.apply_player_action(&PlayerAction::Move(Direction::East))?;
+        assert_eq!(gs.player.position, Position::new(1, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn stat_modifiers_last_while_the_effect_does() -> Result<(), ActionError> {
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![],
+            DungeonMap::simple(10, 10),
+            MyRng::new(),
+        );
+        let base = gs.player.stats.clone();
+        affect(&mut gs, STRENGTH, 2);
+        affect(&mut gs, VIGOR, 2);
+
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(gs.player.stats.min_damage, base.min_damage + 3);
+        assert_eq!(gs.player.stats.max_damage, base.max_damage + 3);
+        assert_eq!(gs.player.stats.max_hp, base.max_hp + 10);
+
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        gs.apply_player_action(&PlayerAction::Skip)?;
+        assert!(gs.player.effects.is_empty());
+        assert_eq!(gs.player.stats, base);
+        Ok(())
+    }
+
+    #[test]
+    fn haste_and_slow_change_enemy_turns() -> Result<(), ActionError> {
+        let game = || {
+            GameState::new(
+                Actor::create_player(Position::new(0, 0)),
+                vec![Actor::create(Position::new(4, 0), ActorKind::Enemy)],
+                DungeonMap::simple(10, 10),
+                MyRng::new(),
+            )
+        };
+
+        let mut hasted = game();
+        affect(&mut hasted, HASTE, 5);
+        let first = hasted.apply_player_action(&PlayerAction::Skip)?;
+        let second = hasted.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(enemy_moves(&first) + enemy_moves(&second), 1);
+
+        let mut slowed = game();
+        affect(&mut slowed, SLOW, 5);
+        let result = slowed.apply_player_action(&PlayerAction::Skip)?;
+        assert_eq!(enemy_moves(&result), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn effects_tick_on_every_actor() -> Result<(), ActionError> {
+        const POISON: EffectId = 2;
+
+        let mut gs = GameState::new(
+            Actor::create_player(Position::new(0, 0)),
+            vec![
+                Actor::create(Position::new(9, 9), ActorKind::Skeleton),
+                Actor::create(Position::new(-9, 9), ActorKind::Skeleton),
+            ],
+            DungeonMap::simple(20, 20),
+            MyRng::new(),
+        );
+        let (hurt_id, dying_id) = (gs.entities[0].id, gs.entities[1].id);
+        for entity in &mut gs.entities {
+            apply_effect(
+                &mut StepContext::default(),
+                &gs.content.effects,
+                entity,
+                POISON,
+                3,
+            );
+        }
+        gs.entities[1].stats.hp = 1;
+        let hp = gs.entities[0].stats.hp;
+
+        let result = gs.apply_player_action(&PlayerAction::Skip)?;
+        let ticked: Vec<_> = result
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::EffectTick { entity_id, .. } => Some(*entity_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ticked, vec![hurt_id, dying_id]);
+        assert!(
+            result.events.contains(&GameEvent::EntityDied { id: dying_id })
+        );
+        assert_eq!(gs.entities.len(), 1);
+        assert_eq!(gs.entities[0].stats.hp, hp - 1);
+        Ok(())
     }
 }
@@ -0,0 +1,20 @@
+/// Represents the overall status of a game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameStatus {
+    /// The game is in progress.
+    #[default]
+    Running,
+    /// The player has died.
+    PlayerDead,
+    /// The player has defeated every enemy.
+    Victory,
+}
+
+impl GameStatus {
+    /// Returns true if the game has ended.
+    #[must_use]
+    pub fn is_over(self) -> bool {
+        self != GameStatus::Running
+    }
+}
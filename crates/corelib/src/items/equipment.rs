@@ -0,0 +1,110 @@
+use std::fmt;
+
+use super::item_stack::ItemStack;
+
+/// A named place on the body an item can be worn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EquipmentSlot {
+    /// The weapon hand.
+    MainHand,
+    /// The other hand, holding a shield. Taken by two-handed weapons.
+    OffHand,
+    /// The helmet.
+    Head,
+    /// The body armor.
+    Body,
+    /// The ring.
+    Ring,
+}
+
+impl EquipmentSlot {
+    /// Every slot, in the order they are shown.
+    pub const ALL: [EquipmentSlot; 5] = [
+        EquipmentSlot::MainHand,
+        EquipmentSlot::OffHand,
+        EquipmentSlot::Head,
+        EquipmentSlot::Body,
+        EquipmentSlot::Ring,
+    ];
+}
+
+impl fmt::Display for EquipmentSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquipmentSlot::MainHand => write!(f, "main hand"),
+            EquipmentSlot::OffHand => write!(f, "off hand"),
+            EquipmentSlot::Head => write!(f, "head"),
+            EquipmentSlot::Body => write!(f, "body"),
+            EquipmentSlot::Ring => write!(f, "ring"),
+        }
+    }
+}
+
+/// The items the player wears, one per slot.
+#[derive(Debug, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equipment {
+    slots: [Option<ItemStack>; EquipmentSlot::ALL.len()],
+}
+
+impl Equipment {
+    pub(crate) fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Returns the item worn in the given slot.
+    #[must_use]
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&ItemStack> {
+        self.slots[slot as usize].as_ref()
+    }
+
+    /// Iterates over every slot and the item worn in it.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (EquipmentSlot, Option<&ItemStack>)> {
+        EquipmentSlot::ALL.into_iter().map(|slot| (slot, self.get(slot)))
+    }
+
+    /// Iterates over the worn items.
+    pub(crate) fn items(&self) -> impl Iterator<Item = &ItemStack> {
+        self.slots.iter().flatten()
+    }
+
+    pub(crate) fn equip(&mut self, stack: ItemStack, slot: EquipmentSlot) {
+        self.slots[slot as usize] = Some(stack);
+    }
+
+    pub(crate) fn take(&mut self, slot: EquipmentSlot) -> Option<ItemStack> {
+        self.slots[slot as usize].take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_hold_one_item_each() {
+        let mut equipment = Equipment::empty();
+        equipment
+            .equip(ItemStack { item_id: 0, count: 1 }, EquipmentSlot::MainHand);
+        equipment
+            .equip(ItemStack { item_id: 2, count: 1 }, EquipmentSlot::Body);
+
+        assert_eq!(
+            equipment.get(EquipmentSlot::MainHand).map(ItemStack::id),
+            Some(0)
+        );
+        assert!(equipment.get(EquipmentSlot::OffHand).is_none());
+        assert_eq!(equipment.items().count(), 2);
+
+        assert!(equipment.take(EquipmentSlot::Body).is_some());
+        assert!(equipment.take(EquipmentSlot::Body).is_none());
+        let worn: Vec<_> = equipment
+            .iter()
+            .filter_map(|(slot, stack)| stack.map(|_| slot))
+            .collect();
+        assert_eq!(worn, vec![EquipmentSlot::MainHand]);
+    }
+}
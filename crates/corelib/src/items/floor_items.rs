@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+use crate::Position;
+
+use super::item_stack::ItemStack;
+
+/// Items lying on the floor of a level, by position.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<(Position, Vec<ItemStack>)>"),
+    serde(into = "Vec<(Position, Vec<ItemStack>)>")
+)]
+pub struct FloorItems {
+    stacks: BTreeMap<Position, Vec<ItemStack>>,
+}
+
+impl FloorItems {
+    /// Puts a stack on the floor. Stacks dropped on the same position pile up.
+    pub(crate) fn add(&mut self, position: Position, stack: ItemStack) {
+        self.stacks.entry(position).or_default().push(stack);
+    }
+
+    /// Removes and returns every stack lying at the given position.
+    pub(crate) fn take_all(&mut self, position: Position) -> Vec<ItemStack> {
+        self.stacks.remove(&position).unwrap_or_default()
+    }
+
+    /// Returns the stacks lying at the given position.
+    #[must_use]
+    pub fn at(&self, position: Position) -> &[ItemStack] {
+        self.stacks.get(&position).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns every position holding items, in position order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &[ItemStack])> {
+        self.stacks
+            .iter()
+            .map(|(position, stacks)| (*position, stacks.as_slice()))
+    }
+}
+
+impl From<Vec<(Position, Vec<ItemStack>)>> for FloorItems {
+    fn from(stacks: Vec<(Position, Vec<ItemStack>)>) -> Self {
+        Self {
+            stacks: stacks
+                .into_iter()
+                .filter(|(_, stacks)| !stacks.is_empty())
+                .collect(),
+        }
+    }
+}
+
+impl From<FloorItems> for Vec<(Position, Vec<ItemStack>)> {
+    fn from(items: FloorItems) -> Self {
+        items.stacks.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacks_pile_up_and_are_taken_together() {
+        let mut items = FloorItems::default();
+        let position = Position::new(1, 2);
+        items.add(position, ItemStack { item_id: 0, count: 1 });
+        items.add(position, ItemStack { item_id: 2, count: 1 });
+
+        assert_eq!(items.at(position).len(), 2);
+        assert!(items.at(Position::new(0, 0)).is_empty());
+
+        let taken = items.take_all(position);
+        assert_eq!(taken.iter().map(ItemStack::id).collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(items.iter().count(), 0);
+    }
+}
@@ -1,7 +1,8 @@
 use super::{MAX_HOTBAR_SIZE, SlotId, item_stack::ItemStack};
 
 /// Represents a hotbar in the game.
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hotbar {
     items: Box<[Option<ItemStack>; MAX_HOTBAR_SIZE]>,
 }
@@ -23,6 +24,30 @@ impl Hotbar {
         if slot < MAX_HOTBAR_SIZE { self.items[slot].take() } else { None }
     }
 
+    /// Returns true if the hotbar has the given slot.
+    pub(crate) fn has_slot(slot: SlotId) -> bool {
+        slot < MAX_HOTBAR_SIZE
+    }
+
+    /// Returns the stack in the given slot.
+    pub(crate) fn get(&self, slot: SlotId) -> Option<&ItemStack> {
+        self.items.get(slot)?.as_ref()
+    }
+
+    /// Takes up to `count` items out of the stack in the given slot.
+    pub(crate) fn take_count(
+        &mut self,
+        slot: SlotId,
+        count: u32,
+    ) -> Option<ItemStack> {
+        let stack = self.items.get_mut(slot)?;
+        let taken = stack.as_mut()?.split(count);
+        if stack.as_ref().is_some_and(ItemStack::is_empty) {
+            *stack = None;
+        }
+        Some(taken)
+    }
+
     pub(crate) fn contains(&self, slot: SlotId) -> bool {
         if slot < MAX_HOTBAR_SIZE { self.items[slot].is_some() } else { false }
     }
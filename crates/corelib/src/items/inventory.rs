@@ -3,7 +3,8 @@ use super::{ItemId, item_stack::ItemStack};
 const DEFAULT_INVENTORY_SIZE: usize = 36;
 
 /// Represents a player's inventory.
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Inventory {
     slots: Vec<Option<ItemStack>>,
 }
@@ -11,22 +12,60 @@ pub struct Inventory {
 impl Inventory {
     /// Creates an empty inventory.
     pub(crate) fn empty() -> Self {
-        // TODO: Fixme
-        let mut slots = Vec::with_capacity(DEFAULT_INVENTORY_SIZE);
-        slots.push(Some(ItemStack { item_id: 0, count: 1 }));
-        slots.push(Some(ItemStack { item_id: 1, count: 1 }));
-        slots.push(Some(ItemStack { item_id: 2, count: 1 }));
-
-        Self { slots }
+        Self { slots: Vec::with_capacity(DEFAULT_INVENTORY_SIZE) }
     }
 
-    pub(crate) fn add(&mut self, item: ItemStack) {
-        for slot in &mut self.slots {
-            if slot.is_none() {
-                *slot = Some(item);
-                return;
+    /// Puts the stack into the inventory.
+    ///
+    /// Items are merged into existing stacks of the same item first, then
+    /// fill free slots, no slot holding more than `max_stack` items. Gives
+    /// back whatever did not fit.
+    pub(crate) fn add(
+        &mut self,
+        mut item: ItemStack,
+        max_stack: u32,
+    ) -> Result<(), ItemStack> {
+        for stack in self.slots.iter_mut().flatten() {
+            if stack.item_id == item.item_id && stack.count < max_stack {
+                stack.count += item.split(max_stack - stack.count).count;
+            }
+        }
+
+        while !item.is_empty() {
+            let part = item.split(max_stack);
+            if let Some(slot) = self.slots.iter_mut().find(|s| s.is_none()) {
+                *slot = Some(part);
+            } else if self.slots.len() < DEFAULT_INVENTORY_SIZE {
+                self.slots.push(Some(part));
+            } else {
+                item.count += part.count;
+                return Err(item);
             }
         }
+        Ok(())
+    }
+
+    /// Returns true if the inventory holds the item.
+    pub(crate) fn contains(&self, item_id: ItemId) -> bool {
+        self.slots.iter().flatten().any(|stack| stack.item_id == item_id)
+    }
+
+    /// Returns true if the whole stack fits into the inventory.
+    pub(crate) fn fits(&self, item: &ItemStack, max_stack: u32) -> bool {
+        let free_slots = DEFAULT_INVENTORY_SIZE.saturating_sub(
+            self.slots.iter().filter(|slot| slot.is_some()).count(),
+        );
+        let room_in_stacks: u64 = self
+            .slots
+            .iter()
+            .flatten()
+            .filter(|stack| stack.item_id == item.item_id)
+            .map(|stack| u64::from(max_stack.saturating_sub(stack.count)))
+            .sum();
+        let room = room_in_stacks
+            + u64::try_from(free_slots).unwrap_or(u64::MAX)
+                * u64::from(max_stack);
+        u64::from(item.count) <= room
     }
 
     pub(crate) fn take(&mut self, item_id: ItemId) -> Option<ItemStack> {
@@ -40,6 +79,23 @@ impl Inventory {
         None
     }
 
+    /// Takes up to `count` items out of the first stack with the given id.
+    pub(crate) fn take_count(
+        &mut self,
+        item_id: ItemId,
+        count: u32,
+    ) -> Option<ItemStack> {
+        let slot = self.slots.iter_mut().find(|slot| {
+            slot.as_ref().is_some_and(|stack| stack.item_id == item_id)
+        })?;
+        let stack = slot.as_mut()?;
+        let taken = stack.split(count);
+        if stack.is_empty() {
+            *slot = None;
+        }
+        Some(taken)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Option<ItemStack>> {
         self.slots.iter()
     }
@@ -53,3 +109,81 @@ impl IntoIterator for Inventory {
         self.slots.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_empty() {
+        assert!(Inventory::empty().iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn add_fills_free_slots_until_full() {
+        let mut inventory = Inventory::empty();
+        for item_id in 0..DEFAULT_INVENTORY_SIZE {
+            assert!(inventory.add(ItemStack { item_id, count: 1 }, 1).is_ok());
+        }
+
+        let stack = ItemStack { item_id: 99, count: 1 };
+        assert_eq!(inventory.add(stack.clone(), 1), Err(stack));
+
+        assert!(inventory.take(3).is_some());
+        assert!(inventory.add(ItemStack { item_id: 99, count: 1 }, 1).is_ok());
+        assert_eq!(
+            inventory.iter().nth(3),
+            Some(&Some(ItemStack { item_id: 99, count: 1 }))
+        );
+    }
+
+    #[test]
+    fn add_merges_stacks_up_to_max() {
+        let mut inventory = Inventory::empty();
+        assert!(inventory.add(ItemStack { item_id: 3, count: 4 }, 10).is_ok());
+        assert!(inventory.add(ItemStack { item_id: 0, count: 1 }, 1).is_ok());
+        assert!(inventory.add(ItemStack { item_id: 3, count: 9 }, 10).is_ok());
+
+        let stacks: Vec<_> = inventory.iter().flatten().cloned().collect();
+        assert_eq!(
+            stacks,
+            [
+                ItemStack { item_id: 3, count: 10 },
+                ItemStack { item_id: 0, count: 1 },
+                ItemStack { item_id: 3, count: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn add_gives_back_what_does_not_fit() {
+        let mut inventory = Inventory::empty();
+        for item_id in 1..DEFAULT_INVENTORY_SIZE {
+            assert!(inventory.add(ItemStack { item_id, count: 1 }, 1).is_ok());
+        }
+
+        let leftover = inventory.add(ItemStack { item_id: 0, count: 25 }, 10);
+        assert_eq!(leftover, Err(ItemStack { item_id: 0, count: 15 }));
+        assert_eq!(
+            inventory.iter().flatten().last().map(ItemStack::count),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn take_count_splits_stack() {
+        let mut inventory = Inventory::empty();
+        assert!(inventory.add(ItemStack { item_id: 3, count: 5 }, 10).is_ok());
+
+        assert_eq!(
+            inventory.take_count(3, 2),
+            Some(ItemStack { item_id: 3, count: 2 })
+        );
+        assert_eq!(
+            inventory.take_count(3, 9),
+            Some(ItemStack { item_id: 3, count: 3 })
+        );
+        assert!(inventory.iter().all(Option::is_none));
+        assert_eq!(inventory.take_count(3, 1), None);
+    }
+}
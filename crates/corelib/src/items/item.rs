@@ -1,14 +1,16 @@
-use super::item_kind::ItemKind;
+use crate::effects::{EffectId, OnHit};
+
+use super::{EquipmentSlot, item_kind::ItemKind};
 
 /// Item definition
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
 pub struct ItemDef {
     pub(crate) name: String,
     pub(crate) title: String,
     pub(crate) description: String,
     pub(crate) kind: ItemKind,
-    #[allow(dead_code)]
     pub(crate) stackable: bool,
+    pub(crate) max_stack: u32,
 }
 
 impl ItemDef {
@@ -29,4 +31,50 @@ impl ItemDef {
     pub fn description(&self) -> &str {
         &self.description
     }
+
+    /// Returns the effect and its duration if the item is a consumable
+    pub(crate) fn consumable_effect(&self) -> Option<(EffectId, u8)> {
+        match self.kind {
+            ItemKind::Consumable { effect_id, duration } => {
+                Some((effect_id, duration))
+            },
+            ItemKind::Weapon { .. } | ItemKind::Armor { .. } => None,
+        }
+    }
+
+    /// Returns the effects a hit with the item inflicts
+    pub(crate) fn on_hit(&self) -> &[OnHit] {
+        match &self.kind {
+            ItemKind::Weapon { on_hit, .. } => on_hit,
+            ItemKind::Armor { .. } | ItemKind::Consumable { .. } => &[],
+        }
+    }
+
+    /// Returns the slot the item is worn in, if it can be worn at all
+    #[must_use]
+    pub fn equipment_slot(&self) -> Option<EquipmentSlot> {
+        match self.kind {
+            ItemKind::Weapon { .. } => Some(EquipmentSlot::MainHand),
+            ItemKind::Armor { slot, .. } => Some(slot),
+            ItemKind::Consumable { .. } => None,
+        }
+    }
+
+    /// Returns true if the item needs both hands
+    #[must_use]
+    pub fn two_handed(&self) -> bool {
+        matches!(self.kind, ItemKind::Weapon { two_handed: true, .. })
+    }
+
+    /// Returns true if several items of this kind share one slot
+    #[must_use]
+    pub fn stackable(&self) -> bool {
+        self.stackable
+    }
+
+    /// Get the number of items that fit into one slot
+    #[must_use]
+    pub fn max_stack(&self) -> u32 {
+        if self.stackable { self.max_stack.max(1) } else { 1 }
+    }
 }
@@ -1,8 +1,20 @@
+use crate::effects::{EffectId, OnHit};
+
+use super::EquipmentSlot;
+
 /// Represents the kind of an item.
-#[derive(Debug)]
+#[derive(Debug, Clone, Hash)]
 pub(crate) enum ItemKind {
-    /// Represents a weapon item.
-    Weapon { min_damage: u32, max_damage: u32 },
-    /// Represents a shield item.
-    Armor { defense: u32 },
+    /// Represents a weapon item, held in the main hand. Two-handed weapons
+    /// take the off hand as well. Hits may inflict effects on the target.
+    Weapon {
+        min_damage: u32,
+        max_damage: u32,
+        two_handed: bool,
+        on_hit: Vec<OnHit>,
+    },
+    /// Represents an armor item worn in the given slot.
+    Armor { defense: u32, slot: EquipmentSlot },
+    /// Represents an item that applies an effect when used up.
+    Consumable { effect_id: EffectId, duration: u8 },
 }
@@ -1,15 +1,59 @@
 use super::ItemId;
 
 /// Represents a stack of items in the inventory.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemStack {
     pub(crate) item_id: ItemId,
-    #[allow(dead_code)]
     pub(crate) count: u32,
 }
 
 impl ItemStack {
+    /// Creates a stack of `count` items.
+    #[must_use]
+    pub fn new(item_id: ItemId, count: u32) -> Self {
+        ItemStack { item_id, count }
+    }
+
+    /// Returns the ID of the stacked item.
+    #[must_use]
     pub fn id(&self) -> ItemId {
         self.item_id
     }
+
+    /// Returns the number of items in the stack.
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Splits up to `count` items off the stack.
+    ///
+    /// The stack keeps whatever is left, which may be nothing.
+    pub(crate) fn split(&mut self, count: u32) -> ItemStack {
+        let count = count.min(self.count);
+        self.count -= count;
+        ItemStack { item_id: self.item_id, count }
+    }
+
+    /// Returns true if no items are left in the stack.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_takes_at_most_whole_stack() {
+        let mut stack = ItemStack { item_id: 3, count: 5 };
+
+        assert_eq!(stack.split(2), ItemStack { item_id: 3, count: 2 });
+        assert_eq!(stack.count(), 3);
+
+        assert_eq!(stack.split(7), ItemStack { item_id: 3, count: 3 });
+        assert!(stack.is_empty());
+    }
 }
@@ -3,13 +3,19 @@ pub(crate) type SlotId = usize; // TODO: Maybe use u8 for optimization?
 
 const MAX_HOTBAR_SIZE: usize = 10;
 
+mod equipment;
+mod floor_items;
 mod hotbar;
 mod inventory;
 mod item;
 mod item_kind;
 mod item_stack;
 
+pub(crate) use equipment::Equipment;
+pub use equipment::EquipmentSlot;
+pub use floor_items::FloorItems;
 pub(crate) use hotbar::Hotbar;
 pub(crate) use inventory::Inventory;
 pub(crate) use item::ItemDef;
 pub(crate) use item_kind::ItemKind;
+pub use item_stack::ItemStack;
@@ -0,0 +1,127 @@
+use crate::{
+    Array2D, EntityId, Position, WorldError, WorldSettings,
+    actors::{Actor, ActorKind},
+    catalog::Content,
+    dungeon::{DungeonMap, Tile},
+    items::FloorItems,
+    rng::MyRng,
+};
+
+/// A dungeon level together with everything that lives on it.
+///
+/// Only the level the player is on is simulated. The others are kept as they
+/// were left.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Level {
+    /// The map of the level.
+    pub(crate) dungeon: DungeonMap,
+    /// Entities living on the level.
+    pub(crate) entities: Vec<Actor>,
+    /// Tiles of the level the player has seen at least once.
+    pub(crate) explored: Array2D<bool>,
+    /// Items lying on the floor of the level.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) items: FloorItems,
+}
+
+impl Level {
+    /// Position where the player enters the level.
+    pub(crate) const SPAWN: Position = Position { x: 0, y: 0 };
+
+    /// Returns the seed of the level at the given depth.
+    ///
+    /// The first level uses the world seed itself, deeper levels derive their
+    /// own from it.
+    pub(crate) fn seed(world_seed: [u8; 32], depth: u32) -> [u8; 32] {
+        if depth == 0 {
+            world_seed
+        } else {
+            MyRng::derive_seed(world_seed, u64::from(depth))
+        }
+    }
+
+    /// Generates the level at the given depth.
+    ///
+    /// Deeper levels have up stairs at the spawn, and every level but the
+    /// deepest has down stairs somewhere else. Entities are numbered from `first_entity_id`.
+    /// The level holds as many piles of loot as enemies, each rolled from the
+    /// loot table of a random enemy kind. Enemies start with the stats the
+    /// content gives their kind.
+    pub(crate) fn generate(
+        settings: &WorldSettings,
+        depth: u32,
+        rng: &mut MyRng,
+        first_entity_id: u32,
+        content: &Content,
+    ) -> Result<Self, WorldError> {
+        let mut dungeon = settings
+            .generator
+            .generator(settings.floor_tiles)
+            .generate(settings.map_width, settings.map_height, rng);
+        if !dungeon.is_walkable(Self::SPAWN) {
+            return Err(WorldError::BlockedSpawn);
+        }
+        dungeon.connect_pockets(Self::SPAWN);
+        dungeon.build_walls();
+
+        let mut free_tiles: Vec<_> = dungeon
+            .iter()
+            .filter(|(position, tile)| {
+                **tile == Tile::Floor && *position != Self::SPAWN
+            })
+            .map(|(position, _)| position)
+            .collect();
+        if depth + 1 < settings.levels {
+            if free_tiles.is_empty() {
+                return Err(WorldError::NoRoomForStairs);
+            }
+            let stairs = free_tiles.swap_remove(rng.range(0..free_tiles.len()));
+            dungeon.set_tile(stairs, Tile::StairsDown);
+        }
+        if depth > 0 {
+            dungeon.set_tile(Self::SPAWN, Tile::StairsUp);
+        }
+
+        if settings.enemies > free_tiles.len() {
+            return Err(WorldError::TooManyEnemies {
+                enemies: settings.enemies,
+                free_tiles: free_tiles.len(),
+            });
+        }
+
+        let mut next_entity_id = first_entity_id;
+        let mut entities = Vec::with_capacity(settings.enemies);
+        while entities.len() < entities.capacity() {
+            let position =
+                free_tiles.swap_remove(rng.range(0..free_tiles.len()));
+            let kind =
+                ActorKind::ENEMIES[rng.range(0..ActorKind::ENEMIES.len())];
+            entities.push(Actor::create_with_stats(
+                EntityId::from(next_entity_id),
+                position,
+                kind,
+                content.actors.stats(kind),
+            ));
+            next_entity_id += 1;
+        }
+
+        let mut items = FloorItems::default();
+        for _ in 0..settings.enemies.min(free_tiles.len()) {
+            let position =
+                free_tiles.swap_remove(rng.range(0..free_tiles.len()));
+            let kind =
+                ActorKind::ENEMIES[rng.range(0..ActorKind::ENEMIES.len())];
+            if let Some(stack) = content.loot.roll(kind, rng) {
+                items.add(position, stack);
+            }
+        }
+
+        Ok(Level {
+            explored: Array2D::empty(dungeon.width(), dungeon.height()),
+            dungeon,
+            entities,
+            items,
+        })
+    }
+}
@@ -9,6 +9,12 @@
 
 //! This module contains the core game logic and data structures.
 
+#[cfg(not(feature = "content"))]
+compile_error!(
+    "the `content` feature is required, as the built-in item, effect and \
+     actor definitions ship as a JSON content pack"
+);
+
 mod actions;
 mod actors;
 mod ai;
@@ -19,25 +25,46 @@ mod direction;
 mod dungeon;
 mod effects;
 mod events;
+mod fog;
+mod fov;
 mod game_state;
+mod game_status;
 mod items;
+mod level;
 mod mechanics;
+mod pathfinding;
 mod position;
+mod replay;
 mod rng;
+#[cfg(feature = "serde")]
+mod save;
 mod step_result;
 mod walk_map;
+mod world_error;
 
-pub use actions::PlayerAction;
+pub use actions::{ActionError, PlayerAction};
 pub use actors::{Actor, ActorKind, EntityId, Stats};
 pub use array2d::Array2D;
-pub use diff::{EntityDiff, StateDiff};
+pub use catalog::{Content, ContentError, ContentErrorKind, EffectInstance};
+pub use diff::{EntityDiff, SlotDiff, StateDiff, TileDiff};
 pub use direction::Direction;
-pub use dungeon::{DungeonMap, Tile};
+pub use dungeon::{DungeonMap, GeneratorKind, Tile, TileProperties};
 pub use events::GameEvent;
+pub use fog::{FogOfWar, TileVisibility};
 pub use game_state::GameState;
+pub use game_status::GameStatus;
+pub use items::{EquipmentSlot, FloorItems, ItemStack};
+pub use mechanics::AttackOutcome;
 pub use position::Position;
+pub use replay::{ActionLog, Divergence, DivergenceKind, RecordedStep};
+#[cfg(feature = "serde")]
+pub use save::{SAVE_FORMAT_VERSION, SaveError};
+pub use step_result::StepResult;
+pub use world_error::WorldError;
 
 /// Settings for the world generation.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorldSettings {
     /// Seed for the random number generator.
     pub seed: [u8; 32],
@@ -46,44 +73,208 @@ pub struct WorldSettings {
     /// Height of the map.
     pub map_height: usize,
     /// Number of floor tiles.
+    ///
+    /// Only used by [`GeneratorKind::RandomWalk`].
     pub floor_tiles: usize,
     /// Number of enemies.
     pub enemies: usize,
+    /// Algorithm used to generate the map.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub generator: GeneratorKind,
+    /// Number of levels in the dungeon.
+    ///
+    /// The deepest level has no stairs down, and clearing it of enemies wins
+    /// the game.
+    #[cfg_attr(feature = "serde", serde(default = "default_levels"))]
+    pub levels: u32,
+}
+
+/// Number of levels of a dungeon when the settings do not say.
+const DEFAULT_LEVELS: u32 = 5;
+
+#[cfg(feature = "serde")]
+fn default_levels() -> u32 {
+    DEFAULT_LEVELS
+}
+
+impl Default for WorldSettings {
+    fn default() -> Self {
+        let floor_tiles = 101 * 101 / 3;
+        WorldSettings {
+            seed: [0; 32],
+            map_width: 101,
+            map_height: 101,
+            floor_tiles,
+            enemies: floor_tiles / 500,
+            generator: GeneratorKind::default(),
+            levels: DEFAULT_LEVELS,
+        }
+    }
 }
 
+impl WorldSettings {
+    /// Checks the settings that can be verified before generating the map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dungeon has no levels, or if the map is empty
+    /// or cannot hold the requested floor tiles.
+    pub fn validate(&self) -> Result<(), WorldError> {
+        if self.levels == 0 {
+            return Err(WorldError::NoLevels);
+        }
+        let area = self.map_width * self.map_height;
+        if area == 0 {
+            return Err(WorldError::EmptyMap);
+        }
+        if self.generator == GeneratorKind::RandomWalk
+            && self.floor_tiles > area
+        {
+            return Err(WorldError::TooManyFloorTiles {
+                floor_tiles: self.floor_tiles,
+                area,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Items lying at the spawn when a new game starts.
+const STARTING_ITEMS: [items::ItemId; 3] = [0, 1, 2];
+
 /// Creates a new game instance.
-#[must_use]
-pub fn new_game(settings: &WorldSettings) -> GameState {
+///
+/// Entity IDs are assigned sequentially, so the same settings always produce
+/// the same game. Pockets of floor that cannot be reached from the spawn are
+/// joined to it with tunnels. The starting gear lies at the spawn, ready to be
+/// picked up, and every level starts with some loot scattered around. Deeper
+/// levels are generated when the player first reaches them, each from its own
+/// seed derived from the world seed.
+///
+/// # Errors
+///
+/// Returns an error if the settings are invalid or the generated map cannot
+/// hold the player and all enemies.
+pub fn new_game(settings: &WorldSettings) -> Result<GameState, WorldError> {
+    new_game_with_content(settings, Content::default())
+}
+
+/// Creates a new game instance played with the given item, effect and actor
+/// definitions.
+///
+/// See [`new_game`] for how the world is set up.
+///
+/// # Errors
+///
+/// Returns an error if the settings are invalid or the generated map cannot
+/// hold the player and all enemies.
+pub fn new_game_with_content(
+    settings: &WorldSettings,
+    content: Content,
+) -> Result<GameState, WorldError> {
+    settings.validate()?;
+
     let mut rng = rng::MyRng::from_seed(settings.seed);
+    let level::Level { dungeon, entities, items, .. } =
+        level::Level::generate(settings, 0, &mut rng, 1, &content)?;
 
-    let map = dungeon::DungeonMap::generate(
-        settings.map_width,
-        settings.map_height,
-        &mut rng,
-        settings.floor_tiles,
+    let player = actors::Actor::create_with_stats(
+        EntityId::from(0),
+        level::Level::SPAWN,
+        ActorKind::Player,
+        content.actors.stats(ActorKind::Player),
     );
 
-    let mut entities = Vec::with_capacity(settings.enemies);
-    while entities.len() < entities.capacity() {
-        let half_width =
-            i32::try_from(settings.map_width).unwrap_or(i32::MAX) / 2;
-        let half_height =
-            i32::try_from(settings.map_height).unwrap_or(i32::MAX) / 2;
-        let x = rng.range(-half_width..=half_width);
-        let y = rng.range(-half_height..=half_height);
-
-        if map.is_walkable(Position { x, y }) {
-            entities.push(actors::Actor::create(
-                position::Position { x, y },
-                ActorKind::Enemy,
-            ));
+    let mut state = GameState::new(player, entities, dungeon, rng);
+    state.settings = settings.clone();
+    state.content = content;
+    state.items = items;
+    for item_id in STARTING_ITEMS {
+        state
+            .items
+            .add(level::Level::SPAWN, items::ItemStack { item_id, count: 1 });
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn settings() -> WorldSettings {
+        WorldSettings {
+            seed: [3; 32],
+            map_width: 11,
+            map_height: 11,
+            floor_tiles: 30,
+            enemies: 2,
+            generator: GeneratorKind::RandomWalk,
+            levels: 3,
         }
     }
 
-    GameState::new(
-        actors::Actor::create_player(position::Position { x: 0, y: 0 }),
-        entities,
-        map,
-        rng,
-    )
+    #[test]
+    fn rejects_dungeon_without_levels() {
+        let settings = WorldSettings { levels: 0, ..settings() };
+        assert_eq!(new_game(&settings).err(), Some(WorldError::NoLevels));
+    }
+
+    #[test]
+    fn rejects_empty_map() {
+        let settings = WorldSettings { map_height: 0, ..settings() };
+        assert_eq!(new_game(&settings).err(), Some(WorldError::EmptyMap));
+    }
+
+    #[test]
+    fn rejects_more_floor_than_map_area() {
+        let settings = WorldSettings { floor_tiles: 122, ..settings() };
+        assert_eq!(
+            new_game(&settings).err(),
+            Some(WorldError::TooManyFloorTiles { floor_tiles: 122, area: 121 })
+        );
+    }
+
+    #[test]
+    fn rejects_map_without_spawn() {
+        let settings = WorldSettings { floor_tiles: 0, ..settings() };
+        assert_eq!(new_game(&settings).err(), Some(WorldError::BlockedSpawn));
+    }
+
+    #[test]
+    fn rejects_more_enemies_than_free_floor() {
+        let settings = WorldSettings { enemies: 30, ..settings() };
+        assert_eq!(
+            new_game(&settings).err(),
+            Some(WorldError::TooManyEnemies { enemies: 30, free_tiles: 28 })
+        );
+    }
+
+    #[test]
+    fn starting_gear_lies_at_spawn() -> Result<(), WorldError> {
+        let gs = new_game(&settings())?;
+
+        assert!(gs.inventory().iter().all(Option::is_none));
+        let items: Vec<_> = gs
+            .floor_items()
+            .at(gs.player().position)
+            .iter()
+            .map(items::ItemStack::id)
+            .collect();
+        assert_eq!(items, STARTING_ITEMS);
+        Ok(())
+    }
+
+    #[test]
+    fn enemies_fill_distinct_free_tiles() -> Result<(), WorldError> {
+        let gs = new_game(&WorldSettings { enemies: 28, ..settings() })?;
+
+        let positions: HashSet<_> =
+            gs.entities().iter().map(|entity| entity.position).collect();
+        assert_eq!(positions.len(), 28);
+        assert!(!positions.contains(&gs.player().position));
+        assert!(positions.iter().all(|p| gs.dungeon().is_walkable(*p)));
+        Ok(())
+    }
 }
@@ -1,36 +1,113 @@
-use crate::{Actor, rng::MyRng};
+use crate::{Actor, rng::MyRng, step_result::StepContext};
 
+/// How an attack turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttackOutcome {
+    /// The target evaded the attack.
+    Miss,
+    /// The attack hit.
+    Hit {
+        /// Damage dealt to the target.
+        damage: u32,
+    },
+    /// The attack hit critically.
+    Critical {
+        /// Damage dealt to the target.
+        damage: u32,
+    },
+}
+
+impl AttackOutcome {
+    /// Returns the damage dealt to the target.
+    #[must_use]
+    pub fn damage(self) -> u32 {
+        match self {
+            AttackOutcome::Miss => 0,
+            AttackOutcome::Hit { damage }
+            | AttackOutcome::Critical { damage } => damage,
+        }
+    }
+
+    /// Returns true if the attack hit the target.
+    #[must_use]
+    pub fn is_hit(self) -> bool {
+        self != AttackOutcome::Miss
+    }
+}
+
+/// Attacks the target.
+///
+/// The attack hits with the accuracy of the attacker minus the evasion of
+/// the target, in percent. Hits may be critical, multiplying the rolled
+/// damage before the defense of the target is taken off. A hit always deals
+/// at least one damage. Chances of 0 or 100 percent are not rolled.
 pub(crate) fn try_attack(
+    step_context: &mut StepContext,
     attacker: &mut Actor,
     target: &mut Actor,
     rng: &mut MyRng,
-) -> u32 {
-    let physical_damage = rng
+) -> AttackOutcome {
+    let hit_chance =
+        attacker.stats.accuracy.saturating_sub(target.stats.evasion).min(100);
+    if !roll_chance(rng, hit_chance) {
+        return AttackOutcome::Miss;
+    }
+
+    let mut physical_damage = rng
         .range(attacker.stats().min_damage()..=attacker.stats().max_damage());
+    let critical = roll_chance(rng, attacker.stats.crit_chance.min(100));
+    if critical {
+        physical_damage = physical_damage
+            .saturating_mul(attacker.stats.crit_multiplier)
+            / 100;
+    }
     let physical_defense = target.stats().defense();
     let total_damage =
         (physical_damage.saturating_sub(physical_defense)).max(1);
 
+    let old_stats = target.stats.clone();
     target.stats.hp = target.stats.hp.saturating_sub(total_damage);
-    // TODO: Implement critical hit logic
-    total_damage
+    step_context.diff().stat(target.id, old_stats, target.stats.clone());
+
+    if critical {
+        AttackOutcome::Critical { damage: total_damage }
+    } else {
+        AttackOutcome::Hit { damage: total_damage }
+    }
+}
+
+/// Rolls a chance in percent.
+fn roll_chance(rng: &mut MyRng, chance: u32) -> bool {
+    match chance {
+        0 => false,
+        100.. => true,
+        _ => rng.range(0..100) < chance,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{actors::{ActorKind}, position::Position};
+    use crate::{actors::ActorKind, position::Position};
 
     #[test]
     fn damage_respects_defense() {
         let mut attacker = Actor::create(Position::new(0, 0), ActorKind::Enemy);
         attacker.stats.min_damage = 5;
         attacker.stats.max_damage = 5;
+        attacker.stats.accuracy = 100;
+        attacker.stats.crit_chance = 0;
         let mut target = Actor::create(Position::new(1, 0), ActorKind::Enemy);
         target.stats.defense = 1;
+        target.stats.evasion = 0;
         let mut rng = MyRng::from_seed([1; 32]);
-        let dmg = try_attack(&mut attacker, &mut target, &mut rng);
-        assert_eq!(dmg, 4);
+        let dmg = try_attack(
+            &mut StepContext::default(),
+            &mut attacker,
+            &mut target,
+            &mut rng,
+        );
+        assert_eq!(dmg, AttackOutcome::Hit { damage: 4 });
         assert_eq!(target.stats.hp, 16);
     }
 
@@ -39,11 +116,19 @@ mod tests {
         let mut attacker = Actor::create(Position::new(0, 0), ActorKind::Enemy);
         attacker.stats.min_damage = 3;
         attacker.stats.max_damage = 3;
+        attacker.stats.accuracy = 100;
+        attacker.stats.crit_chance = 0;
         let mut target = Actor::create(Position::new(1, 0), ActorKind::Enemy);
         target.stats.defense = 10;
+        target.stats.evasion = 0;
         let mut rng = MyRng::from_seed([2; 32]);
-        let dmg = try_attack(&mut attacker, &mut target, &mut rng);
-        assert_eq!(dmg, 1);
+        let dmg = try_attack(
+            &mut StepContext::default(),
+            &mut attacker,
+            &mut target,
+            &mut rng,
+        );
+        assert_eq!(dmg, AttackOutcome::Hit { damage: 1 });
         assert_eq!(target.stats.hp, 19);
     }
 
@@ -55,8 +140,71 @@ mod tests {
         let mut t2 = Actor::create(Position::new(1, 0), ActorKind::Enemy);
         let mut rng1 = MyRng::from_seed([3; 32]);
         let mut rng2 = MyRng::from_seed([3; 32]);
-        let d1 = try_attack(&mut a1, &mut t1, &mut rng1);
-        let d2 = try_attack(&mut a2, &mut t2, &mut rng2);
+        let d1 = try_attack(
+            &mut StepContext::default(),
+            &mut a1,
+            &mut t1,
+            &mut rng1,
+        );
+        let d2 = try_attack(
+            &mut StepContext::default(),
+            &mut a2,
+            &mut t2,
+            &mut rng2,
+        );
         assert_eq!(d1, d2);
     }
+
+    #[test]
+    fn evasion_lowers_hit_chance() {
+        let mut attacker = Actor::create(Position::new(0, 0), ActorKind::Enemy);
+        attacker.stats.accuracy = 100;
+        let mut target = Actor::create(Position::new(1, 0), ActorKind::Enemy);
+        target.stats.evasion = 100;
+        target.stats.hp = u32::MAX;
+        let mut rng = MyRng::from_seed([4; 32]);
+        let outcome = try_attack(
+            &mut StepContext::default(),
+            &mut attacker,
+            &mut target,
+            &mut rng,
+        );
+        assert_eq!(outcome, AttackOutcome::Miss);
+        assert_eq!(target.stats.hp, u32::MAX);
+
+        target.stats.evasion = 50;
+        let hits = (0..200)
+            .filter(|_| {
+                try_attack(
+                    &mut StepContext::default(),
+                    &mut attacker,
+                    &mut target,
+                    &mut rng,
+                )
+                .is_hit()
+            })
+            .count();
+        assert!((60..140).contains(&hits));
+    }
+
+    #[test]
+    fn critical_hits_multiply_damage() {
+        let mut attacker = Actor::create(Position::new(0, 0), ActorKind::Enemy);
+        attacker.stats.min_damage = 4;
+        attacker.stats.max_damage = 4;
+        attacker.stats.accuracy = 100;
+        attacker.stats.crit_chance = 100;
+        attacker.stats.crit_multiplier = 250;
+        let mut target = Actor::create(Position::new(1, 0), ActorKind::Enemy);
+        target.stats.defense = 2;
+        target.stats.evasion = 0;
+        let outcome = try_attack(
+            &mut StepContext::default(),
+            &mut attacker,
+            &mut target,
+            &mut MyRng::from_seed([5; 32]),
+        );
+        assert_eq!(outcome, AttackOutcome::Critical { damage: 8 });
+        assert_eq!(outcome.damage(), 8);
+    }
 }
@@ -0,0 +1,124 @@
+use crate::{
+    Position,
+    dungeon::{DungeonMap, Tile},
+    step_result::StepContext,
+    walk_map::WalkMap,
+};
+
+/// Try to open the closed door at a given position.
+///
+/// Returns false if there is no closed door.
+pub(crate) fn try_open_door(
+    step_context: &mut StepContext,
+    dungeon: &mut DungeonMap,
+    walk_map: &mut WalkMap,
+    position: Position,
+) -> bool {
+    if dungeon.get_tile(position) != &Tile::DoorClosed {
+        return false;
+    }
+
+    dungeon.set_tile(position, Tile::DoorOpen);
+    walk_map.open_door(position);
+    step_context.diff().tile(position, Tile::DoorClosed, Tile::DoorOpen);
+    true
+}
+
+/// Try to close the open door at a given position.
+///
+/// Returns false if there is no open door, or if something stands in the
+/// doorway.
+pub(crate) fn try_close_door(
+    step_context: &mut StepContext,
+    dungeon: &mut DungeonMap,
+    walk_map: &mut WalkMap,
+    position: Position,
+) -> bool {
+    if dungeon.get_tile(position) != &Tile::DoorOpen
+        || !walk_map.is_walkable(position)
+    {
+        return false;
+    }
+
+    dungeon.set_tile(position, Tile::DoorClosed);
+    walk_map.close_door(position);
+    step_context.diff().tile(position, Tile::DoorOpen, Tile::DoorClosed);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (DungeonMap, WalkMap) {
+        let mut dungeon = DungeonMap::simple(5, 5);
+        dungeon.set_tile(Position::new(1, 0), Tile::DoorClosed);
+        let mut walk_map = dungeon
+            .iter()
+            .filter_map(|(pos, tile)| tile.is_walkable().then_some(pos))
+            .collect::<WalkMap>();
+        walk_map.close_door(Position::new(1, 0));
+        (dungeon, walk_map)
+    }
+
+    #[test]
+    fn opens_and_closes_door() {
+        let (mut dungeon, mut walk_map) = setup();
+        let mut step_context = StepContext::default();
+        let door = Position::new(1, 0);
+
+        assert!(try_open_door(
+            &mut step_context,
+            &mut dungeon,
+            &mut walk_map,
+            door
+        ));
+        assert_eq!(dungeon.get_tile(door), &Tile::DoorOpen);
+        assert!(walk_map.is_walkable(door));
+
+        assert!(try_close_door(
+            &mut step_context,
+            &mut dungeon,
+            &mut walk_map,
+            door
+        ));
+        assert_eq!(dungeon.get_tile(door), &Tile::DoorClosed);
+        assert!(!walk_map.is_walkable(door));
+
+        // The door ended up as it started
+        assert!(step_context.build().diff.tiles.is_empty());
+    }
+
+    #[test]
+    fn occupied_door_stays_open() {
+        let (mut dungeon, mut walk_map) = setup();
+        let mut step_context = StepContext::default();
+        let door = Position::new(1, 0);
+        try_open_door(&mut step_context, &mut dungeon, &mut walk_map, door);
+        walk_map.occupy(door);
+
+        assert!(!try_close_door(
+            &mut step_context,
+            &mut dungeon,
+            &mut walk_map,
+            door
+        ));
+        assert_eq!(dungeon.get_tile(door), &Tile::DoorOpen);
+
+        let diff = step_context.build().diff;
+        assert_eq!(diff.tiles.len(), 1);
+        assert_eq!(diff.tiles[0].position, door);
+        assert_eq!(diff.tiles[0].new, Tile::DoorOpen);
+    }
+
+    #[test]
+    fn floor_cannot_be_opened() {
+        let (mut dungeon, mut walk_map) = setup();
+        assert!(!try_open_door(
+            &mut StepContext::default(),
+            &mut dungeon,
+            &mut walk_map,
+            Position::new(2, 0)
+        ));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    Actor, Stats,
+    catalog::{Content, EffectInstance, EffectsCatalog},
+    effects::{EffectId, EffectKind, OnHit, Stacking},
+    events::GameEvent,
+    rng::MyRng,
+    step_result::StepContext,
+};
+
+/// Applies an effect to the entity for the given number of turns.
+///
+/// If the entity is already affected, the stacking rule of the effect decides
+/// whether the running instance is refreshed, a second one is added or the
+/// new application is ignored. Unknown effects are ignored as well.
+pub(crate) fn apply_effect(
+    step_context: &mut StepContext,
+    effects_catalog: &EffectsCatalog,
+    entity: &mut Actor,
+    effect_id: EffectId,
+    duration: u8,
+) {
+    let Some(effect_def) = effects_catalog.get(effect_id) else {
+        return;
+    };
+
+    let old_effects = entity.effects.clone();
+    let running = entity.effects.iter_mut().find(|effect| {
+        effect.effect_id == effect_id && effect.remaining_turns > 0
+    });
+    match (effect_def.stacking, running) {
+        (Stacking::Ignore, Some(_)) => return,
+        (Stacking::Refresh, Some(effect)) => {
+            effect.remaining_turns = duration;
+        },
+        _ => {
+            entity
+                .effects
+                .push(EffectInstance { effect_id, remaining_turns: duration });
+        },
+    }
+
+    step_context.diff().effects(entity.id, old_effects, entity.effects.clone());
+    step_context.add_event(GameEvent::EffectApplied {
+        entity_id: entity.id,
+        effect_id,
+        name: effect_def.name.clone(),
+        duration,
+    });
+}
+
+/// Ticks the effects of an entity.
+///
+/// Expired effects are removed, the others act once and count down. Killed
+/// entities are left for the caller to remove.
+pub(crate) fn tick_effects(
+    step_context: &mut StepContext,
+    effects_catalog: &EffectsCatalog,
+    entity: &mut Actor,
+) {
+    let old_stats = entity.stats.clone();
+    let old_effects = entity.effects.clone();
+
+    entity.effects.retain_mut(|effect| {
+        if effect.remaining_turns == 0 {
+            step_context.add_event(GameEvent::EffectExpired {
+                entity_id: entity.id,
+                effect_id: effect.effect_id,
+            });
+            return false;
+        }
+        effect.remaining_turns = effect.remaining_turns.saturating_sub(1);
+
+        let Some(effect_def) = effects_catalog.get(effect.effect_id) else {
+            // Unknown effect, remove it
+            step_context.add_event(GameEvent::EffectExpired {
+                entity_id: entity.id,
+                effect_id: effect.effect_id,
+            });
+            return false;
+        };
+        match effect_def.kind {
+            EffectKind::Heal { hp_per_turn } => {
+                entity.stats.hp = entity
+                    .stats
+                    .hp
+                    .saturating_add(hp_per_turn)
+                    .min(entity.stats.max_hp);
+            },
+            EffectKind::Damage { hp_per_turn } => {
+                entity.stats.hp = entity.stats.hp.saturating_sub(hp_per_turn);
+            },
+            EffectKind::Modify { .. }
+            | EffectKind::Stun
+            | EffectKind::Haste
+            | EffectKind::Slow => {},
+        }
+        step_context.add_event(GameEvent::EffectTick {
+            entity_id: entity.id,
+            effect_id: effect.effect_id,
+        });
+
+        true
+    });
+
+    step_context.diff().stat(entity.id, old_stats, entity.stats.clone());
+    step_context.diff().effects(entity.id, old_effects, entity.effects.clone());
+}
+
+/// Adds the stat modifiers of the effects to the given stats.
+///
+/// Health is left alone, only its maximum changes.
+pub(crate) fn modify_stats(
+    effects_catalog: &EffectsCatalog,
+    stats: &mut Stats,
+    effects: &[EffectInstance],
+) {
+    for effect in effects {
+        let Some(effect_def) = effects_catalog.get(effect.effect_id) else {
+            continue;
+        };
+        if let EffectKind::Modify { attack, defense, max_hp } = effect_def.kind
+        {
+            stats.min_damage = stats.min_damage.saturating_add_signed(attack);
+            stats.max_damage = stats.max_damage.saturating_add_signed(attack);
+            stats.defense = stats.defense.saturating_add_signed(defense);
+            stats.max_hp = stats.max_hp.saturating_add_signed(max_hp).max(1);
+        }
+    }
+}
+
+/// Recalculates the stats of an entity from the starting stats of its kind
+/// and the modifiers of its effects, and records the change.
+///
+/// Health above the resulting maximum is lost.
+pub(crate) fn refresh_entity_stats(
+    step_context: &mut StepContext,
+    content: &Content,
+    entity: &mut Actor,
+) {
+    let mut stats = content.actors.stats(entity.kind);
+    modify_stats(&content.effects, &mut stats, &entity.effects);
+    stats.hp = entity.stats.hp.min(stats.max_hp);
+    step_context.diff().stat(entity.id, entity.stats.clone(), stats.clone());
+    entity.stats = stats;
+}
+
+/// Inflicts the on-hit effects of an attack on its target.
+///
+/// Each effect is rolled against its chance. Dead targets are spared.
+/// Returns true if any effect was applied.
+pub(crate) fn inflict_on_hit<'a>(
+    step_context: &mut StepContext,
+    effects_catalog: &EffectsCatalog,
+    on_hit: impl IntoIterator<Item = &'a OnHit>,
+    target: &mut Actor,
+    rng: &mut MyRng,
+) -> bool {
+    let mut inflicted = false;
+    for hit in on_hit {
+        if !target.is_alive() {
+            break;
+        }
+        // Certain effects do not consume random numbers
+        if hit.chance < 100 && rng.range(0..100) >= hit.chance {
+            continue;
+        }
+        apply_effect(
+            step_context,
+            effects_catalog,
+            target,
+            hit.effect_id,
+            hit.duration,
+        );
+        inflicted = true;
+    }
+    inflicted
+}
+
+/// Returns how many times an entity acts on the given tick.
+///
+/// Stunned entities lose their turn, hasted ones act twice and slowed ones
+/// only every other tick. Haste and slow together cancel out.
+pub(crate) fn entity_turns(
+    effects_catalog: &EffectsCatalog,
+    entity: &Actor,
+    tick_id: u64,
+) -> usize {
+    let active = || effects_catalog.active(&entity.effects);
+    if active().any(|kind| matches!(kind, EffectKind::Stun)) {
+        return 0;
+    }
+    let hasted = active().any(|kind| matches!(kind, EffectKind::Haste));
+    let slowed = active().any(|kind| matches!(kind, EffectKind::Slow));
+    match (hasted, slowed) {
+        (true, false) => 2,
+        (false, true) => usize::from(tick_id % 2 == 1),
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Position, actors::ActorKind, effects::EffectDef};
+
+    fn catalog() -> EffectsCatalog {
+        let effect = |name: &str, stacking| EffectDef {
+            name: name.to_string(),
+            description: String::new(),
+            kind: EffectKind::Stun,
+            stacking,
+        };
+        EffectsCatalog {
+            effects: vec![
+                effect("refresh", Stacking::Refresh),
+                effect("stack", Stacking::Stack),
+                effect("ignore", Stacking::Ignore),
+            ],
+        }
+    }
+
+    fn remaining_turns(actor: &Actor) -> Vec<(EffectId, u8)> {
+        actor
+            .effects
+            .iter()
+            .map(|effect| (effect.effect_id, effect.remaining_turns))
+            .collect()
+    }
+
+    #[test]
+    fn stacking_rules_decide_repeated_applications() {
+        let catalog = catalog();
+        let mut actor = Actor::create(Position::new(0, 0), ActorKind::Enemy);
+        let mut step_context = StepContext::default();
+        for (effect_id, duration) in [(0, 3), (1, 3), (2, 3), (0, 5), (1, 5)] {
+            apply_effect(
+                &mut step_context,
+                &catalog,
+                &mut actor,
+                effect_id,
+                duration,
+            );
+        }
+        apply_effect(&mut step_context, &catalog, &mut actor, 2, 5);
+
+        assert_eq!(
+            remaining_turns(&actor),
+            vec![(0, 5), (1, 3), (2, 3), (1, 5)]
+        );
+        let result = step_context.build();
+        let applied: Vec<_> = result
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::EffectApplied { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            applied,
+            vec!["refresh", "stack", "ignore", "refresh", "stack"]
+        );
+    }
+
+    #[test]
+    fn unknown_effects_are_ignored() {
+        let mut actor = Actor::create(Position::new(0, 0), ActorKind::Enemy);
+        let mut step_context = StepContext::default();
+        apply_effect(&mut step_context, &catalog(), &mut actor, 9, 3);
+        assert!(actor.effects.is_empty());
+        assert!(step_context.build().events.is_empty());
+    }
+}
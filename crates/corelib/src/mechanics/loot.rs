@@ -0,0 +1,97 @@
+use crate::{
+    Actor, catalog::LootCatalog, events::GameEvent, items::FloorItems,
+    rng::MyRng, step_result::StepContext,
+};
+
+/// Rolls the loot of a dead entity and leaves it where the entity died.
+pub(crate) fn drop_loot(
+    step_context: &mut StepContext,
+    loot: &LootCatalog,
+    items: &mut FloorItems,
+    corpse: &Actor,
+    rng: &mut MyRng,
+) {
+    let Some(stack) = loot.roll(corpse.kind, rng) else {
+        return;
+    };
+
+    step_context.add_event(GameEvent::EntityDroppedLoot {
+        id: corpse.id,
+        position: corpse.position,
+        item_id: stack.item_id,
+        count: stack.count,
+    });
+    items.add(corpse.position, stack);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Position,
+        actors::ActorKind,
+        catalog::{LootEntry, LootTable},
+    };
+
+    fn catalog() -> LootCatalog {
+        LootCatalog {
+            tables: vec![(
+                ActorKind::Rat,
+                LootTable {
+                    nothing_weight: 0,
+                    entries: vec![LootEntry {
+                        item_id: 2,
+                        weight: 1,
+                        min_count: 1,
+                        max_count: 1,
+                    }],
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn corpse_leaves_loot() {
+        let corpse = Actor::create(Position::new(3, 4), ActorKind::Rat);
+        let mut items = FloorItems::default();
+        let mut step_context = StepContext::default();
+
+        drop_loot(
+            &mut step_context,
+            &catalog(),
+            &mut items,
+            &corpse,
+            &mut MyRng::from_seed([0; 32]),
+        );
+
+        assert_eq!(items.at(corpse.position).len(), 1);
+        assert_eq!(items.at(corpse.position)[0].item_id, 2);
+        assert_eq!(
+            step_context.build().events.front(),
+            Some(&GameEvent::EntityDroppedLoot {
+                id: corpse.id,
+                position: corpse.position,
+                item_id: 2,
+                count: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn kind_without_table_drops_nothing() {
+        let corpse = Actor::create(Position::new(3, 4), ActorKind::Goblin);
+        let mut items = FloorItems::default();
+        let mut step_context = StepContext::default();
+
+        drop_loot(
+            &mut step_context,
+            &catalog(),
+            &mut items,
+            &corpse,
+            &mut MyRng::from_seed([0; 32]),
+        );
+
+        assert_eq!(items.iter().count(), 0);
+        assert!(step_context.build().events.is_empty());
+    }
+}
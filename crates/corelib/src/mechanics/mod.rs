@@ -1,5 +1,17 @@
 mod combat;
+mod doors;
+mod effects;
+mod loot;
 mod movement;
+mod terrain;
 
+pub use combat::AttackOutcome;
 pub(crate) use combat::try_attack;
+pub(crate) use doors::{try_close_door, try_open_door};
+pub(crate) use effects::{
+    apply_effect, entity_turns, inflict_on_hit, modify_stats,
+    refresh_entity_stats, tick_effects,
+};
+pub(crate) use loot::drop_loot;
 pub(crate) use movement::try_move;
+pub(crate) use terrain::enter_tile;
@@ -1,7 +1,10 @@
-use crate::{Actor, Direction, Position, walk_map::WalkMap};
+use crate::{
+    Actor, Direction, Position, step_result::StepContext, walk_map::WalkMap,
+};
 
 /// Try to move an entity in a given direction.
 pub(crate) fn try_move(
+    step_context: &mut StepContext,
     entity: &mut Actor,
     direction: Direction,
     walk_map: &mut WalkMap,
@@ -12,6 +15,7 @@ pub(crate) fn try_move(
     if walk_map.is_walkable(new_position) {
         entity.position = new_position;
         walk_map.relocate(old_position, new_position);
+        step_context.diff().position(entity.id, old_position, new_position);
         Some((old_position, new_position))
     } else {
         None
@@ -37,11 +41,14 @@ mod tests {
     #[test]
     fn moves_to_walkable_tile() {
         let (mut walk_map, mut actor) = setup_walk_map();
-        let result = try_move(&mut actor, Direction::East, &mut walk_map);
-        assert_eq!(
-            result,
-            Some((Position::new(0, 0), Position::new(1, 0)))
+        let mut step_context = StepContext::default();
+        let result = try_move(
+            &mut step_context,
+            &mut actor,
+            Direction::East,
+            &mut walk_map,
         );
+        assert_eq!(result, Some((Position::new(0, 0), Position::new(1, 0))));
         assert_eq!(actor.position, Position::new(1, 0));
         assert!(!walk_map.is_walkable(Position::new(1, 0)));
         assert!(walk_map.is_walkable(Position::new(0, 0)));
@@ -52,7 +59,13 @@ mod tests {
         let (mut walk_map, mut actor) = setup_walk_map();
         // East is walkable, occupy it to block
         walk_map.occupy(Position::new(1, 0));
-        let result = try_move(&mut actor, Direction::East, &mut walk_map);
+        let mut step_context = StepContext::default();
+        let result = try_move(
+            &mut step_context,
+            &mut actor,
+            Direction::East,
+            &mut walk_map,
+        );
         assert!(result.is_none());
         assert_eq!(actor.position, Position::new(0, 0));
     }
@@ -0,0 +1,69 @@
+use crate::{
+    Actor, dungeon::DungeonMap, events::GameEvent, step_result::StepContext,
+};
+
+/// Applies the effects of the tile the entity has just stepped on.
+///
+/// Tiles that deal damage hurt the entity, which may kill it. Removing dead
+/// entities is left to the caller.
+pub(crate) fn enter_tile(
+    step_context: &mut StepContext,
+    entity: &mut Actor,
+    dungeon: &DungeonMap,
+) {
+    let damage =
+        dungeon.get_tile(entity.position).properties().damage_on_enter();
+    if damage == 0 {
+        return;
+    }
+
+    let old_stats = entity.stats.clone();
+    entity.stats.hp = entity.stats.hp.saturating_sub(damage);
+    step_context.diff().stat(entity.id, old_stats, entity.stats.clone());
+    step_context.add_event(GameEvent::EntityHurtByTerrain {
+        id: entity.id,
+        position: entity.position,
+        damage,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Position, actors::ActorKind, dungeon::Tile};
+
+    #[test]
+    fn lava_hurts_entity() {
+        let mut dungeon = DungeonMap::simple(5, 5);
+        dungeon.set_tile(Position::new(1, 0), Tile::Lava);
+        let mut actor = Actor::create(Position::new(1, 0), ActorKind::Enemy);
+        let hp = actor.stats.hp;
+        let mut step_context = StepContext::default();
+
+        enter_tile(&mut step_context, &mut actor, &dungeon);
+
+        assert_eq!(actor.stats.hp, hp - 5);
+        let result = step_context.build();
+        assert_eq!(
+            result.events.front(),
+            Some(&GameEvent::EntityHurtByTerrain {
+                id: actor.id,
+                position: Position::new(1, 0),
+                damage: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn floor_is_harmless() {
+        let dungeon = DungeonMap::simple(5, 5);
+        let mut actor = Actor::create(Position::new(1, 0), ActorKind::Enemy);
+        let hp = actor.stats.hp;
+        let mut step_context = StepContext::default();
+
+        enter_tile(&mut step_context, &mut actor, &dungeon);
+
+        assert_eq!(actor.stats.hp, hp);
+        assert!(step_context.build().events.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{Direction, Position, walk_map::WalkMap};
+
+/// Maximum number of tiles the AI explores when looking for a path.
+pub(crate) const MAX_SEARCH_NODES: usize = 512;
+
+/// Finds the shortest path from `start` to `goal` with A*.
+///
+/// Only walkable tiles are crossed, except for the goal itself, which is
+/// usually occupied by the entity being chased. Closed doors are crossed too,
+/// as they can be opened on the way. Paths over costly tiles are avoided when
+/// a cheaper one exists. The search gives up after expanding
+/// `budget` tiles.
+///
+/// Returns the directions to follow, or `None` if no path was found.
+pub(crate) fn find_path(
+    walk_map: &WalkMap,
+    start: Position,
+    goal: Position,
+    budget: usize,
+) -> Option<Vec<Direction>> {
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<Position, (Position, Direction)> =
+        HashMap::new();
+    let mut cost = HashMap::from([(start, 0)]);
+
+    // Ties are broken by position so the search is deterministic.
+    open.push(Reverse((start.manhattan_distance(goal), 0, start)));
+
+    let mut expanded = 0;
+    while let Some(Reverse((_, current_cost, current))) = open.pop() {
+        if current == goal {
+            return Some(reconstruct(&came_from, start, goal));
+        }
+        if cost.get(&current).is_some_and(|&best| best < current_cost) {
+            continue;
+        }
+        if expanded == budget {
+            return None;
+        }
+        expanded += 1;
+
+        for direction in Direction::ALL {
+            let next = current + direction.to_offset_position();
+            if next != goal
+                && !walk_map.is_walkable(next)
+                && !walk_map.is_closed_door(next)
+            {
+                continue;
+            }
+            let next_cost = current_cost + walk_map.movement_cost(next);
+            if cost.get(&next).is_some_and(|&best| best <= next_cost) {
+                continue;
+            }
+            cost.insert(next, next_cost);
+            came_from.insert(next, (current, direction));
+            open.push(Reverse((
+                next_cost + next.manhattan_distance(goal),
+                next_cost,
+                next,
+            )));
+        }
+    }
+
+    None
+}
+
+/// Walks the `came_from` links back from the goal to the start.
+fn reconstruct(
+    came_from: &HashMap<Position, (Position, Direction)>,
+    start: Position,
+    goal: Position,
+) -> Vec<Direction> {
+    let mut path = Vec::new();
+    let mut current = goal;
+    while current != start {
+        let Some(&(previous, direction)) = came_from.get(&current) else {
+            break;
+        };
+        path.push(direction);
+        current = previous;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk_map(rows: &[&str]) -> WalkMap {
+        rows.iter()
+            .zip(0..)
+            .flat_map(|(row, y)| {
+                row.chars()
+                    .zip(0..)
+                    .filter(|(tile, _)| *tile == '.')
+                    .map(move |(_, x)| Position::new(x, y))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_straight_path() {
+        let map = walk_map(&["....."]);
+        let path =
+            find_path(&map, Position::new(0, 0), Position::new(3, 0), 100);
+        assert_eq!(path, Some(vec![Direction::East; 3]));
+    }
+
+    #[test]
+    fn routes_around_walls() {
+        let map = walk_map(&[
+            "...#.", //
+            ".#.#.", //
+            ".#...", //
+        ]);
+        let path =
+            find_path(&map, Position::new(0, 0), Position::new(4, 0), 100);
+        assert_eq!(path.map(|p| p.len()), Some(8));
+    }
+
+    #[test]
+    fn goal_may_be_occupied() {
+        let mut map = walk_map(&["..."]);
+        map.occupy(Position::new(2, 0));
+        let path =
+            find_path(&map, Position::new(0, 0), Position::new(2, 0), 100);
+        assert_eq!(path, Some(vec![Direction::East; 2]));
+    }
+
+    #[test]
+    fn avoids_costly_tiles() {
+        let map: WalkMap = [
+            ((0, 0), 1),
+            ((1, 0), 5),
+            ((2, 0), 1),
+            ((0, 1), 1),
+            ((1, 1), 1),
+            ((2, 1), 1),
+        ]
+        .into_iter()
+        .map(|((x, y), cost)| (Position::new(x, y), cost))
+        .collect();
+        let path =
+            find_path(&map, Position::new(0, 0), Position::new(2, 0), 100);
+        assert_eq!(path.map(|p| p.len()), Some(4));
+    }
+
+    #[test]
+    fn passes_through_closed_doors() {
+        let mut map = walk_map(&["....."]);
+        map.close_door(Position::new(2, 0));
+        let path =
+            find_path(&map, Position::new(0, 0), Position::new(4, 0), 100);
+        assert_eq!(path, Some(vec![Direction::East; 4]));
+    }
+
+    #[test]
+    fn returns_none_without_path() {
+        let map = walk_map(&["..#.."]);
+        let path =
+            find_path(&map, Position::new(0, 0), Position::new(4, 0), 100);
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn gives_up_after_budget() {
+        let map = walk_map(&[".........."]);
+        let start = Position::new(0, 0);
+        let goal = Position::new(9, 0);
+        assert!(find_path(&map, start, goal, 3).is_none());
+        assert!(find_path(&map, start, goal, 9).is_some());
+    }
+}
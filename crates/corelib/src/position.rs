@@ -2,6 +2,7 @@ use std::ops::{Add, AddAssign, Sub};
 
 /// Represents a position in the game.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     /// The x-coordinate of the position.
     pub x: i32,
@@ -25,6 +26,11 @@ impl Position {
     pub(crate) fn y(self) -> i32 {
         self.y
     }
+
+    /// Returns the Manhattan distance to another position.
+    pub(crate) fn manhattan_distance(self, other: Position) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
 }
 
 impl Add<Position> for Position {
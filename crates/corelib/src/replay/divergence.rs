@@ -0,0 +1,30 @@
+use crate::WorldError;
+
+/// Describes what differed between a recording and its replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivergenceKind {
+    /// The action was recorded at a different tick than it was replayed at.
+    Tick {
+        /// Tick at which the action was replayed.
+        found: u64,
+    },
+    /// The recorded settings no longer create a game.
+    InvalidSettings(WorldError),
+    /// The recorded action was refused during the replay.
+    Rejected,
+    /// The step produced different events.
+    Events,
+    /// The step left the game in a different state.
+    State,
+}
+
+/// The first point where a replay diverged from its recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    /// Recorded tick of the diverging action.
+    pub tick_id: u64,
+    /// Index of the diverging action in the log.
+    pub step: usize,
+    /// What differed.
+    pub kind: DivergenceKind,
+}
@@ -0,0 +1,121 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{GameEvent, GameState};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// FNV-1a hasher.
+///
+/// Unlike `DefaultHasher` it is not seeded, and it feeds integers in as
+/// little-endian bytes, with `usize` and `isize` (lengths and enum
+/// discriminants) widened to 64 bits. Fingerprints stored in an action log
+/// therefore match on every platform. They can still change when the hashed
+/// types change, or when a Rust release changes how the standard library
+/// feeds its types to a hasher.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_isize(&mut self, value: isize) {
+        self.write_i64(value as i64);
+    }
+}
+
+/// Fingerprint of the events produced by a single step.
+pub(crate) fn events_fingerprint<'a>(
+    events: impl IntoIterator<Item = &'a GameEvent>,
+) -> u64 {
+    let mut hasher = StableHasher::default();
+    for event in events {
+        event.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Fingerprint of the item, effect and actor definitions a game is played
+/// with.
+#[cfg(feature = "serde")]
+pub(crate) fn content_fingerprint(content: &crate::Content) -> u64 {
+    let mut hasher = StableHasher::default();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Fingerprint of everything in the game state that a step can change.
+pub(crate) fn state_fingerprint(state: &GameState) -> u64 {
+    let mut hasher = StableHasher::default();
+    state.tick_id.hash(&mut hasher);
+    state.status.hash(&mut hasher);
+    state.player.hash(&mut hasher);
+    state.inventory.hash(&mut hasher);
+    state.hotbar.hash(&mut hasher);
+    state.equipment.hash(&mut hasher);
+    state.entities.hash(&mut hasher);
+    state.dungeon.hash(&mut hasher);
+    state.items.hash(&mut hasher);
+    state.depth.hash(&mut hasher);
+    state.levels.hash(&mut hasher);
+    state.rng.tick_id().hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(value: impl Hash) -> u64 {
+        let mut hasher = StableHasher::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn pointer_sized_integers_hash_as_64_bits() {
+        assert_eq!(fingerprint(7_usize), fingerprint(7_u64));
+        assert_eq!(fingerprint(-7_isize), fingerprint(-7_i64));
+    }
+
+    #[test]
+    fn integers_hash_as_little_endian() {
+        let mut hasher = StableHasher::default();
+        hasher.write(&[1, 0, 0, 0]);
+        assert_eq!(fingerprint(1_u32), hasher.finish());
+    }
+}
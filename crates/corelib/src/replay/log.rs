@@ -0,0 +1,259 @@
+use crate::{
+    ActionError, GameState, PlayerAction, WorldError, WorldSettings, new_game,
+    step_result::StepResult,
+};
+
+use super::{
+    divergence::{Divergence, DivergenceKind},
+    fingerprint::{events_fingerprint, state_fingerprint},
+};
+
+/// A single player action recorded together with its outcome.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedStep {
+    /// The tick at which the action was applied.
+    pub(crate) tick_id: u64,
+    /// The applied action.
+    pub(crate) action: PlayerAction,
+    /// Fingerprint of the events produced by the action.
+    pub(crate) events: u64,
+    /// Fingerprint of the game state after the action.
+    pub(crate) state: u64,
+}
+
+impl RecordedStep {
+    /// Returns the tick at which the action was applied.
+    #[must_use]
+    pub fn tick_id(&self) -> u64 {
+        self.tick_id
+    }
+
+    /// Returns the applied action.
+    #[must_use]
+    pub fn action(&self) -> &PlayerAction {
+        &self.action
+    }
+}
+
+/// Records a run as world settings plus the sequence of player actions,
+/// so it can be replayed deterministically.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionLog {
+    settings: WorldSettings,
+    steps: Vec<RecordedStep>,
+}
+
+impl ActionLog {
+    /// Starts a new recording of a game created with the given settings.
+    ///
+    /// Returns the log together with the game to record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no game can be created with the settings.
+    pub fn start(
+        settings: WorldSettings,
+    ) -> Result<(Self, GameState), WorldError> {
+        let state = new_game(&settings)?;
+        Ok((Self { settings, steps: Vec::new() }, state))
+    }
+
+    /// Applies the action to the game state and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the action was refused. Refused actions do not
+    /// change the game, so they are not recorded.
+    pub fn record(
+        &mut self,
+        state: &mut GameState,
+        action: PlayerAction,
+    ) -> Result<StepResult, ActionError> {
+        let tick_id = state.tick_id;
+        let result = state.apply_player_action(&action)?;
+        self.steps.push(RecordedStep {
+            tick_id,
+            action,
+            events: events_fingerprint(&result.events),
+            state: state_fingerprint(state),
+        });
+        Ok(result)
+    }
+
+    /// Returns the settings the recorded game was created with.
+    #[must_use]
+    pub fn settings(&self) -> &WorldSettings {
+        &self.settings
+    }
+
+    /// Returns the recorded steps.
+    #[must_use]
+    pub fn steps(&self) -> &[RecordedStep] {
+        &self.steps
+    }
+
+    /// Replays the recorded actions against a fresh game.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first step where the replay diverged from the recording.
+    pub fn replay(&self) -> Result<GameState, Divergence> {
+        let mut state =
+            new_game(&self.settings).map_err(|error| Divergence {
+                tick_id: 0,
+                step: 0,
+                kind: DivergenceKind::InvalidSettings(error),
+            })?;
+
+        for (step, recorded) in self.steps.iter().enumerate() {
+            let divergence =
+                |kind| Divergence { tick_id: recorded.tick_id, step, kind };
+
+            if state.tick_id != recorded.tick_id {
+                return Err(divergence(DivergenceKind::Tick {
+                    found: state.tick_id,
+                }));
+            }
+
+            let Ok(result) = state.apply_player_action(&recorded.action) else {
+                return Err(divergence(DivergenceKind::Rejected));
+            };
+
+            if events_fingerprint(&result.events) != recorded.events {
+                return Err(divergence(DivergenceKind::Events));
+            }
+            if state_fingerprint(&state) != recorded.state {
+                return Err(divergence(DivergenceKind::State));
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EquipmentSlot, GeneratorKind};
+
+    fn settings() -> WorldSettings {
+        WorldSettings {
+            seed: [9; 32],
+            map_width: 21,
+            map_height: 21,
+            floor_tiles: 150,
+            enemies: 4,
+            generator: GeneratorKind::default(),
+            levels: 3,
+        }
+    }
+
+    fn record_run() -> (ActionLog, GameState) {
+        let Ok((mut log, mut state)) = ActionLog::start(settings()) else {
+            panic!("test settings must be valid");
+        };
+        for action in [
+            PlayerAction::PickUp,
+            PlayerAction::Skip,
+            PlayerAction::EquipItem {
+                item_id: 0,
+                slot: EquipmentSlot::MainHand,
+            },
+            PlayerAction::Skip,
+            PlayerAction::EquipItem { item_id: 2, slot: EquipmentSlot::Body },
+            PlayerAction::Skip,
+        ] {
+            assert!(log.record(&mut state, action).is_ok());
+        }
+        (log, state)
+    }
+
+    #[test]
+    fn records_tick_of_every_action() {
+        let (log, _) = record_run();
+        let ticks: Vec<_> =
+            log.steps().iter().map(RecordedStep::tick_id).collect();
+        assert_eq!(ticks, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn replay_reproduces_recorded_run() {
+        let (log, state) = record_run();
+        let replayed = log.replay();
+        assert!(
+            replayed.is_ok_and(
+                |r| state_fingerprint(&r) == state_fingerprint(&state)
+            )
+        );
+    }
+
+    #[test]
+    fn reports_first_diverging_step() {
+        let (mut log, _) = record_run();
+        log.steps[3].action =
+            PlayerAction::UnequipItem { slot: EquipmentSlot::MainHand };
+        log.steps[4].action = PlayerAction::Skip;
+
+        let divergence = log.replay().err();
+        assert_eq!(
+            divergence,
+            Some(Divergence {
+                tick_id: 3,
+                step: 3,
+                kind: DivergenceKind::Events
+            })
+        );
+    }
+
+    #[test]
+    fn reports_diverging_state() {
+        let (mut log, _) = record_run();
+        log.steps[1].state ^= 1;
+
+        let divergence = log.replay().err();
+        assert_eq!(
+            divergence,
+            Some(Divergence {
+                tick_id: 1,
+                step: 1,
+                kind: DivergenceKind::State
+            })
+        );
+    }
+
+    #[test]
+    fn reports_invalid_settings() {
+        let log = ActionLog {
+            settings: WorldSettings { map_width: 0, ..settings() },
+            steps: Vec::new(),
+        };
+
+        let divergence = log.replay().err();
+        assert_eq!(
+            divergence,
+            Some(Divergence {
+                tick_id: 0,
+                step: 0,
+                kind: DivergenceKind::InvalidSettings(WorldError::EmptyMap)
+            })
+        );
+    }
+
+    #[test]
+    fn reports_tick_mismatch() {
+        let (mut log, _) = record_run();
+        log.steps[2].tick_id = 7;
+
+        let divergence = log.replay().err();
+        assert_eq!(
+            divergence,
+            Some(Divergence {
+                tick_id: 7,
+                step: 2,
+                kind: DivergenceKind::Tick { found: 2 }
+            })
+        );
+    }
+}
@@ -0,0 +1,8 @@
+mod divergence;
+mod fingerprint;
+mod log;
+
+pub use divergence::{Divergence, DivergenceKind};
+#[cfg(feature = "serde")]
+pub(crate) use fingerprint::content_fingerprint;
+pub use log::{ActionLog, RecordedStep};
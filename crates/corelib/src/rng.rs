@@ -24,6 +24,18 @@ impl MyRng {
         MyRng { rng: ChaCha8Rng::from_seed(seed), tick_id: 0 }
     }
 
+    /// Derives an independent seed from the given one.
+    ///
+    /// Different streams of the same seed never produce the same values.
+    #[must_use]
+    pub(crate) fn derive_seed(seed: [u8; 32], stream: u64) -> [u8; 32] {
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        rng.set_stream(stream);
+        let mut derived = [0; 32];
+        rng.fill_bytes(&mut derived);
+        derived
+    }
+
     /// Load a random number generator from a seed and tick ID.
     ///
     /// # Warning!
@@ -38,6 +50,12 @@ impl MyRng {
         MyRng { rng, tick_id }
     }
 
+    /// Returns the number of values generated so far.
+    #[must_use]
+    pub(crate) fn tick_id(&self) -> usize {
+        self.tick_id
+    }
+
     /// Generate the next random number.
     #[must_use]
     pub(crate) fn range<T, R>(&mut self, range: R) -> T
@@ -50,6 +68,48 @@ impl MyRng {
     }
 }
 
+/// Serializable snapshot of the exact generator position.
+///
+/// Restoring from the word position is O(1), unlike [`MyRng::load`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MyRngSnapshot {
+    seed: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+    tick_id: usize,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MyRng {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        MyRngSnapshot {
+            seed: self.rng.get_seed(),
+            stream: self.rng.get_stream(),
+            word_pos: self.rng.get_word_pos(),
+            tick_id: self.tick_id,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MyRng {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let snapshot = MyRngSnapshot::deserialize(deserializer)?;
+        let mut rng = ChaCha8Rng::from_seed(snapshot.seed);
+        rng.set_stream(snapshot.stream);
+        rng.set_word_pos(snapshot.word_pos);
+        Ok(MyRng { rng, tick_id: snapshot.tick_id })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +158,12 @@ mod tests {
         let mut rng2 = MyRng::load(seed2, 0);
         assert_ne!(rng1.range(0..=100), rng2.range(0..=100));
     }
+
+    #[test]
+    fn derived_seeds_differ_per_stream() {
+        let seed = [0; 32];
+        assert_eq!(MyRng::derive_seed(seed, 1), MyRng::derive_seed(seed, 1));
+        assert_ne!(MyRng::derive_seed(seed, 1), MyRng::derive_seed(seed, 2));
+        assert_ne!(MyRng::derive_seed(seed, 1), seed);
+    }
 }
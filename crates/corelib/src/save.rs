@@ -0,0 +1,306 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Content, GameState, replay::content_fingerprint};
+
+/// Current version of the save format.
+///
+/// Must be bumped on every incompatible change of the serialized state.
+pub const SAVE_FORMAT_VERSION: u32 = 8;
+
+/// Errors that can occur while saving or loading a game.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The save was written with a different version of the format.
+    UnsupportedVersion {
+        /// Version found in the save.
+        found: u32,
+        /// Version supported by this build.
+        expected: u32,
+    },
+    /// The save was made with other item, effect or actor definitions than
+    /// the ones it is loaded with.
+    ContentMismatch,
+    /// The save could not be encoded or decoded.
+    Format(serde_json::Error),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::UnsupportedVersion { found, expected } => write!(
+                f,
+                "unsupported save version {found} (expected {expected})"
+            ),
+            SaveError::ContentMismatch => {
+                write!(f, "the save was made with different content")
+            },
+            SaveError::Format(error) => write!(f, "malformed save: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(error: serde_json::Error) -> Self {
+        SaveError::Format(error)
+    }
+}
+
+/// Minimal view of a save used to check the version before decoding the state.
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+#[derive(Serialize)]
+struct SaveFileRef<'a> {
+    version: u32,
+    /// Fingerprint of the content the game is played with.
+    content: u64,
+    state: &'a GameState,
+}
+
+#[derive(Deserialize)]
+struct SaveFile {
+    content: u64,
+    state: GameState,
+}
+
+impl GameState {
+    /// Serializes the full game state into a versioned save.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state could not be encoded.
+    pub fn save(&self) -> Result<Vec<u8>, SaveError> {
+        let save = SaveFileRef {
+            version: SAVE_FORMAT_VERSION,
+            content: content_fingerprint(&self.content),
+            state: self,
+        };
+        Ok(serde_json::to_vec(&save)?)
+    }
+
+    /// Restores a game state from a save of a game played with the built-in
+    /// content.
+    ///
+    /// The random number generator resumes from the exact position it had
+    /// when the game was saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the save has an unsupported version, is malformed
+    /// or was made with other content.
+    pub fn load(bytes: &[u8]) -> Result<Self, SaveError> {
+        GameState::load_with_content(bytes, Content::default())
+    }
+
+    /// Restores a game state from a save of a game played with the given
+    /// content, see [`crate::new_game_with_content`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the save has an unsupported version, is malformed
+    /// or was made with other content.
+    pub fn load_with_content(
+        bytes: &[u8],
+        content: Content,
+    ) -> Result<Self, SaveError> {
+        let header: SaveHeader = serde_json::from_slice(bytes)?;
+        if header.version != SAVE_FORMAT_VERSION {
+            return Err(SaveError::UnsupportedVersion {
+                found: header.version,
+                expected: SAVE_FORMAT_VERSION,
+            });
+        }
+
+        let SaveFile { content: fingerprint, mut state } =
+            serde_json::from_slice(bytes)?;
+        if fingerprint != content_fingerprint(&content) {
+            return Err(SaveError::ContentMismatch);
+        }
+
+        state.content = content;
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        EquipmentSlot, GeneratorKind, PlayerAction, Position, Tile,
+        TileVisibility, WorldSettings, new_game, new_game_with_content,
+    };
+
+    fn settings() -> WorldSettings {
+        WorldSettings {
+            seed: [7; 32],
+            map_width: 21,
+            map_height: 21,
+            floor_tiles: 150,
+            enemies: 4,
+            generator: GeneratorKind::default(),
+            levels: 3,
+        }
+    }
+
+    fn game() -> GameState {
+        let Ok(gs) = new_game(&settings()) else {
+            panic!("test settings must be valid");
+        };
+        gs
+    }
+
+    fn actions() -> Vec<PlayerAction> {
+        vec![
+            PlayerAction::PickUp,
+            PlayerAction::Skip,
+            PlayerAction::EquipItem {
+                item_id: 0,
+                slot: EquipmentSlot::MainHand,
+            },
+            PlayerAction::Skip,
+            PlayerAction::EquipItem { item_id: 2, slot: EquipmentSlot::Body },
+            PlayerAction::Skip,
+        ]
+    }
+
+    #[test]
+    fn loaded_game_continues_identically() -> Result<(), SaveError> {
+        let mut original = game();
+        for action in &actions() {
+            assert!(original.apply_player_action(action).is_ok());
+        }
+
+        let save = original.save()?;
+        let mut loaded = GameState::load(&save)?;
+        assert_eq!(loaded.save()?, save);
+
+        for action in &actions() {
+            assert_eq!(
+                original.apply_player_action(action).is_ok(),
+                loaded.apply_player_action(action).is_ok()
+            );
+        }
+
+        assert_eq!(loaded.tick_id, original.tick_id);
+        assert_eq!(loaded.save()?, original.save()?);
+        assert_eq!(loaded.rng.range(0..=1000), original.rng.range(0..=1000));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unsupported_version() -> Result<(), SaveError> {
+        let gs = game();
+        let save = serde_json::to_vec(&SaveFileRef {
+            version: 0,
+            content: content_fingerprint(&gs.content),
+            state: &gs,
+        })?;
+
+        let result = GameState::load(&save);
+        assert!(matches!(
+            result,
+            Err(SaveError::UnsupportedVersion {
+                found: 0,
+                expected: SAVE_FORMAT_VERSION
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_save_with_weapons_in_hotbar() -> Result<(), SaveError> {
+        // Saves from before the equipment slots kept worn items in the hotbar
+        let mut save: serde_json::Value =
+            serde_json::from_slice(&game().save()?)?;
+        save["version"] = 6.into();
+        if let Some(state) = save["state"].as_object_mut() {
+            state.remove("equipment");
+        }
+
+        let result = GameState::load(&serde_json::to_vec(&save)?);
+        assert!(matches!(
+            result,
+            Err(SaveError::UnsupportedVersion {
+                found: 6,
+                expected: SAVE_FORMAT_VERSION
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_save() {
+        let result = GameState::load(b"{\"version\": 8}");
+        assert!(matches!(result, Err(SaveError::Format(_))));
+    }
+
+    #[test]
+    fn new_entity_ids_do_not_collide_with_loaded_ones() -> Result<(), SaveError>
+    {
+        let gs = game();
+        let loaded = GameState::load(&gs.save()?)?;
+
+        let next = loaded.next_entity_id;
+        assert!(next > loaded.player.id.into_inner());
+        assert!(loaded.entities.iter().all(|e| next > e.id.into_inner()));
+        Ok(())
+    }
+
+    #[test]
+    fn explored_tiles_survive_save() -> Result<(), SaveError> {
+        let mut gs = game();
+        let corner = Position::new(10, 10);
+        gs.explored.set(corner, true);
+
+        let loaded = GameState::load(&gs.save()?)?;
+        assert_eq!(
+            loaded.fog_of_war().visibility(corner),
+            TileVisibility::Remembered
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn visited_levels_survive_save() -> Result<(), SaveError> {
+        let mut gs = game();
+        if let Some(stairs) = gs.dungeon.find_tile(&Tile::StairsDown) {
+            gs.player.position = stairs;
+        }
+        assert!(gs.apply_player_action(&PlayerAction::Descend).is_ok());
+
+        let loaded = GameState::load(&gs.save()?)?;
+        assert_eq!(loaded.depth(), 1);
+        assert_eq!(loaded.levels.len(), 1);
+        assert_eq!(loaded.next_entity_id, gs.next_entity_id);
+        assert_eq!(loaded.save()?, gs.save()?);
+        Ok(())
+    }
+
+    #[test]
+    fn custom_content_survives_save() -> Result<(), SaveError> {
+        let mut content = Content::default();
+        content.items.items[0].title = "Rusty Sword".to_string();
+        let Ok(gs) = new_game_with_content(&settings(), content.clone()) else {
+            panic!("test settings must be valid");
+        };
+        let save = gs.save()?;
+
+        assert!(matches!(
+            GameState::load(&save),
+            Err(SaveError::ContentMismatch)
+        ));
+        let loaded = GameState::load_with_content(&save, content)?;
+        let Some(item) = loaded.items_catalog().get(0) else {
+            panic!("the first item must exist");
+        };
+        assert_eq!(item.title(), "Rusty Sword");
+        assert_eq!(loaded.save()?, save);
+        Ok(())
+    }
+}
@@ -25,6 +25,10 @@ impl StepContext {
         self.events.push_back(event);
     }
 
+    pub(crate) fn diff(&mut self) -> &mut DiffBuilder {
+        &mut self.diff
+    }
+
     pub(crate) fn build(self) -> StepResult {
         StepResult { events: self.events, diff: self.diff.build() }
     }
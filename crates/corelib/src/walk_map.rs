@@ -1,10 +1,14 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::Position;
 
 /// `WalkMap` represents a set of positions where the entities can walk.
 pub(crate) struct WalkMap {
     inner: HashSet<Position>,
+    /// Cost of stepping on a position, if it differs from 1.
+    costs: HashMap<Position, u32>,
+    /// Closed doors, which can be walked on once opened.
+    doors: HashSet<Position>,
 }
 
 impl WalkMap {
@@ -13,6 +17,33 @@ impl WalkMap {
         self.inner.contains(&position)
     }
 
+    /// Check if a position holds a closed door.
+    pub(crate) fn is_closed_door(&self, position: Position) -> bool {
+        self.doors.contains(&position)
+    }
+
+    /// Mark a position as a closed door.
+    ///
+    /// Getting through costs an extra turn for opening the door.
+    pub(crate) fn close_door(&mut self, position: Position) {
+        self.inner.remove(&position);
+        self.doors.insert(position);
+        self.costs.insert(position, 2);
+    }
+
+    /// Mark a previously closed door as open so it can be walked on.
+    pub(crate) fn open_door(&mut self, position: Position) {
+        if self.doors.remove(&position) {
+            self.inner.insert(position);
+            self.costs.remove(&position);
+        }
+    }
+
+    /// Returns the pathing weight of a position.
+    pub(crate) fn movement_cost(&self, position: Position) -> u32 {
+        self.costs.get(&position).copied().unwrap_or(1)
+    }
+
     /// Move a position from one location to another.
     pub(crate) fn relocate(&mut self, from: Position, to: Position) {
         self.inner.insert(from);
@@ -23,11 +54,33 @@ impl WalkMap {
     pub(crate) fn occupy(&mut self, position: Position) {
         self.inner.remove(&position);
     }
+
+    /// Mark previously occupied position as free so it can be walked on.
+    pub(crate) fn release(&mut self, position: Position) {
+        self.inner.insert(position);
+    }
 }
 
 impl FromIterator<Position> for WalkMap {
     fn from_iter<T: IntoIterator<Item = Position>>(iter: T) -> Self {
-        Self { inner: iter.into_iter().collect() }
+        iter.into_iter().map(|position| (position, 1)).collect()
+    }
+}
+
+impl FromIterator<(Position, u32)> for WalkMap {
+    fn from_iter<T: IntoIterator<Item = (Position, u32)>>(iter: T) -> Self {
+        let mut walk_map = Self {
+            inner: HashSet::new(),
+            costs: HashMap::new(),
+            doors: HashSet::new(),
+        };
+        for (position, cost) in iter {
+            walk_map.inner.insert(position);
+            if cost != 1 {
+                walk_map.costs.insert(position, cost);
+            }
+        }
+        walk_map
     }
 }
 
@@ -44,6 +97,15 @@ mod tests {
         assert!(walk_map.is_walkable(Position::new(1, 1)));
     }
 
+    #[test]
+    fn test_release() {
+        let mut walk_map =
+            WalkMap::from_iter(vec![Position::new(0, 0), Position::new(1, 1)]);
+        walk_map.occupy(Position::new(0, 0));
+        walk_map.release(Position::new(0, 0));
+        assert!(walk_map.is_walkable(Position::new(0, 0)));
+    }
+
     #[test]
     fn test_relocate() {
         let mut walk_map =
@@ -53,4 +115,29 @@ mod tests {
         assert!(walk_map.is_walkable(Position::new(1, 1)));
         assert!(walk_map.is_walkable(Position::new(2, 2)));
     }
+
+    #[test]
+    fn test_doors() {
+        let mut walk_map = WalkMap::from_iter(vec![Position::new(0, 0)]);
+        walk_map.close_door(Position::new(0, 0));
+        assert!(!walk_map.is_walkable(Position::new(0, 0)));
+        assert!(walk_map.is_closed_door(Position::new(0, 0)));
+        assert_eq!(walk_map.movement_cost(Position::new(0, 0)), 2);
+
+        walk_map.open_door(Position::new(0, 0));
+        assert!(walk_map.is_walkable(Position::new(0, 0)));
+        assert!(!walk_map.is_closed_door(Position::new(0, 0)));
+        assert_eq!(walk_map.movement_cost(Position::new(0, 0)), 1);
+    }
+
+    #[test]
+    fn test_movement_cost() {
+        let mut walk_map = WalkMap::from_iter(vec![
+            (Position::new(0, 0), 3),
+            (Position::new(1, 1), 1),
+        ]);
+        walk_map.occupy(Position::new(0, 0));
+        assert_eq!(walk_map.movement_cost(Position::new(0, 0)), 3);
+        assert_eq!(walk_map.movement_cost(Position::new(1, 1)), 1);
+    }
 }
@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// Reasons why a game could not be created from the world settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldError {
+    /// The dungeon has no levels.
+    NoLevels,
+    /// The map has no tiles.
+    EmptyMap,
+    /// More floor tiles were requested than the map can hold.
+    TooManyFloorTiles {
+        /// Requested number of floor tiles.
+        floor_tiles: usize,
+        /// Number of tiles in the map.
+        area: usize,
+    },
+    /// The generated map has no floor where the player spawns.
+    BlockedSpawn,
+    /// The generated map has no floor left for the stairs.
+    NoRoomForStairs,
+    /// There is not enough free floor for the requested enemies.
+    TooManyEnemies {
+        /// Requested number of enemies.
+        enemies: usize,
+        /// Floor tiles left after placing the player and the stairs.
+        free_tiles: usize,
+    },
+}
+
+impl fmt::Display for WorldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorldError::NoLevels => {
+                write!(f, "the dungeon must have at least one level")
+            },
+            WorldError::EmptyMap => write!(f, "the map must not be empty"),
+            WorldError::TooManyFloorTiles { floor_tiles, area } => write!(
+                f,
+                "{floor_tiles} floor tiles do not fit into a map of {area} \
+                 tiles"
+            ),
+            WorldError::BlockedSpawn => {
+                write!(f, "the generated map has no floor at the spawn point")
+            },
+            WorldError::NoRoomForStairs => {
+                write!(f, "the generated map has no room for the stairs")
+            },
+            WorldError::TooManyEnemies { enemies, free_tiles } => write!(
+                f,
+                "{enemies} enemies do not fit on {free_tiles} free floor tiles"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WorldError {}
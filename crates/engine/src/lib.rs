@@ -12,9 +12,9 @@
 use std::sync::{Arc, Mutex};
 
 use protocol::{PlayerAction, State, StepResult};
-use transport::{LocalState, Transport, TransportError};
+use transport::{LocalState, Transport};
 
-pub use transport::LocalTransport;
+pub use transport::{LocalTransport, TransportError};
 
 /// This type represents the engine for the dungeon game.
 pub type LocalEngine = Engine<LocalTransport>;
@@ -26,8 +26,11 @@ pub struct Engine<T: Transport> {
 
 impl Engine<LocalTransport> {
     /// Creates a new instance of the corelib.
-    #[must_use]
-    pub fn new_state() -> LocalState {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the world could not be generated.
+    pub fn new_state() -> Result<LocalState, TransportError> {
         LocalTransport::new_state()
     }
 
@@ -7,7 +7,7 @@ use engine::Engine;
 mod resources {
     use std::sync::{Arc, Mutex};
 
-    use bevy::{ecs::resource::Resource, tasks::Task};
+    use bevy::{ecs::resource::Resource, prelude::*, tasks::Task};
 
     use protocol::StepResult;
     use transport::TransportResult;
@@ -19,6 +19,7 @@ mod resources {
         NetworkStage {
             task: Task<TransportResult<StepResult>>,
         },
+        GameOver,
     }
 
     #[derive(Resource, Default)]
@@ -30,13 +31,21 @@ mod resources {
     pub struct GlobalState {
         pub state: Arc<Mutex<transport::LocalState>>,
     }
+
+    #[derive(Resource, Clone)]
+    pub struct SpriteAtlas {
+        pub texture: Handle<Image>,
+        pub layout: Handle<TextureAtlasLayout>,
+    }
 }
 
 mod components {
     use bevy::ecs::component::Component;
 
     #[derive(Component)]
-    pub struct Player;
+    pub struct Player {
+        pub entity_id: u32,
+    }
 
     #[derive(Component)]
     pub struct Npc {
@@ -44,7 +53,9 @@ mod components {
     }
 
     #[derive(Component)]
-    pub struct Background;
+    pub struct Background {
+        pub position: corelib::Position,
+    }
 }
 
 mod systems {
@@ -57,9 +68,105 @@ mod systems {
 
     use crate::{
         components::{Background, Npc, Player},
-        resources::{GlobalState, TurnInfo, TurnStage},
+        resources::{GlobalState, SpriteAtlas, TurnInfo, TurnStage},
     };
 
+    type TileQuery<'w, 's> = Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Background,
+            &'static mut Sprite,
+            &'static mut Visibility,
+        ),
+        Without<Npc>,
+    >;
+
+    /// Sprite visibility and tint for a tile the player knows this much about.
+    fn fog_style(visibility: corelib::TileVisibility) -> (Visibility, Color) {
+        match visibility {
+            corelib::TileVisibility::Unknown => {
+                (Visibility::Hidden, Color::WHITE)
+            },
+            corelib::TileVisibility::Remembered => {
+                (Visibility::Inherited, Color::srgb(0.35, 0.35, 0.35))
+            },
+            corelib::TileVisibility::Visible => {
+                (Visibility::Inherited, Color::WHITE)
+            },
+        }
+    }
+
+    /// Spawns the sprites of the tiles and NPCs of the current level.
+    fn spawn_level(
+        commands: &mut Commands,
+        state: &transport::LocalState,
+        atlas: &SpriteAtlas,
+    ) {
+        let fog = state.fog_of_war();
+
+        for (position, tile) in state.dungeon().iter() {
+            let screen_x = position.x as f32 * 96.0;
+            let screen_y = position.y as f32 * -96.0;
+
+            // TODO: Add dedicated sprites for the stairs and terrain
+            let tile_index = match tile {
+                corelib::Tile::Floor
+                | corelib::Tile::StairsDown
+                | corelib::Tile::StairsUp
+                | corelib::Tile::DoorClosed
+                | corelib::Tile::DoorOpen
+                | corelib::Tile::Water
+                | corelib::Tile::Lava => 3,
+                corelib::Tile::Empty | corelib::Tile::Wall => 0,
+            };
+
+            let (visibility, color) = fog_style(fog.visibility(position));
+
+            commands.spawn((
+                Sprite {
+                    color,
+                    ..Sprite::from_atlas_image(
+                        atlas.texture.clone(),
+                        TextureAtlas {
+                            layout: atlas.layout.clone(),
+                            index: tile_index,
+                        },
+                    )
+                },
+                Transform::from_translation(Vec3::new(
+                    screen_x, screen_y, -1.0,
+                ))
+                .with_scale(Vec3::splat(8.0)),
+                visibility,
+                Background { position },
+            ));
+        }
+
+        for entity in state.entities() {
+            let position = entity.position();
+
+            let screen_x = position.x as f32 * 96.0;
+            let screen_y = position.y as f32 * -96.0;
+
+            commands.spawn((
+                Sprite::from_atlas_image(
+                    atlas.texture.clone(),
+                    TextureAtlas { layout: atlas.layout.clone(), index: 2 },
+                ),
+                Transform::from_translation(Vec3::new(screen_x, screen_y, 0.5))
+                    .with_scale(Vec3::splat(8.0)),
+                if fog.is_visible(position) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                },
+                Npc { entity_id: entity.id().into() },
+            ));
+        }
+    }
+
     pub fn setup(
         mut commands: Commands,
         global_state: ResMut<GlobalState>,
@@ -81,33 +188,14 @@ mod systems {
         layout.add_texture(bevy::math::URect::new(1, 66, 13, 78));
         let texture_atlas_layout = texture_atlas_layouts.add(layout);
 
+        let atlas = SpriteAtlas {
+            texture: sprite_texture.clone(),
+            layout: texture_atlas_layout.clone(),
+        };
+
         {
             let state = global_state.state.lock().unwrap();
-
-            for (position, tile) in state.dungeon().iter() {
-                let screen_x = position.x as f32 * 96.0;
-                let screen_y = position.y as f32 * -96.0;
-
-                let tile_index = match tile {
-                    corelib::Tile::Floor => 3,
-                    corelib::Tile::Empty => 0,
-                };
-
-                commands.spawn((
-                    Sprite::from_atlas_image(
-                        sprite_texture.clone(),
-                        TextureAtlas {
-                            layout: texture_atlas_layout.clone(),
-                            index: tile_index,
-                        },
-                    ),
-                    Transform::from_translation(Vec3::new(
-                        screen_x, screen_y, -1.0,
-                    ))
-                    .with_scale(Vec3::splat(8.0)),
-                    Background,
-                ));
-            }
+            spawn_level(&mut commands, &state, &atlas);
 
             let player = state.player();
             let player_position = player.position();
@@ -128,31 +216,11 @@ mod systems {
                     1.0,
                 ))
                 .with_scale(Vec3::splat(8.0)),
-                Player,
+                Player { entity_id: player.id().into() },
             ));
-
-            for entity in state.entities() {
-                let position = entity.position();
-
-                let screen_x = position.x as f32 * 96.0;
-                let screen_y = position.y as f32 * -96.0;
-
-                commands.spawn((
-                    Sprite::from_atlas_image(
-                        sprite_texture.clone(),
-                        TextureAtlas {
-                            layout: texture_atlas_layout.clone(),
-                            index: 2,
-                        },
-                    ),
-                    Transform::from_translation(Vec3::new(
-                        screen_x, screen_y, 0.5,
-                    ))
-                    .with_scale(Vec3::splat(8.0)),
-                    Npc { entity_id: entity.id().into() },
-                ));
-            }
         };
+
+        commands.insert_resource(atlas);
     }
 
     pub fn input_turn(
@@ -164,14 +232,18 @@ mod systems {
             return;
         }
 
-        let direction = if keys.just_pressed(KeyCode::KeyW) {
-            protocol::Direction::North
+        let action = if keys.just_pressed(KeyCode::KeyW) {
+            PlayerAction::Move(protocol::Direction::North)
         } else if keys.just_pressed(KeyCode::KeyS) {
-            protocol::Direction::South
+            PlayerAction::Move(protocol::Direction::South)
         } else if keys.just_pressed(KeyCode::KeyA) {
-            protocol::Direction::West
+            PlayerAction::Move(protocol::Direction::West)
         } else if keys.just_pressed(KeyCode::KeyD) {
-            protocol::Direction::East
+            PlayerAction::Move(protocol::Direction::East)
+        } else if keys.just_pressed(KeyCode::Period) {
+            PlayerAction::Descend
+        } else if keys.just_pressed(KeyCode::Comma) {
+            PlayerAction::Ascend
         } else {
             return;
         };
@@ -180,52 +252,127 @@ mod systems {
         let task_pool = IoTaskPool::get();
         let task = task_pool.spawn(async move {
             let mut engine = Engine::new_local_game(state_arc);
-            engine.apply_step(PlayerAction::Move(direction)).await
+            engine.apply_step(action).await
         });
 
         turn_info.stage = TurnStage::NetworkStage { task };
     }
 
     pub fn poll_turn_result(
+        mut commands: Commands,
         mut turn_info: ResMut<TurnInfo>,
         global_state: ResMut<GlobalState>,
-        mut player_query: Query<&mut Transform, (With<Player>, Without<Npc>)>,
-        mut npc_query: Query<(&mut Transform, &Npc), Without<Player>>,
+        mut player_query: Query<(&mut Transform, &Player), Without<Npc>>,
+        mut npc_query: Query<
+            (Entity, &mut Transform, &mut Visibility, &Npc),
+            Without<Player>,
+        >,
+        mut tile_query: TileQuery,
+        atlas: Res<SpriteAtlas>,
     ) {
         if let TurnStage::NetworkStage { task } = &mut turn_info.stage {
             let status = future::block_on(future::poll_once(task));
 
             if let Some(chunk_data) = status {
-                if let Ok(_step_result) = chunk_data {
-                    // Update player and NPC positions after turn completion
+                if let Ok(step_result) = chunk_data {
+                    let diff = &step_result.diff;
+                    let changed_level =
+                        step_result.events.iter().any(|event| {
+                            matches!(
+                                event,
+                                protocol::GameEvent::PlayerChangedDepth { .. }
+                            )
+                        });
+
+                    // The whole level is replaced when the player takes the stairs
+                    if changed_level {
+                        for (entity, ..) in &tile_query {
+                            commands.entity(entity).despawn();
+                        }
+                    }
+
+                    // Remove sprites of dead NPCs
+                    for (entity, _, _, npc_component) in npc_query.iter() {
+                        if diff
+                            .despawned
+                            .iter()
+                            .any(|id| id.0 == npc_component.entity_id)
+                        {
+                            commands.entity(entity).despawn();
+                        }
+                    }
+
+                    // Move sprites of entities that changed position
+                    for position_diff in &diff.positions {
+                        let screen_x = position_diff.new.x as f32 * 96.0;
+                        let screen_y = position_diff.new.y as f32 * -96.0;
+
+                        if let Ok((mut player_transform, player_component)) =
+                            player_query.single_mut()
+                            && player_component.entity_id
+                                == position_diff.entity_id.0
+                        {
+                            player_transform.translation =
+                                Vec3::new(screen_x, screen_y, 1.0);
+                        }
+                        for (_, mut npc_transform, _, npc_component) in
+                            npc_query.iter_mut()
+                        {
+                            if npc_component.entity_id
+                                == position_diff.entity_id.0
+                            {
+                                npc_transform.translation =
+                                    Vec3::new(screen_x, screen_y, 0.5);
+                            }
+                        }
+                    }
+
                     let state = global_state.state.lock().unwrap();
+                    if changed_level {
+                        spawn_level(&mut commands, &state, &atlas);
+                    }
 
-                    // Update player position
-                    if let Ok(mut player_transform) = player_query.single_mut()
+                    // Dim remembered tiles and hide what the player cannot see
+                    let fog = state.fog_of_war();
+                    for (_, background, mut sprite, mut visibility) in
+                        &mut tile_query
                     {
-                        let player = state.player();
-                        let player_position = player.position();
-                        let player_screen_x = player_position.x as f32 * 96.0;
-                        let player_screen_y = player_position.y as f32 * -96.0;
-                        player_transform.translation =
-                            Vec3::new(player_screen_x, player_screen_y, 1.0);
+                        (*visibility, sprite.color) =
+                            fog_style(fog.visibility(background.position));
                     }
-
-                    // Update NPC positions
-                    for (mut npc_transform, npc_component) in
-                        npc_query.iter_mut()
+                    for (_, _, mut visibility, npc_component) in &mut npc_query
                     {
-                        if let Some(entity) = state
+                        let is_visible = state
                             .entities()
                             .iter()
                             .find(|e| e.id() == npc_component.entity_id.into())
-                        {
-                            let position = entity.position();
-                            let screen_x = position.x as f32 * 96.0;
-                            let screen_y = position.y as f32 * -96.0;
-                            npc_transform.translation =
-                                Vec3::new(screen_x, screen_y, 0.5);
-                        }
+                            .is_some_and(|e| fog.is_visible(e.position()));
+                        *visibility = if is_visible {
+                            Visibility::Inherited
+                        } else {
+                            Visibility::Hidden
+                        };
+                    }
+
+                    // Stop accepting input once the game is over
+                    let message = match state.status() {
+                        corelib::GameStatus::Running => None,
+                        corelib::GameStatus::PlayerDead => Some("You died"),
+                        corelib::GameStatus::Victory => Some("Victory"),
+                    };
+                    if let Some(message) = message {
+                        commands.spawn((
+                            Text::new(message),
+                            TextFont { font_size: 64.0, ..default() },
+                            Node {
+                                position_type: PositionType::Absolute,
+                                top: Val::Px(24.0),
+                                left: Val::Px(24.0),
+                                ..default()
+                            },
+                        ));
+                        turn_info.stage = TurnStage::GameOver;
+                        return;
                     }
                 }
                 turn_info.stage = TurnStage::PlayerStage;
@@ -235,7 +382,10 @@ mod systems {
 }
 
 fn main() {
-    let state = Engine::new_state();
+    let Ok(state) = Engine::new_state() else {
+        eprintln!("Failed to create game");
+        return;
+    };
     let state = Arc::new(Mutex::new(state));
 
     App::new()
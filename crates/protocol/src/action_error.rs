@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{EquipmentSlot, GameStatus, Position};
+
+/// Represents the reason why a player action was refused.
+///
+/// A refused action does not change the game and does not take a turn.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ActionError {
+    /// The game has already ended.
+    GameOver {
+        /// Status the game ended with.
+        status: GameStatus,
+    },
+    /// The player is dead.
+    Dead,
+    /// The level behind the stairs could not be generated.
+    LevelGeneration,
+    /// Something blocks the way.
+    Blocked {
+        /// The blocked position.
+        position: Position,
+    },
+    /// There is nothing to attack.
+    NoTarget {
+        /// The attacked position.
+        position: Position,
+    },
+    /// There is no door to open or close.
+    NoDoor {
+        /// The position without a door.
+        position: Position,
+    },
+    /// There are no stairs leading the requested way.
+    NoStairs {
+        /// The player's position.
+        position: Position,
+    },
+    /// There is nothing to pick up.
+    NoItems {
+        /// The player's position.
+        position: Position,
+    },
+    /// The inventory does not hold the item.
+    ItemNotFound {
+        /// The ID of the missing item.
+        item_id: usize,
+    },
+    /// The item cannot be used.
+    NotUsable {
+        /// The ID of the item that was tried.
+        item_id: usize,
+    },
+    /// Zero items were asked for.
+    InvalidCount,
+    /// The inventory has no room for the item.
+    InventoryFull {
+        /// The ID of the item that does not fit.
+        item_id: usize,
+    },
+    /// The hotbar has no such slot.
+    InvalidSlot {
+        /// The requested hotbar slot.
+        slot: usize,
+    },
+    /// The hotbar slot already holds an item.
+    SlotOccupied {
+        /// The requested hotbar slot.
+        slot: usize,
+    },
+    /// The hotbar slot is empty.
+    SlotEmpty {
+        /// The requested hotbar slot.
+        slot: usize,
+    },
+    /// The item cannot be worn in the equipment slot.
+    NotEquippable {
+        /// The ID of the item that was tried.
+        item_id: usize,
+        /// The requested equipment slot.
+        slot: EquipmentSlot,
+    },
+    /// Something is already worn in the equipment slot.
+    EquipmentSlotTaken {
+        /// The requested equipment slot.
+        slot: EquipmentSlot,
+    },
+    /// Nothing is worn in the equipment slot.
+    NothingEquipped {
+        /// The requested equipment slot.
+        slot: EquipmentSlot,
+    },
+    /// A two-handed weapon and the off hand cannot be used together.
+    TwoHanded {
+        /// The ID of the two-handed weapon.
+        item_id: usize,
+    },
+}
@@ -1,10 +1,61 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Direction;
+use crate::{Direction, EquipmentSlot};
 
 /// Represents a player's action in the game.
 #[derive(Serialize, Deserialize)]
 pub enum PlayerAction {
     /// Move the player in a specific direction.
     Move(Direction),
+    /// Take the stairs down to the next level.
+    Descend,
+    /// Take the stairs up to the previous level.
+    Ascend,
+    /// Open the door in a specific direction.
+    Open(Direction),
+    /// Close the door in a specific direction.
+    Close(Direction),
+    /// Pick up the items lying under the player.
+    PickUp,
+    /// Drop an item from the inventory under the player.
+    Drop {
+        /// The ID of the item to drop.
+        item_id: usize,
+        /// How many items to drop. Drops the whole stack if not set.
+        count: Option<u32>,
+    },
+    /// Wear an item from the inventory in a specific equipment slot.
+    EquipItem {
+        /// The ID of the item to equip.
+        item_id: usize,
+        /// The slot to equip the item in.
+        slot: EquipmentSlot,
+    },
+    /// Take off the item worn in a specific equipment slot.
+    UnequipItem {
+        /// The slot to unequip the item from.
+        slot: EquipmentSlot,
+    },
+    /// Move a stack of consumables from the inventory to a hotbar slot.
+    AssignHotbar {
+        /// The ID of the item to assign.
+        item_id: usize,
+        /// The hotbar slot to put the items in.
+        slot: usize,
+    },
+    /// Move the items in a hotbar slot back to the inventory.
+    ClearHotbar {
+        /// The hotbar slot to clear.
+        slot: usize,
+    },
+    /// Use up one consumable item from the inventory.
+    UseItem {
+        /// The ID of the item to use.
+        item_id: usize,
+    },
+    /// Use up one consumable item from a specific hotbar slot.
+    UseHotbarItem {
+        /// The slot holding the item to use.
+        slot: usize,
+    },
 }
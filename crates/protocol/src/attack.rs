@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents how an attack ended.
+#[derive(Serialize, Deserialize)]
+pub enum AttackOutcome {
+    /// The target evaded the attack.
+    Miss,
+    /// The attack hit.
+    Hit {
+        /// Damage dealt to the target.
+        damage: u32,
+    },
+    /// The attack hit critically.
+    Critical {
+        /// Damage dealt to the target.
+        damage: u32,
+    },
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{EntityId, Position};
+use crate::{
+    EffectInstance, EntityId, EquipmentSlot, ItemStack, Position, Stats, Tile,
+};
 
 /// Container for entity diffs.
 #[derive(Serialize, Deserialize)]
@@ -13,9 +15,56 @@ pub struct EntityDiff<D> {
     pub new: D,
 }
 
+/// Container for inventory, hotbar or equipment slot diffs.
+#[derive(Serialize, Deserialize)]
+pub struct SlotDiff<D, S = usize> {
+    /// Slot index.
+    pub slot: S,
+    /// Old slot content.
+    pub old: D,
+    /// New slot content.
+    pub new: D,
+}
+
+/// Container for dungeon tile diffs.
+#[derive(Serialize, Deserialize)]
+pub struct TileDiff {
+    /// Position of the tile.
+    pub position: Position,
+    /// Old tile.
+    pub old: Tile,
+    /// New tile.
+    pub new: Tile,
+}
+
+/// An entity that appeared during the step.
+#[derive(Serialize, Deserialize)]
+pub struct SpawnedEntity {
+    /// Entity ID.
+    pub entity_id: EntityId,
+    /// The position of the entity.
+    pub position: Position,
+}
+
 /// A diff of the game state before and after the step.
 #[derive(Serialize, Deserialize)]
 pub struct StateDiff {
     /// The positions that changed during the step.
     pub positions: Vec<EntityDiff<Position>>,
+    /// The stats that changed during the step.
+    pub stats: Vec<EntityDiff<Stats>>,
+    /// The active effects that changed during the step.
+    pub effects: Vec<EntityDiff<Vec<EffectInstance>>>,
+    /// The inventory slots that changed during the step.
+    pub inventory: Vec<SlotDiff<Option<ItemStack>>>,
+    /// The hotbar slots that changed during the step.
+    pub hotbar: Vec<SlotDiff<Option<ItemStack>>>,
+    /// The equipment slots that changed during the step.
+    pub equipment: Vec<SlotDiff<Option<ItemStack>, EquipmentSlot>>,
+    /// The entities that were spawned during the step.
+    pub spawned: Vec<SpawnedEntity>,
+    /// The entities that were removed during the step.
+    pub despawned: Vec<EntityId>,
+    /// The dungeon tiles that changed during the step.
+    pub tiles: Vec<TileDiff>,
 }
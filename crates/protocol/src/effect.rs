@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents an effect active on an entity.
+#[derive(Serialize, Deserialize)]
+pub struct EffectInstance {
+    /// Effect ID.
+    pub effect_id: usize,
+    /// Number of turns the effect stays active.
+    pub remaining_turns: u8,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a named place on the body an item can be worn in.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum EquipmentSlot {
+    /// The weapon hand.
+    MainHand,
+    /// The other hand, taken by shields and two-handed weapons.
+    OffHand,
+    /// The helmet.
+    Head,
+    /// The body armor.
+    Body,
+    /// The ring.
+    Ring,
+}
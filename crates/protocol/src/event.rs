@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Direction, Position};
+use crate::{AttackOutcome, EquipmentSlot, Position};
 
 /// Represents an event that occurs in the game.
 #[derive(Serialize, Deserialize)]
@@ -10,6 +10,8 @@ pub enum GameEvent {
     PlayerSkippedMove,
     /// Player died event
     PlayerDied,
+    /// Player lost their turn to a stun
+    PlayerStunned,
     /// Player moved
     PlayerMoved {
         /// Player's previous position
@@ -17,35 +19,106 @@ pub enum GameEvent {
         /// Player's new position
         to: Position,
     },
-    /// Player bumped into something
-    PlayerBumped {
-        /// Player's position
-        position: Position,
-        /// Player's direction
-        direction: Direction,
-    },
     /// Player attacked
     PlayerAttacked {
         /// Player's target entity ID
         target: u32,
-        /// Player's damage
-        damage: u32,
+        /// Whether the attack hit and for how much damage
+        outcome: AttackOutcome,
     },
-    /// Player attack missed
-    PlayerAttackMissed,
     /// Player equipped item
     PlayerEquippedItem {
         /// Item ID
         item_id: usize,
-        /// Slot ID
-        slot: usize,
+        /// Equipment slot
+        slot: EquipmentSlot,
     },
     /// Player unequipped item
     PlayerUnequippedItem {
+        /// Equipment slot
+        slot: EquipmentSlot,
+    },
+    /// Player put items into a hotbar slot
+    PlayerAssignedHotbar {
+        /// Item ID
+        item_id: usize,
+        /// Slot ID
+        slot: usize,
+    },
+    /// Player moved the items of a hotbar slot back to the inventory
+    PlayerClearedHotbar {
         /// Slot ID
         slot: usize,
     },
+    /// Player picked up an item from the floor
+    PlayerPickedUpItem {
+        /// Item ID
+        item_id: usize,
+        /// Number of items picked up
+        count: u32,
+    },
+    /// Player dropped an item on the floor
+    PlayerDroppedItem {
+        /// Item ID
+        item_id: usize,
+        /// Number of items dropped
+        count: u32,
+    },
+    /// Player's inventory has no room for an item
+    PlayerInventoryFull {
+        /// Item ID
+        item_id: usize,
+    },
+    /// Player took the stairs to another level
+    PlayerChangedDepth {
+        /// Depth the player left
+        from: u32,
+        /// Depth the player arrived at
+        to: u32,
+    },
+    /// Player opened a door
+    PlayerOpenedDoor {
+        /// Position of the door
+        position: Position,
+    },
+    /// Player closed a door
+    PlayerClosedDoor {
+        /// Position of the door
+        position: Position,
+    },
     /* --- Entity events --- */
+    /// Entity, including the player, was hurt by the tile it stepped on
+    EntityHurtByTerrain {
+        /// Entity ID
+        id: u32,
+        /// Position of the tile
+        position: Position,
+        /// Damage dealt
+        damage: u32,
+    },
+    /// Entity appeared on the player's level
+    EntityCreated {
+        /// Entity ID
+        id: u32,
+        /// Entity's position
+        position: Position,
+    },
+    /// Entity died event
+    EntityDied {
+        /// Entity ID
+        id: u32,
+    },
+    /// Dead entity left loot on the floor
+    EntityDroppedLoot {
+        /// Entity ID
+        id: u32,
+        /// Position of the loot
+        position: Position,
+        /// Item ID
+        item_id: usize,
+        /// Number of items dropped
+        count: u32,
+    },
     /// Entity moved
     EntityMoved {
         /// Entity ID
@@ -55,14 +128,21 @@ pub enum GameEvent {
         /// Entity's new position
         to: Position,
     },
+    /// Entity opened a door
+    EntityOpenedDoor {
+        /// Entity ID
+        id: u32,
+        /// Position of the door
+        position: Position,
+    },
     /// Entity attacked
     EntityAttacked {
         /// Entity ID
         id: u32,
         /// Entity's target position
         target: Position,
-        /// Entity's damage
-        damage: u32,
+        /// Whether the attack hit and for how much damage
+        outcome: AttackOutcome,
     },
     /// Entity effect ticked
     EffectTick {
@@ -78,4 +158,20 @@ pub enum GameEvent {
         /// Effect ID
         effect_id: usize,
     },
+    /// Player used up an item
+    ItemUsed {
+        /// Item ID
+        item_id: usize,
+    },
+    /// Effect applied to entity
+    EffectApplied {
+        /// Entity ID
+        entity_id: u32,
+        /// Effect ID
+        effect_id: usize,
+        /// Name of the effect
+        name: String,
+        /// Number of turns the effect lasts
+        duration: u8,
+    },
 }
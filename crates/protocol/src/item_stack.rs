@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a stack of items of the same kind.
+#[derive(Serialize, Deserialize)]
+pub struct ItemStack {
+    /// Item ID.
+    pub item_id: usize,
+    /// Number of items in the stack.
+    pub count: u32,
+}
@@ -10,20 +10,36 @@
 //! This crate provides the protocol for the dungeon game.
 //! It defines the data structures and messages used to communicate between the frontend and backend.
 
+mod action_error;
 mod actions;
+mod attack;
 mod diff;
 mod directions;
+mod effect;
 mod entity_id;
+mod equipment;
 mod event;
+mod item_stack;
 mod position;
 mod state;
+mod stats;
+mod status;
 mod step;
+mod tile;
 
+pub use action_error::ActionError;
 pub use actions::PlayerAction;
-pub use diff::{EntityDiff, StateDiff};
+pub use attack::AttackOutcome;
+pub use diff::{EntityDiff, SlotDiff, SpawnedEntity, StateDiff, TileDiff};
 pub use directions::Direction;
+pub use effect::EffectInstance;
 pub use entity_id::EntityId;
+pub use equipment::EquipmentSlot;
 pub use event::GameEvent;
+pub use item_stack::ItemStack;
 pub use position::Position;
 pub use state::State;
+pub use stats::Stats;
+pub use status::GameStatus;
 pub use step::StepResult;
+pub use tile::Tile;
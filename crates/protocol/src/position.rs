@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Represents a position in the game.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Position {
     /// The x-coordinate of the position.
     pub x: i32,
@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Position;
+use crate::{GameStatus, Position};
 
 /// Represents the state of a game.
 #[derive(Serialize, Deserialize)]
 pub struct State {
     /// The position of the player.
     pub player: Position,
+    /// The status of the game.
+    pub status: GameStatus,
+    /// Depth of the level the player is on. The first level has depth 0.
+    pub depth: u32,
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the stats of an entity.
+#[derive(Serialize, Deserialize)]
+pub struct Stats {
+    /// The current health of the entity.
+    pub hp: u32,
+    /// The health the entity cannot be healed beyond.
+    pub max_hp: u32,
+    /// Minimum attack power of the entity.
+    pub min_damage: u32,
+    /// Maximum attack power of the entity.
+    pub max_damage: u32,
+    /// Defense power of the entity.
+    pub defense: u32,
+    /// How many tiles far the entity can see.
+    pub sight_radius: u32,
+    /// Chance in percent to hit a target that does not evade.
+    pub accuracy: u32,
+    /// Percentage points taken off the hit chance of attackers.
+    pub evasion: u32,
+    /// Chance in percent that a hit is critical.
+    pub crit_chance: u32,
+    /// Damage of critical hits in percent of the rolled damage.
+    pub crit_multiplier: u32,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the status of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameStatus {
+    /// The game is in progress.
+    Running,
+    /// The player has died.
+    PlayerDead,
+    /// The player has won.
+    Victory,
+}
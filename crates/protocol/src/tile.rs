@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a tile of the dungeon map.
+#[derive(Serialize, Deserialize)]
+pub enum Tile {
+    /// No tile.
+    Empty,
+    /// A tile that can be walked on.
+    Floor,
+    /// Stairs leading one level deeper.
+    StairsDown,
+    /// Stairs leading one level up.
+    StairsUp,
+    /// Solid rock bordering the walkable parts of the map.
+    Wall,
+    /// A closed door.
+    DoorClosed,
+    /// Shallow water.
+    Water,
+    /// Lava.
+    Lava,
+    /// An open door.
+    DoorOpen,
+}
@@ -10,6 +10,39 @@ impl ToCorelib<corelib::PlayerAction> for protocol::PlayerAction {
             Self::Move(direction) => {
                 corelib::PlayerAction::Move(direction.to_corelib())
             },
+            Self::Descend => corelib::PlayerAction::Descend,
+            Self::Ascend => corelib::PlayerAction::Ascend,
+            Self::Open(direction) => {
+                corelib::PlayerAction::Open(direction.to_corelib())
+            },
+            Self::Close(direction) => {
+                corelib::PlayerAction::Close(direction.to_corelib())
+            },
+            Self::PickUp => corelib::PlayerAction::PickUp,
+            Self::Drop { item_id, count } => {
+                corelib::PlayerAction::Drop { item_id, count }
+            },
+            Self::EquipItem { item_id, slot } => {
+                corelib::PlayerAction::EquipItem {
+                    item_id,
+                    slot: slot.to_corelib(),
+                }
+            },
+            Self::UnequipItem { slot } => {
+                corelib::PlayerAction::UnequipItem { slot: slot.to_corelib() }
+            },
+            Self::AssignHotbar { item_id, slot } => {
+                corelib::PlayerAction::AssignHotbar { item_id, slot }
+            },
+            Self::ClearHotbar { slot } => {
+                corelib::PlayerAction::ClearHotbar { slot }
+            },
+            Self::UseItem { item_id } => {
+                corelib::PlayerAction::UseItem { item_id }
+            },
+            Self::UseHotbarItem { slot } => {
+                corelib::PlayerAction::UseHotbarItem { slot }
+            },
         }
     }
 }
@@ -25,6 +58,18 @@ impl ToCorelib<corelib::Direction> for protocol::Direction {
     }
 }
 
+impl ToCorelib<corelib::EquipmentSlot> for protocol::EquipmentSlot {
+    fn to_corelib(self) -> corelib::EquipmentSlot {
+        match self {
+            Self::MainHand => corelib::EquipmentSlot::MainHand,
+            Self::OffHand => corelib::EquipmentSlot::OffHand,
+            Self::Head => corelib::EquipmentSlot::Head,
+            Self::Body => corelib::EquipmentSlot::Body,
+            Self::Ring => corelib::EquipmentSlot::Ring,
+        }
+    }
+}
+
 /// Trait for casting corelib native types into DTO protocol types
 pub trait FromCorelib<T> {
     /// Convert type from `corelib` types
@@ -32,29 +77,96 @@ pub trait FromCorelib<T> {
 }
 
 impl FromCorelib<corelib::GameEvent> for protocol::GameEvent {
+    // One arm per event, so the mapping grows with every new event.
+    #[allow(clippy::too_many_lines)]
     fn from_corelib(from: corelib::GameEvent) -> Self {
         match from {
             corelib::GameEvent::PlayerSkippedMove => Self::PlayerSkippedMove,
             corelib::GameEvent::PlayerDied => Self::PlayerDied,
+            corelib::GameEvent::PlayerStunned => Self::PlayerStunned,
             corelib::GameEvent::PlayerMoved { from, to } => Self::PlayerMoved {
                 from: protocol::Position::from_corelib(from),
                 to: protocol::Position::from_corelib(to),
             },
-            corelib::GameEvent::PlayerBumped { position, direction } => {
-                Self::PlayerBumped {
-                    position: protocol::Position::from_corelib(position),
-                    direction: protocol::Direction::from_corelib(direction),
+            corelib::GameEvent::PlayerAttacked { target, outcome } => {
+                Self::PlayerAttacked {
+                    target: target.into(),
+                    outcome: protocol::AttackOutcome::from_corelib(outcome),
                 }
             },
-            corelib::GameEvent::PlayerAttacked { target, damage } => {
-                Self::PlayerAttacked { target: target.into(), damage }
-            },
-            corelib::GameEvent::PlayerAttackMissed => Self::PlayerAttackMissed,
             corelib::GameEvent::PlayerEquippedItem { item_id, slot } => {
-                Self::PlayerEquippedItem { item_id, slot }
+                Self::PlayerEquippedItem {
+                    item_id,
+                    slot: protocol::EquipmentSlot::from_corelib(slot),
+                }
             },
             corelib::GameEvent::PlayerUnequippedItem { slot } => {
-                Self::PlayerUnequippedItem { slot }
+                Self::PlayerUnequippedItem {
+                    slot: protocol::EquipmentSlot::from_corelib(slot),
+                }
+            },
+            corelib::GameEvent::PlayerAssignedHotbar { item_id, slot } => {
+                Self::PlayerAssignedHotbar { item_id, slot }
+            },
+            corelib::GameEvent::PlayerClearedHotbar { slot } => {
+                Self::PlayerClearedHotbar { slot }
+            },
+            corelib::GameEvent::PlayerChangedDepth { from, to } => {
+                Self::PlayerChangedDepth { from, to }
+            },
+            corelib::GameEvent::PlayerPickedUpItem { item_id, count } => {
+                Self::PlayerPickedUpItem { item_id, count }
+            },
+            corelib::GameEvent::PlayerDroppedItem { item_id, count } => {
+                Self::PlayerDroppedItem { item_id, count }
+            },
+            corelib::GameEvent::PlayerInventoryFull { item_id } => {
+                Self::PlayerInventoryFull { item_id }
+            },
+            corelib::GameEvent::PlayerOpenedDoor { position } => {
+                Self::PlayerOpenedDoor {
+                    position: protocol::Position::from_corelib(position),
+                }
+            },
+            corelib::GameEvent::PlayerClosedDoor { position } => {
+                Self::PlayerClosedDoor {
+                    position: protocol::Position::from_corelib(position),
+                }
+            },
+            corelib::GameEvent::EntityOpenedDoor { id, position } => {
+                Self::EntityOpenedDoor {
+                    id: id.into(),
+                    position: protocol::Position::from_corelib(position),
+                }
+            },
+            corelib::GameEvent::EntityHurtByTerrain {
+                id,
+                position,
+                damage,
+            } => Self::EntityHurtByTerrain {
+                id: id.into(),
+                position: protocol::Position::from_corelib(position),
+                damage,
+            },
+            corelib::GameEvent::EntityCreated { id, position } => {
+                Self::EntityCreated {
+                    id: id.into(),
+                    position: protocol::Position::from_corelib(position),
+                }
+            },
+            corelib::GameEvent::EntityDied { id } => {
+                Self::EntityDied { id: id.into() }
+            },
+            corelib::GameEvent::EntityDroppedLoot {
+                id,
+                position,
+                item_id,
+                count,
+            } => Self::EntityDroppedLoot {
+                id: id.into(),
+                position: protocol::Position::from_corelib(position),
+                item_id,
+                count,
             },
             corelib::GameEvent::EntityMoved { id, from, to } => {
                 Self::EntityMoved {
@@ -63,11 +175,11 @@ impl FromCorelib<corelib::GameEvent> for protocol::GameEvent {
                     to: protocol::Position::from_corelib(to),
                 }
             },
-            corelib::GameEvent::EntityAttacked { id, target, damage } => {
+            corelib::GameEvent::EntityAttacked { id, target, outcome } => {
                 Self::EntityAttacked {
                     id: id.into(),
                     target: protocol::Position::from_corelib(target),
-                    damage,
+                    outcome: protocol::AttackOutcome::from_corelib(outcome),
                 }
             },
             corelib::GameEvent::EffectTick { entity_id, effect_id } => {
@@ -76,6 +188,20 @@ impl FromCorelib<corelib::GameEvent> for protocol::GameEvent {
             corelib::GameEvent::EffectExpired { entity_id, effect_id } => {
                 Self::EffectExpired { entity_id: entity_id.into(), effect_id }
             },
+            corelib::GameEvent::ItemUsed { item_id } => {
+                Self::ItemUsed { item_id }
+            },
+            corelib::GameEvent::EffectApplied {
+                entity_id,
+                effect_id,
+                name,
+                duration,
+            } => Self::EffectApplied {
+                entity_id: entity_id.into(),
+                effect_id,
+                name,
+                duration,
+            },
         }
     }
 }
@@ -97,6 +223,18 @@ impl FromCorelib<corelib::Direction> for protocol::Direction {
     }
 }
 
+impl FromCorelib<corelib::EquipmentSlot> for protocol::EquipmentSlot {
+    fn from_corelib(from: corelib::EquipmentSlot) -> Self {
+        match from {
+            corelib::EquipmentSlot::MainHand => Self::MainHand,
+            corelib::EquipmentSlot::OffHand => Self::OffHand,
+            corelib::EquipmentSlot::Head => Self::Head,
+            corelib::EquipmentSlot::Body => Self::Body,
+            corelib::EquipmentSlot::Ring => Self::Ring,
+        }
+    }
+}
+
 impl FromCorelib<corelib::EntityId> for protocol::EntityId {
     fn from_corelib(from: corelib::EntityId) -> Self {
         Self(from.into_inner())
@@ -116,14 +254,312 @@ where
     }
 }
 
+impl<T, P> FromCorelib<Option<P>> for Option<T>
+where
+    T: FromCorelib<P>,
+{
+    fn from_corelib(from: Option<P>) -> Self {
+        from.map(T::from_corelib)
+    }
+}
+
+impl<T, P> FromCorelib<Vec<P>> for Vec<T>
+where
+    T: FromCorelib<P>,
+{
+    fn from_corelib(from: Vec<P>) -> Self {
+        from.into_iter().map(T::from_corelib).collect()
+    }
+}
+
+impl<T, P> FromCorelib<corelib::SlotDiff<P>> for protocol::SlotDiff<T>
+where
+    T: FromCorelib<P>,
+{
+    fn from_corelib(from: corelib::SlotDiff<P>) -> Self {
+        Self {
+            slot: from.slot,
+            old: T::from_corelib(from.old),
+            new: T::from_corelib(from.new),
+        }
+    }
+}
+
+impl<T, P> FromCorelib<corelib::SlotDiff<P, corelib::EquipmentSlot>>
+    for protocol::SlotDiff<T, protocol::EquipmentSlot>
+where
+    T: FromCorelib<P>,
+{
+    fn from_corelib(
+        from: corelib::SlotDiff<P, corelib::EquipmentSlot>,
+    ) -> Self {
+        Self {
+            slot: protocol::EquipmentSlot::from_corelib(from.slot),
+            old: T::from_corelib(from.old),
+            new: T::from_corelib(from.new),
+        }
+    }
+}
+
+impl FromCorelib<corelib::TileDiff> for protocol::TileDiff {
+    fn from_corelib(from: corelib::TileDiff) -> Self {
+        Self {
+            position: protocol::Position::from_corelib(from.position),
+            old: protocol::Tile::from_corelib(from.old),
+            new: protocol::Tile::from_corelib(from.new),
+        }
+    }
+}
+
+impl FromCorelib<corelib::Tile> for protocol::Tile {
+    fn from_corelib(from: corelib::Tile) -> Self {
+        match from {
+            corelib::Tile::Empty => Self::Empty,
+            corelib::Tile::Floor => Self::Floor,
+            corelib::Tile::StairsDown => Self::StairsDown,
+            corelib::Tile::StairsUp => Self::StairsUp,
+            corelib::Tile::Wall => Self::Wall,
+            corelib::Tile::DoorClosed => Self::DoorClosed,
+            corelib::Tile::Water => Self::Water,
+            corelib::Tile::Lava => Self::Lava,
+            corelib::Tile::DoorOpen => Self::DoorOpen,
+        }
+    }
+}
+
+impl FromCorelib<corelib::ItemStack> for protocol::ItemStack {
+    fn from_corelib(from: corelib::ItemStack) -> Self {
+        Self { item_id: from.id(), count: from.count() }
+    }
+}
+
+impl FromCorelib<corelib::EffectInstance> for protocol::EffectInstance {
+    fn from_corelib(from: corelib::EffectInstance) -> Self {
+        Self {
+            effect_id: from.effect_id(),
+            remaining_turns: from.remaining_turns(),
+        }
+    }
+}
+
 impl FromCorelib<corelib::StateDiff> for protocol::StateDiff {
     fn from_corelib(from: corelib::StateDiff) -> Self {
         Self {
-            positions: from
-                .positions
+            positions: Vec::from_corelib(from.positions),
+            stats: Vec::from_corelib(from.stats),
+            effects: Vec::from_corelib(from.effects),
+            inventory: Vec::from_corelib(from.inventory),
+            hotbar: Vec::from_corelib(from.hotbar),
+            equipment: Vec::from_corelib(from.equipment),
+            spawned: from
+                .spawned
                 .into_iter()
-                .map(protocol::EntityDiff::from_corelib)
+                .map(|actor| protocol::SpawnedEntity {
+                    entity_id: protocol::EntityId::from_corelib(actor.id()),
+                    position: protocol::Position::from_corelib(
+                        actor.position(),
+                    ),
+                })
                 .collect(),
+            despawned: Vec::from_corelib(from.despawned),
+            tiles: Vec::from_corelib(from.tiles),
+        }
+    }
+}
+
+impl FromCorelib<corelib::Stats> for protocol::Stats {
+    fn from_corelib(from: corelib::Stats) -> Self {
+        Self {
+            hp: from.hp(),
+            max_hp: from.max_hp(),
+            min_damage: from.min_damage(),
+            max_damage: from.max_damage(),
+            defense: from.defense(),
+            sight_radius: from.sight_radius(),
+            accuracy: from.accuracy(),
+            evasion: from.evasion(),
+            crit_chance: from.crit_chance(),
+            crit_multiplier: from.crit_multiplier(),
+        }
+    }
+}
+
+impl FromCorelib<corelib::AttackOutcome> for protocol::AttackOutcome {
+    fn from_corelib(from: corelib::AttackOutcome) -> Self {
+        match from {
+            corelib::AttackOutcome::Miss => Self::Miss,
+            corelib::AttackOutcome::Hit { damage } => Self::Hit { damage },
+            corelib::AttackOutcome::Critical { damage } => {
+                Self::Critical { damage }
+            },
+        }
+    }
+}
+
+impl FromCorelib<corelib::GameStatus> for protocol::GameStatus {
+    fn from_corelib(from: corelib::GameStatus) -> Self {
+        match from {
+            corelib::GameStatus::Running => Self::Running,
+            corelib::GameStatus::PlayerDead => Self::PlayerDead,
+            corelib::GameStatus::Victory => Self::Victory,
+        }
+    }
+}
+
+impl FromCorelib<corelib::ActionError> for protocol::ActionError {
+    fn from_corelib(from: corelib::ActionError) -> Self {
+        match from {
+            corelib::ActionError::GameOver { status } => Self::GameOver {
+                status: protocol::GameStatus::from_corelib(status),
+            },
+            corelib::ActionError::Dead => Self::Dead,
+            corelib::ActionError::LevelGeneration(_) => Self::LevelGeneration,
+            corelib::ActionError::Blocked { position } => Self::Blocked {
+                position: protocol::Position::from_corelib(position),
+            },
+            corelib::ActionError::NoTarget { position } => Self::NoTarget {
+                position: protocol::Position::from_corelib(position),
+            },
+            corelib::ActionError::NoDoor { position } => Self::NoDoor {
+                position: protocol::Position::from_corelib(position),
+            },
+            corelib::ActionError::NoStairs { position } => Self::NoStairs {
+                position: protocol::Position::from_corelib(position),
+            },
+            corelib::ActionError::NoItems { position } => Self::NoItems {
+                position: protocol::Position::from_corelib(position),
+            },
+            corelib::ActionError::ItemNotFound { item_id } => {
+                Self::ItemNotFound { item_id }
+            },
+            corelib::ActionError::NotUsable { item_id } => {
+                Self::NotUsable { item_id }
+            },
+            corelib::ActionError::InvalidCount => Self::InvalidCount,
+            corelib::ActionError::InventoryFull { item_id } => {
+                Self::InventoryFull { item_id }
+            },
+            corelib::ActionError::InvalidSlot { slot } => {
+                Self::InvalidSlot { slot }
+            },
+            corelib::ActionError::SlotOccupied { slot } => {
+                Self::SlotOccupied { slot }
+            },
+            corelib::ActionError::SlotEmpty { slot } => {
+                Self::SlotEmpty { slot }
+            },
+            corelib::ActionError::NotEquippable { item_id, slot } => {
+                Self::NotEquippable {
+                    item_id,
+                    slot: protocol::EquipmentSlot::from_corelib(slot),
+                }
+            },
+            corelib::ActionError::EquipmentSlotTaken { slot } => {
+                Self::EquipmentSlotTaken {
+                    slot: protocol::EquipmentSlot::from_corelib(slot),
+                }
+            },
+            corelib::ActionError::NothingEquipped { slot } => {
+                Self::NothingEquipped {
+                    slot: protocol::EquipmentSlot::from_corelib(slot),
+                }
+            },
+            corelib::ActionError::TwoHanded { item_id } => {
+                Self::TwoHanded { item_id }
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_diff_keeps_every_change() {
+        let stack = |item_id| Some(corelib::ItemStack::new(item_id, 2));
+        let diff = corelib::StateDiff {
+            positions: Vec::new(),
+            stats: Vec::new(),
+            effects: vec![corelib::EntityDiff {
+                entity_id: corelib::EntityId::from(3),
+                old: Vec::new(),
+                new: vec![corelib::EffectInstance::new(2, 4)],
+            }],
+            inventory: vec![corelib::SlotDiff {
+                slot: 1,
+                old: stack(0),
+                new: None,
+            }],
+            hotbar: vec![corelib::SlotDiff {
+                slot: 2,
+                old: None,
+                new: stack(3),
+            }],
+            equipment: vec![corelib::SlotDiff {
+                slot: corelib::EquipmentSlot::Head,
+                old: None,
+                new: stack(5),
+            }],
+            spawned: Vec::new(),
+            despawned: Vec::new(),
+            tiles: vec![corelib::TileDiff {
+                position: corelib::Position::new(4, 2),
+                old: corelib::Tile::DoorClosed,
+                new: corelib::Tile::DoorOpen,
+            }],
+        };
+
+        let Ok(json) =
+            serde_json::to_string(&protocol::StateDiff::from_corelib(diff))
+        else {
+            panic!("diff must serialize");
+        };
+        let Ok(diff) = serde_json::from_str::<protocol::StateDiff>(&json)
+        else {
+            panic!("diff must deserialize");
+        };
+
+        let [effects] = diff.effects.as_slice() else {
+            panic!("expected one effects diff");
+        };
+        assert_eq!(effects.entity_id.0, 3);
+        assert!(effects.old.is_empty());
+        assert!(matches!(
+            effects.new.as_slice(),
+            [protocol::EffectInstance { effect_id: 2, remaining_turns: 4 }]
+        ));
+        assert!(matches!(
+            diff.inventory.as_slice(),
+            [protocol::SlotDiff {
+                slot: 1,
+                old: Some(protocol::ItemStack { item_id: 0, count: 2 }),
+                new: None,
+            }]
+        ));
+        assert!(matches!(
+            diff.hotbar.as_slice(),
+            [protocol::SlotDiff {
+                slot: 2,
+                old: None,
+                new: Some(protocol::ItemStack { item_id: 3, count: 2 }),
+            }]
+        ));
+        assert!(matches!(
+            diff.equipment.as_slice(),
+            [protocol::SlotDiff {
+                slot: protocol::EquipmentSlot::Head,
+                old: None,
+                new: Some(protocol::ItemStack { item_id: 5, count: 2 }),
+            }]
+        ));
+        assert!(matches!(
+            diff.tiles.as_slice(),
+            [protocol::TileDiff {
+                position: protocol::Position { x: 4, y: 2 },
+                old: protocol::Tile::DoorClosed,
+                new: protocol::Tile::DoorOpen,
+            }]
+        ));
+    }
+}
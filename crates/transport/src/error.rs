@@ -6,4 +6,8 @@ pub type TransportResult<T> = Result<T, TransportError>;
 pub enum TransportError {
     /// Error occurred while trying to lock the state.
     LockError,
+    /// No game could be created from the world settings.
+    InvalidWorld,
+    /// The action was refused and the game did not change.
+    Rejected(protocol::ActionError),
 }
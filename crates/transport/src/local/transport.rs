@@ -25,15 +25,21 @@ impl LocalTransport {
     }
 
     /// Create a new game state.
-    #[must_use]
-    pub fn new_state() -> corelib::GameState {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the world could not be generated.
+    pub fn new_state() -> TransportResult<corelib::GameState> {
         corelib::new_game(&corelib::WorldSettings {
             seed: [0; 32],
             map_width: 1024,
             map_height: 1024,
             floor_tiles: 100,
             enemies: 10,
+            generator: corelib::GeneratorKind::default(),
+            ..corelib::WorldSettings::default()
         })
+        .map_err(|_| TransportError::InvalidWorld)
     }
 }
 
@@ -45,7 +51,13 @@ impl Transport for LocalTransport {
     ) -> TransportResult<StepResult> {
         let mut guard =
             self.state.lock().map_err(|_| TransportError::LockError)?;
-        let result = guard.apply_player_action(&action.to_corelib());
+        let result = guard.apply_player_action(&action.to_corelib()).map_err(
+            |error| {
+                TransportError::Rejected(protocol::ActionError::from_corelib(
+                    error,
+                ))
+            },
+        )?;
         Ok(StepResult {
             events: result
                 .events
@@ -64,6 +76,8 @@ impl Transport for LocalTransport {
         };
         State {
             player: protocol::Position::from_corelib(guard.player().position()),
+            status: protocol::GameStatus::from_corelib(guard.status()),
+            depth: guard.depth(),
         }
     }
 }